@@ -0,0 +1,314 @@
+// src-tauri/src/benchmark.rs
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+use tauri::Emitter;
+
+use crate::error::ChanterelleError;
+use crate::output_contract::OutputContract;
+use crate::python_runner_io;
+use crate::report::table;
+use crate::state::AppState;
+use crate::storage;
+use crate::validation;
+
+const DEFAULT_ITERATIONS: usize = 20;
+const MAX_ITERATIONS: usize = 10_000;
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct BenchmarkRequest {
+    /// Number of timed invocations
+    pub iterations: Option<usize>,
+    /// Inputs to send, cycled through; defaults to the model's input defaults
+    pub inputs: Option<Vec<HashMap<String, serde_json::Value>>>,
+    /// Name of an input preset from model_meta.json, applied over the defaults
+    pub preset: Option<String>,
+    /// Also write the results to model_benchmark.json in the project folder
+    pub save: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LatencyStats {
+    pub min_ms: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BenchmarkReport {
+    pub project_name: String,
+    pub model_version: Option<String>,
+    pub env_fingerprint: String,
+    pub timestamp: u64,
+    /// Spawn through ping, in milliseconds
+    pub warmup_ms: f64,
+    pub iterations: usize,
+    pub errors: usize,
+    /// Response fields that broke the declared outputs, over all invocations
+    pub contract_violations: usize,
+    /// Over the successful invocations
+    pub latency: LatencyStats,
+    /// Successful invocations per second of wall time
+    pub throughput_per_sec: f64,
+    /// Peak resident memory of the Python process; not measured on Windows
+    pub peak_rss_bytes: Option<u64>,
+    /// Latency of every invocation in order, failed ones included
+    pub latencies_ms: Vec<f64>,
+    pub sections: Vec<serde_json::Value>,
+}
+
+/// Input values for the benchmark: explicit inputs, or the model's defaults with a preset on top.
+fn benchmark_inputs(
+    model_dir: &Path,
+    request: &BenchmarkRequest,
+) -> Result<Vec<HashMap<String, serde_json::Value>>, ChanterelleError> {
+    if let Some(inputs) = request.inputs.as_ref().filter(|inputs| !inputs.is_empty()) {
+        return Ok(inputs.clone());
+    }
+
+    let content = fs::read_to_string(model_dir.join("model_meta.json"))
+        .map_err(|e| ChanterelleError::config(format!("Failed to read model_meta.json: {}", e)))?;
+    let mut meta: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| ChanterelleError::config(format!("Failed to parse model_meta.json: {}", e)))?;
+    crate::projects::resolve_json_refs(&mut meta, model_dir)?;
+
+    let mut values: HashMap<String, serde_json::Value> = meta["inputs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|input| Some((input["name"].as_str()?.to_string(), input.get("default")?.clone())))
+        .collect();
+
+    if let Some(preset_name) = &request.preset {
+        let preset = meta["input_presets"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .flat_map(|group| group["presets"].as_array().into_iter().flatten())
+            .find(|preset| preset["name"].as_str() == Some(preset_name.as_str()))
+            .ok_or_else(|| ChanterelleError::config(format!("Input preset '{}' not found", preset_name)))?;
+        if let Some(preset_values) = preset["values"].as_object() {
+            values.extend(preset_values.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+    }
+    Ok(vec![values])
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn latency_stats(latencies: &[f64]) -> LatencyStats {
+    let mut sorted = latencies.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    if sorted.is_empty() {
+        return LatencyStats::default();
+    }
+    LatencyStats {
+        min_ms: sorted[0],
+        mean_ms: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        p50_ms: percentile(&sorted, 50.0),
+        p95_ms: percentile(&sorted, 95.0),
+        p99_ms: percentile(&sorted, 99.0),
+        max_ms: sorted[sorted.len() - 1],
+    }
+}
+
+/// Peak resident set size of a process, in bytes.
+#[cfg(target_os = "linux")]
+fn peak_rss_bytes(pid: u32) -> Option<u64> {
+    // VmHWM is the kernel's own high-water mark for the process
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Current resident set size of a process, in bytes; sampled after every invocation.
+#[cfg(all(unix, not(target_os = "linux")))]
+fn peak_rss_bytes(pid: u32) -> Option<u64> {
+    let output = std::process::Command::new("ps")
+        .args(["-o", "rss=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    let kb: u64 = String::from_utf8_lossy(&output.stdout).trim().parse().ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(not(unix))]
+fn peak_rss_bytes(_pid: u32) -> Option<u64> {
+    None
+}
+
+fn report_sections(report: &BenchmarkReport) -> Vec<serde_json::Value> {
+    let ms = |value: f64| format!("{:.2}", value);
+    let stats = &report.latency;
+    let mut rows = vec![
+        serde_json::json!({ "metric": "Warmup (spawn to ready)", "value": format!("{} ms", ms(report.warmup_ms)) }),
+        serde_json::json!({ "metric": "Invocations", "value": report.iterations }),
+        serde_json::json!({ "metric": "Errors", "value": report.errors }),
+        serde_json::json!({ "metric": "Contract violations", "value": report.contract_violations }),
+        serde_json::json!({ "metric": "Latency p50", "value": format!("{} ms", ms(stats.p50_ms)) }),
+        serde_json::json!({ "metric": "Latency p95", "value": format!("{} ms", ms(stats.p95_ms)) }),
+        serde_json::json!({ "metric": "Latency p99", "value": format!("{} ms", ms(stats.p99_ms)) }),
+        serde_json::json!({ "metric": "Latency mean", "value": format!("{} ms", ms(stats.mean_ms)) }),
+        serde_json::json!({ "metric": "Latency min / max", "value": format!("{} / {} ms", ms(stats.min_ms), ms(stats.max_ms)) }),
+        serde_json::json!({ "metric": "Throughput", "value": format!("{:.2} req/s", report.throughput_per_sec) }),
+    ];
+    if let Some(bytes) = report.peak_rss_bytes {
+        rows.push(serde_json::json!({
+            "metric": "Peak memory (RSS)",
+            "value": format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0)),
+        }));
+    }
+
+    let points: Vec<serde_json::Value> = report
+        .latencies_ms
+        .iter()
+        .enumerate()
+        .map(|(index, latency)| serde_json::json!({ "x": index + 1, "y": latency }))
+        .collect();
+
+    vec![serde_json::json!({
+        "type": "section",
+        "id": "benchmark",
+        "title": "Benchmark",
+        "description": format!(
+            "Version {} · environment {}",
+            report.model_version.as_deref().unwrap_or("unknown"),
+            report.env_fingerprint
+        ),
+        "items_per_row": 2,
+        "items": [
+            table("Results", &[("Metric", "metric"), ("Value", "value")], rows),
+            {
+                "type": "line_chart",
+                "id": "benchmark_latency",
+                "title": "Latency per invocation",
+                "data": {
+                    "lines": [{ "id": "latency", "points": points }],
+                    "axis": { "x": { "label": "Invocation" }, "y": { "label": "Latency (ms)" } }
+                }
+            }
+        ],
+    })]
+}
+
+/// Measures warmup and inference latency in a fresh Python process of its own.
+pub fn run_benchmark(
+    projects_dir: &str,
+    project_name: &str,
+    request: &BenchmarkRequest,
+    mut on_progress: impl FnMut(usize, usize),
+) -> Result<BenchmarkReport, ChanterelleError> {
+    let model_dir = Path::new(projects_dir).join(project_name);
+    let declared = validation::read_model_inputs(&model_dir)?;
+    let inputs = benchmark_inputs(&model_dir, request)?
+        .into_iter()
+        .map(|inputs| validation::validate_inputs(&declared, inputs))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.context("Invalid benchmark inputs"))?;
+    let iterations = request.iterations.unwrap_or(DEFAULT_ITERATIONS).clamp(1, MAX_ITERATIONS);
+    let contract = OutputContract::read(&model_dir);
+
+    let warmup_started = Instant::now();
+    let (mut process, project) = python_runner_io::spawn_project_process(projects_dir, project_name, None)?;
+    python_runner_io::check_detached_model_ready(&mut process)?;
+    let warmup_ms = warmup_started.elapsed().as_secs_f64() * 1000.0;
+    let pid = process.id();
+
+    let mut latencies_ms = Vec::with_capacity(iterations);
+    let mut succeeded_ms = Vec::with_capacity(iterations);
+    let mut contract_violations = 0;
+    let mut peak_rss: Option<u64> = None;
+    let run_started = Instant::now();
+    for index in 0..iterations {
+        let started = Instant::now();
+        let result = python_runner_io::run_detached_model(&mut process, &inputs[index % inputs.len()]);
+        let latency = started.elapsed().as_secs_f64() * 1000.0;
+        latencies_ms.push(latency);
+        match result {
+            Ok(response) => {
+                succeeded_ms.push(latency);
+                contract_violations += contract.check(&response).len();
+            }
+            // A dead process fails every remaining call the same way
+            Err(e @ (ChanterelleError::Spawn { .. } | ChanterelleError::Protocol { .. })) => {
+                return Err(e.context(&format!("Benchmark stopped at invocation {}", index + 1)));
+            }
+            Err(e) => println!("Benchmark invocation {} failed: {}", index + 1, e),
+        }
+        peak_rss = peak_rss.max(peak_rss_bytes(pid));
+        on_progress(index + 1, iterations);
+    }
+    let wall_secs = run_started.elapsed().as_secs_f64();
+    drop(process);
+
+    let mut report = BenchmarkReport {
+        project_name: project_name.to_string(),
+        model_version: project.model_version,
+        env_fingerprint: project.env_fingerprint,
+        timestamp: storage::now_millis(),
+        warmup_ms,
+        iterations,
+        errors: iterations - succeeded_ms.len(),
+        contract_violations,
+        latency: latency_stats(&succeeded_ms),
+        throughput_per_sec: if wall_secs > 0.0 { succeeded_ms.len() as f64 / wall_secs } else { 0.0 },
+        peak_rss_bytes: peak_rss,
+        latencies_ms,
+        sections: Vec::new(),
+    };
+    report.sections = report_sections(&report);
+    Ok(report)
+}
+
+// --- Commands ---
+
+/// Benchmarks a project and optionally saves the results to model_benchmark.json,
+/// in the same `content` format as model_findings.json.
+#[tauri::command]
+pub async fn benchmark_model(
+    project_name: String,
+    request: Option<BenchmarkRequest>,
+    window: tauri::Window,
+    state: tauri::State<'_, AppState>,
+) -> Result<BenchmarkReport, ChanterelleError> {
+    let projects_dir = {
+        let settings = state.settings.lock().unwrap();
+        settings.projects_directory.clone()
+    };
+    let request = request.unwrap_or_default();
+
+    let report = run_benchmark(&projects_dir, &project_name, &request, |done, total| {
+        let progress = serde_json::json!({ "project_name": project_name, "done": done, "total": total });
+        let _ = window.emit("benchmark:progress", &progress);
+    })?;
+
+    if request.save {
+        let path = Path::new(&projects_dir).join(&project_name).join(storage::BENCHMARK_FILE);
+        let saved = serde_json::json!({
+            "version": report.model_version,
+            "content": report.sections,
+            "benchmark": report,
+        });
+        fs::write(&path, serde_json::to_vec_pretty(&saved).unwrap())
+            .map_err(|e| ChanterelleError::io(format!("Failed to write {}: {}", path.display(), e)))?;
+    }
+    println!(
+        "Benchmark for {}: p50 {:.2} ms, p95 {:.2} ms, {:.2} req/s",
+        project_name, report.latency.p50_ms, report.latency.p95_ms, report.throughput_per_sec
+    );
+    Ok(report)
+}
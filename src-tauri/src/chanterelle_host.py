@@ -0,0 +1,103 @@
+"""
+Host services for Chanterelle handlers.
+Rust copies this file next to the base handlers, so handler code can ask the app
+for things only it can do:
+
+    import chanterelle_host as host
+
+    host.notify("Training finished", "Accuracy: 0.93")
+    path = host.pick_file(title="Choose a dataset", extensions=["csv"])
+    host.kv_set("last_dataset", path)  # persists across restarts
+    host.log_metric("accuracy", 0.93)
+
+Calls are answered by the Rust runner over the same stdio pipes as the protocol,
+so they only work while Chanterelle is handling a request (not while the model
+or module is loading). Projects can limit the allowed calls with `host_services`
+in model_meta.json / interactive.json.
+"""
+import json
+from typing import Any, Callable, Dict, List, Optional
+
+_HOST_CALL_KEY = "_chanterelle_host_call"
+_HOST_RESULT_KEY = "_chanterelle_host_result"
+
+_send: Optional[Callable[[Dict[str, Any]], None]] = None
+_read_line: Optional[Callable[[], str]] = None
+_CALL_SEQ = 0
+
+
+class HostServiceError(RuntimeError):
+    """Raised when a host call is unavailable, not allowed, or fails in the app."""
+
+
+def _install(send: Callable[[Dict[str, Any]], None], read_line: Callable[[], str]) -> None:
+    """Connect the helper to the protocol pipes. Called by the base handlers once ready."""
+    global _send, _read_line
+    _send = send
+    _read_line = read_line
+
+
+def call(service: str, **args: Any) -> Any:
+    """Run a host service in the app and return its result."""
+    global _CALL_SEQ
+    if _send is None or _read_line is None:
+        raise HostServiceError("Host services are only available while Chanterelle is handling a request")
+
+    _CALL_SEQ += 1
+    call_id = f"h{_CALL_SEQ}"
+    _send({_HOST_CALL_KEY: {"id": call_id, "service": service, "args": args}})
+
+    while True:
+        line = _read_line()
+        if not line:
+            raise HostServiceError("Chanterelle closed the connection during a host call")
+        line = line.strip()
+        if not line:
+            continue
+        try:
+            message = json.loads(line)
+        except json.JSONDecodeError:
+            continue
+
+        result = message.get(_HOST_RESULT_KEY) if isinstance(message, dict) else None
+        if not isinstance(result, dict) or result.get("id") != call_id:
+            continue
+        if result.get("ok"):
+            return result.get("result")
+        raise HostServiceError(result.get("error") or f"Host call '{service}' failed")
+
+
+def notify(title: str, body: str = "", level: str = "info") -> None:
+    """Show a notification in the app. level: info, success, warning or error."""
+    call("notify", title=title, body=body, level=level)
+
+
+def pick_file(title: Optional[str] = None, extensions: Optional[List[str]] = None) -> Optional[str]:
+    """Open the native file picker. Returns the chosen path, or None if cancelled."""
+    return call("pick_file", title=title, extensions=extensions)
+
+
+def kv_get(key: str, default: Any = None, namespace: str = "default") -> Any:
+    """Read a value from the project's persistent key-value store."""
+    value = call("kv_get", key=key, namespace=namespace)
+    return default if value is None else value
+
+
+def kv_set(key: str, value: Any, namespace: str = "default") -> None:
+    """Store a JSON-serialisable value (up to 1 MB) in the project's key-value store."""
+    call("kv_set", key=key, value=value, namespace=namespace)
+
+
+def kv_delete(key: str, namespace: str = "default") -> bool:
+    """Remove a key. Returns whether it existed."""
+    return bool(call("kv_delete", key=key, namespace=namespace))
+
+
+def kv_keys(namespace: str = "default") -> List[str]:
+    """List the keys stored in a namespace."""
+    return list(call("kv_keys", namespace=namespace) or [])
+
+
+def log_metric(name: str, value: float, step: Optional[int] = None, tags: Optional[Dict[str, str]] = None) -> None:
+    """Record a numeric metric for the project."""
+    call("log_metric", name=name, value=value, step=step, tags=tags)
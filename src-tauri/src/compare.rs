@@ -0,0 +1,396 @@
+// src-tauri/src/compare.rs
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+use std::time::Instant;
+use tauri::Emitter;
+
+use crate::error::ChanterelleError;
+use crate::output_diff::{self, DiffOptions, FieldDiff};
+use crate::python_runner_io::{self, PythonProcess};
+use crate::replay;
+use crate::report::{cell, table};
+use crate::state::AppState;
+use crate::types::PythonEnvironment;
+use crate::validation;
+
+/// Rows shown in the "Disagreements" table
+const MAX_REPORT_ROWS: usize = 200;
+
+/// One project taking part in a comparison. The first target is the baseline.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CompareTarget {
+    pub project_name: String,
+    /// Shown in the report; defaults to the project name
+    pub label: Option<String>,
+    /// Runs the project in this environment instead of its own `python_environment`
+    pub python_environment: Option<PythonEnvironment>,
+}
+
+impl CompareTarget {
+    fn label(&self) -> String {
+        self.label.clone().unwrap_or_else(|| self.project_name.clone())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CompareRequest {
+    pub targets: Vec<CompareTarget>,
+    /// A single set of inputs
+    pub inputs: Option<HashMap<String, serde_json::Value>>,
+    /// A .json array or .jsonl file of input objects (or `{"inputs": {...}}` records),
+    /// relative to the projects directory
+    pub inputs_file: Option<String>,
+    /// Absolute tolerance per `ModelOutput` name of the baseline project
+    #[serde(default)]
+    pub tolerances: HashMap<String, f64>,
+    pub default_tolerance: Option<f64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CompareTargetInfo {
+    pub label: String,
+    pub project_name: String,
+    pub model_version: Option<String>,
+    pub env_fingerprint: Option<String>,
+    /// Spawn through ping, in milliseconds
+    pub warmup_ms: u64,
+    /// Set when the target could not be started; it then has no results
+    pub error: Option<ChanterelleError>,
+    pub mean_latency_ms: Option<f64>,
+    /// Cases whose outputs match the baseline's within tolerance
+    pub agreeing_cases: usize,
+    pub failed_cases: usize,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CompareResult {
+    pub label: String,
+    pub outputs: Option<serde_json::Value>,
+    pub error: Option<ChanterelleError>,
+    pub latency_ms: u64,
+    /// Differences from the baseline's outputs; always empty for the baseline
+    pub diffs: Vec<FieldDiff>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CompareCase {
+    pub case_id: String,
+    pub inputs: HashMap<String, serde_json::Value>,
+    /// One result per target, in target order
+    pub results: Vec<CompareResult>,
+    /// `ModelOutput` names (or paths) where any target disagrees with the baseline
+    pub disagreeing_fields: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CompareReport {
+    pub targets: Vec<CompareTargetInfo>,
+    pub cases: Vec<CompareCase>,
+    pub sections: Vec<serde_json::Value>,
+}
+
+/// Case id and the inputs sent to every target
+type CompareInputs = (String, HashMap<String, serde_json::Value>);
+
+fn collect_inputs(projects_dir: &str, request: &CompareRequest) -> Result<Vec<CompareInputs>, ChanterelleError> {
+    let mut cases = Vec::new();
+    if let Some(inputs) = &request.inputs {
+        cases.push(("input".to_string(), inputs.clone()));
+    }
+    if let Some(file) = &request.inputs_file {
+        for (index, record) in replay::read_records(&Path::new(projects_dir).join(file))?.into_iter().enumerate() {
+            let case_id = record
+                .get("id")
+                .and_then(|id| id.as_str())
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| format!("case-{}", index + 1));
+            let inputs = record.get("inputs").cloned().unwrap_or(record);
+            let inputs = serde_json::from_value(inputs)
+                .map_err(|e| ChanterelleError::config(format!("Case '{}' has invalid inputs: {}", case_id, e)))?;
+            cases.push((case_id, inputs));
+        }
+    }
+    Ok(cases)
+}
+
+/// Starts every target on its own thread, since model loading dominates. The base
+/// handler files are written first, so targets sharing a project folder don't
+/// write them at the same time.
+fn warm_targets(
+    projects_dir: &str,
+    targets: &[CompareTarget],
+) -> Vec<(CompareTargetInfo, Option<PythonProcess>)> {
+    let mut prepared = HashSet::new();
+    for target in targets {
+        let model_dir = Path::new(projects_dir).join(&target.project_name);
+        if prepared.insert(target.project_name.as_str()) && model_dir.join("handler_io.py").exists() {
+            // A failure here shows up again when the target is started
+            if let Err(e) = python_runner_io::ensure_handler_files(&model_dir) {
+                println!("Failed to prepare '{}': {}", target.project_name, e);
+            }
+        }
+    }
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = targets
+            .iter()
+            .map(|target| {
+                scope.spawn(move || {
+                    let started = Instant::now();
+                    let spawned = python_runner_io::spawn_project_process(
+                        projects_dir,
+                        &target.project_name,
+                        target.python_environment.clone(),
+                    )
+                    .and_then(|(mut process, project)| {
+                        python_runner_io::check_detached_model_ready(&mut process)?;
+                        Ok((process, project))
+                    });
+                    let warmup_ms = started.elapsed().as_millis() as u64;
+
+                    let mut info = CompareTargetInfo {
+                        label: target.label(),
+                        project_name: target.project_name.clone(),
+                        model_version: None,
+                        env_fingerprint: None,
+                        warmup_ms,
+                        error: None,
+                        mean_latency_ms: None,
+                        agreeing_cases: 0,
+                        failed_cases: 0,
+                    };
+                    match spawned {
+                        Ok((process, project)) => {
+                            info.model_version = project.model_version;
+                            info.env_fingerprint = Some(project.env_fingerprint);
+                            (info, Some(process))
+                        }
+                        Err(e) => {
+                            info.error = Some(e.context(&format!("Failed to start '{}'", target.label())));
+                            (info, None)
+                        }
+                    }
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+fn report_sections(targets: &[CompareTargetInfo], cases: &[CompareCase]) -> Vec<serde_json::Value> {
+    let baseline = &targets[0].label;
+    let target_rows = targets
+        .iter()
+        .map(|t| serde_json::json!({
+            "target": t.label,
+            "project": t.project_name,
+            "version": t.model_version.clone().unwrap_or_default(),
+            "warmup": t.warmup_ms,
+            "latency": t.mean_latency_ms.map(|l| format!("{:.1}", l)).unwrap_or_default(),
+            "agreement": if t.error.is_some() {
+                "not started".to_string()
+            } else {
+                format!("{}/{}", t.agreeing_cases, cases.len())
+            },
+            "failed": t.failed_cases,
+        }))
+        .collect();
+    let mut sections = vec![serde_json::json!({
+        "type": "section",
+        "id": "compare_summary",
+        "title": "Comparison",
+        "description": format!("{} case(s); agreement is measured against {}", cases.len(), baseline),
+        "items": [table(
+            "Targets",
+            &[
+                ("Target", "target"),
+                ("Project", "project"),
+                ("Version", "version"),
+                ("Warmup (ms)", "warmup"),
+                ("Mean latency (ms)", "latency"),
+                ("Agrees with baseline", "agreement"),
+                ("Failed", "failed"),
+            ],
+            target_rows,
+        )],
+    })];
+
+    let disagreement_rows: Vec<serde_json::Value> = cases
+        .iter()
+        .flat_map(|case| {
+            case.results.iter().skip(1).flat_map(move |result| {
+                result.diffs.iter().map(move |diff| {
+                    serde_json::json!({
+                        "case": case.case_id,
+                        "target": result.label,
+                        "field": diff.output.clone().unwrap_or_else(|| diff.path.clone()),
+                        "baseline": cell(diff.baseline.as_ref()),
+                        "value": cell(diff.current.as_ref()),
+                        "delta": diff.delta.map(|d| format!("{:+.6}", d)).unwrap_or_default(),
+                    })
+                })
+            })
+        })
+        .take(MAX_REPORT_ROWS)
+        .collect();
+    if !disagreement_rows.is_empty() {
+        sections.push(serde_json::json!({
+            "type": "section",
+            "id": "compare_disagreements",
+            "title": "Disagreements",
+            "color": "yellow",
+            "items": [table(
+                &format!("Fields that differ from {}", baseline),
+                &[("Case", "case"), ("Target", "target"), ("Field", "field"), ("Baseline", "baseline"), ("Value", "value"), ("Delta", "delta")],
+                disagreement_rows,
+            )],
+        }));
+    }
+
+    // A single input is easiest to judge by looking at the outputs side by side
+    if let [case] = cases {
+        let columns: Vec<serde_json::Value> = case
+            .results
+            .iter()
+            .map(|result| {
+                let items = match (&result.outputs, &result.error) {
+                    (Some(serde_json::Value::Array(sections)), _) => sections.clone(),
+                    (_, Some(error)) => vec![serde_json::json!({ "type": "error", "error": error.message() })],
+                    (Some(other), None) => vec![serde_json::json!({ "type": "error", "error": cell(Some(other)) })],
+                    (None, None) => Vec::new(),
+                };
+                serde_json::json!({
+                    "type": "section",
+                    "title": format!("{} ({} ms)", result.label, result.latency_ms),
+                    "items": items,
+                })
+            })
+            .collect();
+        sections.push(serde_json::json!({
+            "type": "section",
+            "id": "compare_outputs",
+            "title": "Outputs",
+            "items_per_row": columns.len(),
+            "items": columns,
+        }));
+    }
+    sections
+}
+
+// --- Commands ---
+
+/// Runs the same inputs through two or more projects, each in its own Python process,
+/// and reports latency and the output fields where they disagree with the first one.
+/// The project loaded for the form is left untouched.
+#[tauri::command]
+pub async fn compare_models(
+    request: CompareRequest,
+    window: tauri::Window,
+    state: tauri::State<'_, AppState>,
+) -> Result<CompareReport, ChanterelleError> {
+    if request.targets.len() < 2 {
+        return Err(ChanterelleError::config("Pick at least two projects to compare"));
+    }
+    let projects_dir = {
+        let settings = state.settings.lock().unwrap();
+        settings.projects_directory.clone()
+    };
+    let inputs = collect_inputs(&projects_dir, &request)?;
+    if inputs.is_empty() {
+        return Err(ChanterelleError::config("Provide inputs or an inputs file to compare"));
+    }
+
+    let (mut targets, mut processes): (Vec<_>, Vec<_>) = warm_targets(&projects_dir, &request.targets).into_iter().unzip();
+    if processes[0].is_none() {
+        let error = targets.swap_remove(0).error.unwrap();
+        return Err(error.context("The baseline could not be started"));
+    }
+
+    let options = DiffOptions {
+        outputs: output_diff::read_model_outputs(&Path::new(&projects_dir).join(&request.targets[0].project_name)),
+        tolerances: request.tolerances,
+        default_tolerance: request.default_tolerance,
+    };
+
+    // Each target checks the inputs against its own declarations
+    let declared: Vec<_> = request
+        .targets
+        .iter()
+        .map(|target| validation::read_model_inputs(&Path::new(&projects_dir).join(&target.project_name)))
+        .collect();
+
+    let total = inputs.len();
+    let mut cases = Vec::with_capacity(total);
+    // Per target: summed latency and number of successful runs
+    let mut latencies = vec![(0u64, 0usize); targets.len()];
+    for (index, (case_id, case_inputs)) in inputs.into_iter().enumerate() {
+        // Targets run one after another so their latencies don't compete for the CPU
+        let mut results: Vec<CompareResult> = targets
+            .iter()
+            .zip(processes.iter_mut())
+            .zip(&declared)
+            .map(|((target, process), declared)| {
+                let Some(process) = process.as_mut() else {
+                    return CompareResult {
+                        label: target.label.clone(),
+                        outputs: None,
+                        error: target.error.clone(),
+                        latency_ms: 0,
+                        diffs: Vec::new(),
+                    };
+                };
+                let started = Instant::now();
+                let result = declared
+                    .clone()
+                    .and_then(|declared| validation::validate_inputs(&declared, case_inputs.clone()))
+                    .and_then(|inputs| python_runner_io::run_detached_model(process, &inputs));
+                let latency_ms = started.elapsed().as_millis() as u64;
+                let (outputs, error) = match result {
+                    Ok(outputs) => (Some(outputs), None),
+                    Err(e) => (None, Some(e)),
+                };
+                CompareResult { label: target.label.clone(), outputs, error, latency_ms, diffs: Vec::new() }
+            })
+            .collect();
+
+        let baseline = results[0].outputs.clone();
+        let mut disagreeing = BTreeSet::new();
+        for ((target, result), latency) in targets.iter_mut().zip(results.iter_mut()).zip(latencies.iter_mut()) {
+            if target.error.is_some() {
+                continue;
+            }
+            let Some(outputs) = &result.outputs else {
+                target.failed_cases += 1;
+                continue;
+            };
+            latency.0 += result.latency_ms;
+            latency.1 += 1;
+            if let Some(baseline) = &baseline {
+                result.diffs = output_diff::diff_outputs(baseline, outputs, &options);
+                if result.diffs.is_empty() {
+                    target.agreeing_cases += 1;
+                }
+                disagreeing.extend(result.diffs.iter().map(|d| d.output.clone().unwrap_or_else(|| d.path.clone())));
+            }
+        }
+        cases.push(CompareCase {
+            case_id,
+            inputs: case_inputs,
+            results,
+            disagreeing_fields: disagreeing.into_iter().collect(),
+        });
+
+        let progress = serde_json::json!({ "done": index + 1, "total": total });
+        let _ = window.emit("compare:progress", &progress);
+    }
+    drop(processes);
+
+    for (target, (sum, runs)) in targets.iter_mut().zip(latencies) {
+        if runs > 0 {
+            target.mean_latency_ms = Some(sum as f64 / runs as f64);
+        }
+    }
+
+    let sections = report_sections(&targets, &cases);
+    Ok(CompareReport { targets, cases, sections })
+}
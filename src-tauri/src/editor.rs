@@ -0,0 +1,138 @@
+// src-tauri/src/editor.rs
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::error::ChanterelleError;
+use crate::python_runner_io;
+use crate::settings::EditorSetting;
+use crate::state::AppState;
+
+/// Resolves a traceback frame to an absolute file path. Only files inside the
+/// project or a package installed in the project's own Python environment
+/// (site-packages/dist-packages under its prefix) are allowed.
+fn resolve_frame_path(project_dir: &Path, file: &str) -> Result<PathBuf, ChanterelleError> {
+    let candidate = if Path::new(file).is_absolute() {
+        PathBuf::from(file)
+    } else {
+        project_dir.join(file)
+    };
+
+    let resolved = candidate
+        .canonicalize()
+        .map_err(|e| ChanterelleError::io(format!("Cannot resolve '{}': {}", candidate.display(), e)))?;
+    let project_root = project_dir
+        .canonicalize()
+        .map_err(|e| ChanterelleError::config(format!("Cannot resolve project directory '{}': {}", project_dir.display(), e)))?;
+
+    let in_project = resolved.starts_with(&project_root);
+    // Asking the interpreter for its prefix starts Python, so only done when needed
+    let in_packages = || -> Result<bool, ChanterelleError> {
+        let in_site_packages = resolved
+            .components()
+            .any(|c| matches!(c.as_os_str().to_str(), Some("site-packages") | Some("dist-packages")));
+        if !in_site_packages {
+            return Ok(false);
+        }
+        let prefix = python_runner_io::python_prefix(project_dir)?;
+        let prefix = prefix
+            .canonicalize()
+            .map_err(|e| ChanterelleError::env(format!("Cannot resolve Python prefix '{}': {}", prefix.display(), e)))?;
+        Ok(resolved.starts_with(&prefix))
+    };
+    if !in_project && !in_packages()? {
+        return Err(ChanterelleError::config(format!(
+            "Refusing to open '{}': it is outside the project and its environment's site-packages",
+            resolved.display()
+        )));
+    }
+    if !resolved.is_file() {
+        return Err(ChanterelleError::config(format!("Not a file: {}", resolved.display())));
+    }
+
+    Ok(resolved)
+}
+
+/// Expands the configured editor into a command. The template is split on
+/// whitespace before substitution so paths with spaces stay a single argument.
+fn build_editor_command(editor: &EditorSetting, file: &Path, line: u64) -> Result<Command, ChanterelleError> {
+    let template = match editor {
+        EditorSetting::Vscode => "code -g {file}:{line}".to_string(),
+        EditorSetting::Env => {
+            let editor = std::env::var("VISUAL")
+                .or_else(|_| std::env::var("EDITOR"))
+                .map_err(|_| ChanterelleError::env("Neither $VISUAL nor $EDITOR is set"))?;
+            format!("{} +{{line}} {{file}}", editor)
+        }
+        EditorSetting::Custom { template } => template.clone(),
+    };
+
+    let file = file.to_string_lossy();
+    let line = line.to_string();
+    let mut parts = template
+        .split_whitespace()
+        .map(|part| part.replace("{file}", &file).replace("{line}", &line));
+    let program = parts
+        .next()
+        .ok_or_else(|| ChanterelleError::config("Editor command is empty"))?;
+
+    // Started directly rather than through `cmd /C`, which would interpret shell
+    // metacharacters in the file path
+    #[cfg(windows)]
+    let program = resolve_windows_program(&program);
+    let mut command = Command::new(program);
+    command.args(parts);
+
+    Ok(command)
+}
+
+/// Finds a bare program name on `PATH` with the `PATHEXT` extensions, since editors
+/// such as `code` are `.cmd` shims that `Command` would not find by itself. The
+/// standard library quotes arguments safely when running `.cmd` and `.bat` files.
+#[cfg(windows)]
+fn resolve_windows_program(program: &str) -> PathBuf {
+    let path = Path::new(program);
+    if path.components().count() > 1 || path.extension().is_some() {
+        return path.to_path_buf();
+    }
+    let extensions = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+    let dirs = std::env::var_os("PATH").unwrap_or_default();
+    for dir in std::env::split_paths(&dirs) {
+        for extension in extensions.split(';').filter(|e| !e.is_empty()) {
+            let candidate = dir.join(format!("{}{}", program, extension));
+            if candidate.is_file() {
+                return candidate;
+            }
+        }
+    }
+    path.to_path_buf()
+}
+
+// --- Commands ---
+
+#[tauri::command]
+pub async fn open_in_editor(
+    project_name: String,
+    file: String,
+    line: Option<u64>,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    let (projects_dir, editor) = {
+        let settings = state.settings.lock().unwrap();
+        (settings.projects_directory.clone(), settings.editor.clone())
+    };
+
+    let project_dir = Path::new(&projects_dir).join(&project_name);
+    let path = resolve_frame_path(&project_dir, &file)?;
+    let mut command = build_editor_command(&editor, &path, line.unwrap_or(1))?;
+
+    println!("Opening {} in editor", path.display());
+    let mut child = command
+        .spawn()
+        .map_err(|e| ChanterelleError::spawn(format!("Failed to launch editor: {}", e)))?;
+    // Reap the editor launcher in the background so it doesn't linger as a zombie
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+// src-tauri/src/error.rs
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// One frame of a Python traceback as reported by the base handlers.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TracebackFrame {
+    pub file: String,
+    /// Absolute path of the source file, when it exists on disk
+    #[serde(default)]
+    pub path: Option<String>,
+    pub line: u64,
+    pub function: String,
+    pub code: String,
+}
+
+/// An exception raised inside the user's handler code.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HandlerError {
+    /// Readable one-liner, e.g. "ValueError: bad input (in handler_io.py:12)"
+    pub message: String,
+    /// Python exception class name
+    pub error_type: Option<String>,
+    /// Handler function that failed: model_fn, input_fn, predict_fn, output_fn, ...
+    pub stage: Option<String>,
+    pub context: Option<String>,
+    pub traceback: Vec<TracebackFrame>,
+}
+
+impl HandlerError {
+    /// Builds a handler error from the detailed error dict produced by `format_detailed_error`.
+    pub fn from_detailed(value: &serde_json::Value) -> Self {
+        let text = |key: &str| value.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+        let message = text("summary")
+            .or_else(|| text("error"))
+            .unwrap_or_else(|| "Unknown handler error".to_string());
+        let traceback = value
+            .get("traceback")
+            .cloned()
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default();
+
+        Self {
+            message,
+            error_type: text("error_type"),
+            stage: text("stage"),
+            context: text("context").filter(|c| !c.is_empty()),
+            traceback,
+        }
+    }
+}
+
+/// A problem with one input value, reported before the handler is called.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FieldError {
+    /// Input name
+    pub field: String,
+    pub message: String,
+}
+
+/// A handler response field that breaks its `ModelOutput` declaration.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ContractViolation {
+    /// Output name
+    pub output: String,
+    /// Path of the field in the response
+    pub path: String,
+    pub message: String,
+}
+
+/// Error returned by every command. Serialised with a stable `code` tag so the
+/// frontend (and tests) can match on the kind of failure.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum ChanterelleError {
+    /// Missing or invalid settings and project files
+    Config { message: String },
+    /// Python environment could not be resolved
+    Env { message: String },
+    /// Handler process failed to start or is not running
+    Spawn { message: String },
+    /// Unexpected or malformed message on the stdio protocol
+    Protocol { message: String },
+    /// Exception raised by the user's handler
+    Handler(HandlerError),
+    /// Handler did not answer in time
+    Timeout { message: String },
+    /// Filesystem failure on the Rust side
+    Io { message: String },
+    /// Inputs that break the constraints in model_meta.json, one entry per field
+    Validation { message: String, fields: Vec<FieldError> },
+    /// Handler response that breaks the declared outputs, in strict mode
+    Contract { message: String, violations: Vec<ContractViolation> },
+}
+
+impl ChanterelleError {
+    pub fn config(message: impl Into<String>) -> Self {
+        Self::Config { message: message.into() }
+    }
+
+    pub fn env(message: impl Into<String>) -> Self {
+        Self::Env { message: message.into() }
+    }
+
+    pub fn spawn(message: impl Into<String>) -> Self {
+        Self::Spawn { message: message.into() }
+    }
+
+    pub fn protocol(message: impl Into<String>) -> Self {
+        Self::Protocol { message: message.into() }
+    }
+
+    pub fn timeout(message: impl Into<String>) -> Self {
+        Self::Timeout { message: message.into() }
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::Io { message: message.into() }
+    }
+
+    pub fn validation(fields: Vec<FieldError>) -> Self {
+        let details: Vec<&str> = fields.iter().map(|f| f.message.as_str()).collect();
+        Self::Validation { message: format!("Invalid inputs: {}", details.join("; ")), fields }
+    }
+
+    pub fn contract(violations: Vec<ContractViolation>) -> Self {
+        let details: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+        Self::Contract { message: format!("Output contract violated: {}", details.join("; ")), violations }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Config { message }
+            | Self::Env { message }
+            | Self::Spawn { message }
+            | Self::Protocol { message }
+            | Self::Timeout { message }
+            | Self::Io { message }
+            | Self::Validation { message, .. }
+            | Self::Contract { message, .. } => message,
+            Self::Handler(handler) => &handler.message,
+        }
+    }
+
+    /// Prefixes the message with extra context, keeping the error kind.
+    pub fn context(mut self, prefix: &str) -> Self {
+        match &mut self {
+            Self::Config { message }
+            | Self::Env { message }
+            | Self::Spawn { message }
+            | Self::Protocol { message }
+            | Self::Timeout { message }
+            | Self::Io { message }
+            | Self::Validation { message, .. }
+            | Self::Contract { message, .. } => *message = format!("{}: {}", prefix, message),
+            Self::Handler(handler) => handler.message = format!("{}: {}", prefix, handler.message),
+        }
+        self
+    }
+}
+
+impl fmt::Display for ChanterelleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for ChanterelleError {}
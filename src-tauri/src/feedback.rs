@@ -0,0 +1,452 @@
+// src-tauri/src/feedback.rs
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use crate::error::ChanterelleError;
+use crate::feedback_outbox::{self, FeedbackDelivery};
+use crate::feedback_query::FeedbackIndex;
+use crate::feedback_schema;
+use crate::history::HistoryEntry;
+use crate::state::AppState;
+use crate::storage;
+
+/// Held exclusively while the feedback file is read and rewritten, so other app
+/// instances don't interleave their writes. Lives in the project's data directory.
+const LOCK_FILE: &str = "feedback.jsonl.lock";
+const TMP_FILE: &str = "feedback.tmp";
+
+/// One feedback submission, a line of the project's feedback.jsonl.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeedbackEntry {
+    pub id: String,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub feedback: serde_json::Value,
+    /// The run the feedback is about, when it was given on a recorded prediction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<FeedbackRun>,
+    /// Whether the handler's `feedback_fn` has received the entry. Entries written
+    /// before the outbox existed have none and are only sent by `replay_feedback`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery: Option<FeedbackDelivery>,
+}
+
+impl FeedbackEntry {
+    pub fn new(feedback: serde_json::Value) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            timestamp: now_secs(),
+            feedback,
+            run: None,
+            delivery: None,
+        }
+    }
+}
+
+/// A copy of the history entry feedback refers to, so the link survives the
+/// history being pruned or deleted.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeedbackRun {
+    pub run_id: String,
+    pub model_version: Option<String>,
+    pub inputs: HashMap<String, serde_json::Value>,
+    pub outputs: Option<serde_json::Value>,
+    pub env_fingerprint: Option<String>,
+    /// Short hash of the handler and other project files the run used
+    pub artifacts_fingerprint: Option<String>,
+}
+
+impl From<HistoryEntry> for FeedbackRun {
+    fn from(entry: HistoryEntry) -> Self {
+        Self {
+            run_id: entry.id,
+            model_version: entry.model_version,
+            inputs: entry.inputs,
+            outputs: entry.outputs,
+            env_fingerprint: entry.env_fingerprint,
+            artifacts_fingerprint: entry.artifacts_fingerprint,
+        }
+    }
+}
+
+/// Filters for feedback exports and summaries. Every field is optional.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct FeedbackFilter {
+    /// Inclusive lower bound, seconds since the Unix epoch
+    pub since: Option<u64>,
+    /// Exclusive upper bound, seconds since the Unix epoch
+    pub until: Option<u64>,
+    /// Version of the linked run; feedback without a linked run never matches
+    pub model_version: Option<String>,
+    /// Exact value of the feedback's `rating`, e.g. "up"
+    pub rating: Option<serde_json::Value>,
+}
+
+impl FeedbackFilter {
+    pub fn matches(&self, entry: &FeedbackEntry) -> bool {
+        if self.since.is_some_and(|since| entry.timestamp < since) {
+            return false;
+        }
+        if self.until.is_some_and(|until| entry.timestamp >= until) {
+            return false;
+        }
+        if let Some(version) = &self.model_version {
+            let run_version = entry.run.as_ref().and_then(|run| run.model_version.as_ref());
+            if run_version != Some(version) {
+                return false;
+            }
+        }
+        if let Some(rating) = &self.rating {
+            if entry.feedback.get("rating") != Some(rating) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A line of feedback.jsonl. Lines that don't parse are kept as they are.
+enum FeedbackLine {
+    Entry(Box<FeedbackEntry>),
+    Raw(String),
+}
+
+/// Size and modification time of a feedback file, to notice changes made by
+/// other app instances or by hand
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FileStamp {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+fn file_stamp(model_dir: &Path) -> Option<FileStamp> {
+    let metadata = fs::metadata(feedback_path(model_dir)).ok()?;
+    Some(FileStamp { len: metadata.len(), modified: metadata.modified().ok() })
+}
+
+struct CachedIndex {
+    stamp: FileStamp,
+    index: Arc<FeedbackIndex>,
+}
+
+/// Feedback of every project, stored in feedback.jsonl in the project folder.
+/// Writes go through a temporary file and a rename, under an in-process lock and an
+/// advisory file lock, both in the project's data directory. Reads take no lock, so
+/// read-only project folders keep working. The last read of each file is kept as
+/// an index for queries.
+#[derive(Default)]
+pub struct FeedbackStore {
+    lock: Mutex<()>,
+    indexes: Mutex<HashMap<PathBuf, CachedIndex>>,
+}
+
+fn feedback_path(model_dir: &Path) -> PathBuf {
+    model_dir.join(storage::FEEDBACK_FILE)
+}
+
+fn lock_file(project_name: &str) -> Result<fs::File, ChanterelleError> {
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(storage::project_data_dir(project_name)?.join(LOCK_FILE))
+        .map_err(|e| ChanterelleError::io(format!("Failed to open feedback lock file: {}", e)))?;
+    file.lock()
+        .map_err(|e| ChanterelleError::io(format!("Failed to lock feedback file: {}", e)))?;
+    Ok(file)
+}
+
+fn now_secs() -> u64 {
+    storage::now_millis() / 1000
+}
+
+/// Id for an entry written before ids existed. It depends only on the line and its
+/// position, so reads of an unchanged file agree on it until a write stores it.
+fn legacy_id(index: usize, line: &str) -> String {
+    let digest = Sha256::new().chain_update(index.to_le_bytes()).chain_update(line).finalize();
+    uuid::Uuid::from_slice(&digest[..16]).unwrap().to_string()
+}
+
+/// Reads feedback.jsonl, giving entries written before ids existed an id.
+/// Returns whether any entry was migrated.
+fn read_lines(model_dir: &Path) -> Result<(Vec<FeedbackLine>, bool), ChanterelleError> {
+    let path = feedback_path(model_dir);
+    if !path.exists() {
+        return Ok((Vec::new(), false));
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to read feedback file: {}", e)))?;
+
+    let mut migrated = false;
+    let lines = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(index, line)| {
+            let Ok(mut value) = serde_json::from_str::<serde_json::Value>(line) else {
+                println!("Keeping unparseable feedback line as is");
+                return FeedbackLine::Raw(line.to_string());
+            };
+            if value.get("id").and_then(|id| id.as_str()).is_none() {
+                value["id"] = serde_json::json!(legacy_id(index, line));
+                migrated = true;
+            }
+            match serde_json::from_value::<FeedbackEntry>(value) {
+                Ok(entry) => FeedbackLine::Entry(Box::new(entry)),
+                Err(_) => FeedbackLine::Raw(line.to_string()),
+            }
+        })
+        .collect();
+    Ok((lines, migrated))
+}
+
+fn write_lines(project_name: &str, model_dir: &Path, lines: &[FeedbackLine]) -> Result<(), ChanterelleError> {
+    let mut contents = String::new();
+    for line in lines {
+        match line {
+            FeedbackLine::Entry(entry) => contents.push_str(&serde_json::to_string(entry).unwrap()),
+            FeedbackLine::Raw(raw) => contents.push_str(raw),
+        }
+        contents.push('\n');
+    }
+    let tmp_path = storage::project_data_dir(project_name)?.join(TMP_FILE);
+    storage::write_atomic_via(&tmp_path, &feedback_path(model_dir), contents.as_bytes())
+}
+
+impl FeedbackStore {
+    /// Runs a read-modify-write of the feedback file under both locks. The file is
+    /// rewritten when `update` reports a change or old entries were migrated, and
+    /// the index is rebuilt from what the file now holds.
+    fn modify<T>(
+        &self,
+        project_name: &str,
+        model_dir: &Path,
+        update: impl FnOnce(&mut Vec<FeedbackLine>) -> (T, bool),
+    ) -> Result<T, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let _file_lock = lock_file(project_name)?;
+        let (mut lines, migrated) = read_lines(model_dir)?;
+        let (result, changed) = update(&mut lines);
+        if changed || migrated {
+            write_lines(project_name, model_dir, &lines)?;
+        }
+        match file_stamp(model_dir) {
+            Some(stamp) => {
+                self.cache_index(model_dir, stamp, lines);
+            }
+            None => {
+                self.indexes.lock().unwrap().remove(model_dir);
+            }
+        }
+        Ok(result)
+    }
+
+    fn cache_index(&self, model_dir: &Path, stamp: FileStamp, lines: Vec<FeedbackLine>) -> Arc<FeedbackIndex> {
+        let entries = lines
+            .into_iter()
+            .filter_map(|line| match line {
+                FeedbackLine::Entry(entry) => Some(*entry),
+                FeedbackLine::Raw(_) => None,
+            })
+            .collect();
+        let index = Arc::new(FeedbackIndex::new(entries));
+        self.indexes
+            .lock()
+            .unwrap()
+            .insert(model_dir.to_path_buf(), CachedIndex { stamp, index: index.clone() });
+        index
+    }
+
+    /// Index of a project's feedback. The file is only read again when it changed
+    /// since the last read. Reading takes no file lock and writes nothing.
+    pub fn index(&self, model_dir: &Path) -> Result<Arc<FeedbackIndex>, ChanterelleError> {
+        // Stamped before reading, so a write in between makes the next call read again
+        let Some(stamp) = file_stamp(model_dir) else {
+            return Ok(Arc::default());
+        };
+        if let Some(cached) = self.indexes.lock().unwrap().get(model_dir).filter(|c| c.stamp == stamp) {
+            return Ok(cached.index.clone());
+        }
+        let (lines, _) = read_lines(model_dir)?;
+        Ok(self.cache_index(model_dir, stamp, lines))
+    }
+
+    /// Every entry of a project, oldest first.
+    pub fn list(&self, model_dir: &Path) -> Result<Vec<FeedbackEntry>, ChanterelleError> {
+        Ok(self.index(model_dir)?.entries().to_vec())
+    }
+
+    pub fn append(&self, project_name: &str, model_dir: &Path, entry: &FeedbackEntry) -> Result<(), ChanterelleError> {
+        self.modify(project_name, model_dir, |lines| {
+            lines.push(FeedbackLine::Entry(Box::new(entry.clone())));
+            ((), true)
+        })
+    }
+
+    /// Removes an entry; returns whether it existed.
+    pub fn delete(&self, project_name: &str, model_dir: &Path, id: &str) -> Result<bool, ChanterelleError> {
+        self.modify(project_name, model_dir, |lines| {
+            let before = lines.len();
+            lines.retain(|line| !matches!(line, FeedbackLine::Entry(entry) if entry.id == id));
+            let removed = lines.len() != before;
+            (removed, removed)
+        })
+    }
+
+    /// Sets the delivery state of the given entries.
+    pub fn record_deliveries(
+        &self,
+        project_name: &str,
+        model_dir: &Path,
+        deliveries: &HashMap<String, FeedbackDelivery>,
+    ) -> Result<(), ChanterelleError> {
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+        self.modify(project_name, model_dir, |lines| {
+            for line in lines.iter_mut() {
+                if let FeedbackLine::Entry(entry) = line {
+                    if let Some(delivery) = deliveries.get(&entry.id) {
+                        entry.delivery = Some(delivery.clone());
+                    }
+                }
+            }
+            ((), true)
+        })
+    }
+
+    /// Applies `update` to the feedback of one entry; returns whether it existed.
+    /// Nothing is written when `update` fails.
+    pub fn update(
+        &self,
+        project_name: &str,
+        model_dir: &Path,
+        id: &str,
+        update: impl FnOnce(&mut serde_json::Value) -> Result<(), ChanterelleError>,
+    ) -> Result<bool, ChanterelleError> {
+        self.modify(project_name, model_dir, |lines| {
+            let entry = lines.iter_mut().find_map(|line| match line {
+                FeedbackLine::Entry(entry) if entry.id == id => Some(entry),
+                _ => None,
+            });
+            let Some(entry) = entry else {
+                return (Ok(false), false);
+            };
+            let mut feedback = entry.feedback.clone();
+            match update(&mut feedback) {
+                Ok(()) => {
+                    entry.feedback = feedback;
+                    (Ok(true), true)
+                }
+                Err(e) => (Err(e), false),
+            }
+        })?
+    }
+}
+
+pub fn model_dir(state: &AppState, project_name: &str) -> PathBuf {
+    let settings = state.settings.lock().unwrap();
+    Path::new(&settings.projects_directory).join(project_name)
+}
+
+// --- Commands ---
+
+/// Saves feedback and hands it to the running handler. Returns the new entry id.
+/// Feedback must match the project's `feedback_schema` when it declares one.
+/// With a `run_id` from the run history, the inputs, outputs and versions of that
+/// run are stored with the feedback.
+#[tauri::command]
+pub async fn submit_feedback(
+    project_name: String,
+    feedback: serde_json::Value,
+    run_id: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> Result<String, ChanterelleError> {
+    let model_dir = model_dir(&state, &project_name);
+    if let Some(schema) = feedback_schema::read_schema(&model_dir)? {
+        schema.validate(&feedback)?;
+    }
+    let run = match run_id {
+        Some(run_id) => {
+            let entry = state.history.get(&project_name, &run_id)?.ok_or_else(|| {
+                ChanterelleError::config(format!("Run '{}' not found in the history of '{}'", run_id, project_name))
+            })?;
+            Some(FeedbackRun::from(entry))
+        }
+        None => None,
+    };
+    let entry = FeedbackEntry {
+        run,
+        delivery: feedback_outbox::initial_delivery(&model_dir),
+        ..FeedbackEntry::new(feedback)
+    };
+    state.feedback.append(&project_name, &model_dir, &entry)?;
+
+    // Hand it to the handler now if it is running; otherwise it waits for the next warmup
+    if let Err(e) = feedback_outbox::deliver(&state, &project_name, &model_dir, std::slice::from_ref(&entry)) {
+        println!("Failed to deliver feedback to the handler: {}", e);
+    }
+    Ok(entry.id)
+}
+
+#[tauri::command]
+pub async fn delete_feedback(
+    project_name: String,
+    id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<bool, ChanterelleError> {
+    state.feedback.delete(&project_name, &model_dir(&state, &project_name), &id)
+}
+
+/// Replaces the `context` of an entry, e.g. when an interactive session continues,
+/// and sets the given `fields`. Changed fields are checked against the project's
+/// `feedback_schema`.
+#[tauri::command]
+pub async fn update_feedback(
+    project_name: String,
+    id: String,
+    context: Option<serde_json::Value>,
+    fields: Option<serde_json::Map<String, serde_json::Value>>,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    let model_dir = model_dir(&state, &project_name);
+    // Context-only updates keep entries written before the schema existed editable
+    let schema = match fields {
+        Some(_) => feedback_schema::read_schema(&model_dir)?,
+        None => None,
+    };
+    let found = state.feedback.update(&project_name, &model_dir, &id, |feedback| {
+        for (key, value) in fields.into_iter().flatten() {
+            feedback[key.as_str()] = value;
+        }
+        if let Some(context) = context {
+            feedback["context"] = context;
+        }
+        match &schema {
+            Some(schema) => schema.validate(feedback),
+            None => Ok(()),
+        }
+    })?;
+    if !found {
+        return Err(ChanterelleError::config(format!("Feedback entry '{}' not found", id)));
+    }
+    Ok(())
+}
+
+/// Every feedback entry of a project, newest first. `query_feedback` pages
+/// through large projects.
+#[tauri::command]
+pub async fn get_feedback_history(
+    project_name: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<FeedbackEntry>, ChanterelleError> {
+    let mut history = state.feedback.list(&model_dir(&state, &project_name))?;
+    history.reverse();
+    Ok(history)
+}
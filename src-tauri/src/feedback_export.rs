@@ -0,0 +1,391 @@
+// src-tauri/src/feedback_export.rs
+// Writes feedback joined with the prediction it is about as a dataset for retraining.
+use parquet::basic::{LogicalType, Repetition, Type as PhysicalType};
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type};
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::error::ChanterelleError;
+use crate::feedback::{self, FeedbackEntry, FeedbackFilter};
+use crate::sessions;
+use crate::state::AppState;
+
+/// Columns every tabular export starts with, in this order
+const LEADING_COLUMNS: [&str; 5] = ["id", "timestamp", "run_id", "model_version", "artifacts_fingerprint"];
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackExportFormat {
+    Csv,
+    Jsonl,
+    Parquet,
+    /// `{"messages": [...]}` lines for chat fine-tuning, interactive projects only
+    ChatJsonl,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FeedbackExportRequest {
+    pub format: FeedbackExportFormat,
+    /// File to write, replaced when it exists
+    pub path: String,
+    #[serde(default)]
+    pub filter: FeedbackFilter,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct FeedbackExport {
+    pub path: String,
+    pub rows: usize,
+    /// Flattened column names; empty for JSONL formats
+    pub columns: Vec<String>,
+}
+
+/// Feedback fields other than the UI context, e.g. rating and comment.
+fn feedback_fields(entry: &FeedbackEntry) -> serde_json::Map<String, serde_json::Value> {
+    let mut fields = entry.feedback.as_object().cloned().unwrap_or_default();
+    fields.remove("context");
+    fields
+}
+
+/// One entry joined with its inputs and outputs. Linked runs give the exact values
+/// the handler saw; older entries fall back to what the form showed.
+fn dataset_row(entry: &FeedbackEntry) -> serde_json::Value {
+    let run = entry.run.as_ref();
+    let context = &entry.feedback["context"];
+    let mut row = serde_json::Map::new();
+    row.insert("id".into(), serde_json::json!(entry.id));
+    row.insert("timestamp".into(), serde_json::json!(entry.timestamp));
+    row.insert("run_id".into(), serde_json::json!(run.map(|r| &r.run_id)));
+    row.insert("model_version".into(), serde_json::json!(run.and_then(|r| r.model_version.as_ref())));
+    row.insert(
+        "artifacts_fingerprint".into(),
+        serde_json::json!(run.and_then(|r| r.artifacts_fingerprint.as_ref())),
+    );
+    for (key, value) in feedback_fields(entry) {
+        row.entry(key).or_insert(value);
+    }
+    let inputs = run.map(|r| serde_json::json!(r.inputs)).unwrap_or_else(|| context["inputs"].clone());
+    let outputs = run.and_then(|r| r.outputs.clone()).unwrap_or_else(|| context["outputs"].clone());
+    for (key, value) in [("inputs", inputs), ("outputs", outputs)] {
+        if !value.is_null() {
+            row.insert(key.into(), value);
+        }
+    }
+    serde_json::Value::Object(row)
+}
+
+/// Flattens nested objects and arrays into `a.b.0`-style columns.
+fn flatten(prefix: &str, value: &serde_json::Value, out: &mut Vec<(String, serde_json::Value)>) {
+    let key = |part: &str| if prefix.is_empty() { part.to_string() } else { format!("{}.{}", prefix, part) };
+    match value {
+        serde_json::Value::Object(map) if !map.is_empty() => {
+            for (name, value) in map {
+                flatten(&key(name), value, out);
+            }
+        }
+        serde_json::Value::Array(items) if !items.is_empty() => {
+            for (i, value) in items.iter().enumerate() {
+                flatten(&key(&i.to_string()), value, out);
+            }
+        }
+        _ => out.push((prefix.to_string(), value.clone())),
+    }
+}
+
+/// Rows keyed by flattened column, with the columns in first-seen order.
+struct Table {
+    columns: Vec<String>,
+    rows: Vec<HashMap<String, serde_json::Value>>,
+}
+
+impl Table {
+    fn new(entries: &[FeedbackEntry]) -> Self {
+        let mut columns: Vec<String> = LEADING_COLUMNS.iter().map(|c| c.to_string()).collect();
+        let mut seen: HashSet<String> = columns.iter().cloned().collect();
+        let mut rows = Vec::new();
+        for entry in entries {
+            let mut cells = Vec::new();
+            flatten("", &dataset_row(entry), &mut cells);
+            for (column, _) in &cells {
+                if seen.insert(column.clone()) {
+                    columns.push(column.clone());
+                }
+            }
+            rows.push(cells.into_iter().collect());
+        }
+        Self { columns, rows }
+    }
+
+    fn column(&self, name: &str) -> Vec<&serde_json::Value> {
+        self.rows.iter().map(|row| row.get(name).unwrap_or(&serde_json::Value::Null)).collect()
+    }
+}
+
+fn cell_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn to_csv(table: &Table) -> String {
+    let mut out = String::new();
+    let header: Vec<String> = table.columns.iter().map(|c| csv_field(c)).collect();
+    out.push_str(&header.join(","));
+    out.push_str("\r\n");
+    for row in &table.rows {
+        let cells: Vec<String> = table
+            .columns
+            .iter()
+            .map(|c| csv_field(&cell_text(row.get(c).unwrap_or(&serde_json::Value::Null))))
+            .collect();
+        out.push_str(&cells.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+enum ColumnKind {
+    Boolean,
+    Int,
+    Double,
+    Text,
+}
+
+fn column_kind(values: &[&serde_json::Value]) -> ColumnKind {
+    let present: Vec<&&serde_json::Value> = values.iter().filter(|v| !v.is_null()).collect();
+    if present.is_empty() {
+        ColumnKind::Text
+    } else if present.iter().all(|v| v.is_boolean()) {
+        ColumnKind::Boolean
+    } else if present.iter().all(|v| v.is_i64()) {
+        ColumnKind::Int
+    } else if present.iter().all(|v| v.is_number()) {
+        ColumnKind::Double
+    } else {
+        ColumnKind::Text
+    }
+}
+
+fn parquet_error(e: ParquetError) -> ChanterelleError {
+    ChanterelleError::io(format!("Failed to write Parquet file: {}", e))
+}
+
+/// Writes one row group of optional columns, typed from their values. Columns
+/// mixing types are written as text.
+fn write_parquet(path: &Path, table: &Table) -> Result<(), ChanterelleError> {
+    let kinds: Vec<ColumnKind> = table.columns.iter().map(|c| column_kind(&table.column(c))).collect();
+    let fields = table
+        .columns
+        .iter()
+        .zip(&kinds)
+        .map(|(name, kind)| {
+            let builder = match kind {
+                ColumnKind::Boolean => Type::primitive_type_builder(name, PhysicalType::BOOLEAN),
+                ColumnKind::Int => Type::primitive_type_builder(name, PhysicalType::INT64),
+                ColumnKind::Double => Type::primitive_type_builder(name, PhysicalType::DOUBLE),
+                ColumnKind::Text => Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+                    .with_logical_type(Some(LogicalType::String)),
+            };
+            builder.with_repetition(Repetition::OPTIONAL).build().map(Arc::new)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(parquet_error)?;
+    let schema = Type::group_type_builder("feedback").with_fields(fields).build().map_err(parquet_error)?;
+
+    let file = fs::File::create(path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create '{}': {}", path.display(), e)))?;
+    let mut writer = SerializedFileWriter::new(file, Arc::new(schema), Arc::new(WriterProperties::builder().build()))
+        .map_err(parquet_error)?;
+    let mut group = writer.next_row_group().map_err(parquet_error)?;
+    for (name, kind) in table.columns.iter().zip(&kinds) {
+        let values = table.column(name);
+        let levels: Vec<i16> = values.iter().map(|v| i16::from(!v.is_null())).collect();
+        let mut column = group
+            .next_column()
+            .map_err(parquet_error)?
+            .ok_or_else(|| ChanterelleError::io(format!("Parquet schema has no column '{}'", name)))?;
+        let written = match kind {
+            ColumnKind::Boolean => {
+                let data: Vec<bool> = values.iter().filter_map(|v| v.as_bool()).collect();
+                column.typed::<BoolType>().write_batch(&data, Some(&levels), None)
+            }
+            ColumnKind::Int => {
+                let data: Vec<i64> = values.iter().filter_map(|v| v.as_i64()).collect();
+                column.typed::<Int64Type>().write_batch(&data, Some(&levels), None)
+            }
+            ColumnKind::Double => {
+                let data: Vec<f64> = values.iter().filter_map(|v| v.as_f64()).collect();
+                column.typed::<DoubleType>().write_batch(&data, Some(&levels), None)
+            }
+            ColumnKind::Text => {
+                let data: Vec<ByteArray> = values
+                    .iter()
+                    .filter(|v| !v.is_null())
+                    .map(|v| ByteArray::from(cell_text(v).as_str()))
+                    .collect();
+                column.typed::<ByteArrayType>().write_batch(&data, Some(&levels), None)
+            }
+        };
+        written.map_err(parquet_error)?;
+        column.close().map_err(parquet_error)?;
+    }
+    group.close().map_err(parquet_error)?;
+    writer.close().map_err(parquet_error)?;
+    Ok(())
+}
+
+/// Keeps the turns up to the agent turn that was rated. `None` when the rated
+/// output is not among them.
+fn turns_until_rated(mut turns: Vec<serde_json::Value>, rated: &serde_json::Value) -> Option<Vec<serde_json::Value>> {
+    let end = turns
+        .iter()
+        .rposition(|turn| turn["type"] == "agent" && (rated.is_null() || turn["content"] == *rated))?;
+    turns.truncate(end + 1);
+    Some(turns)
+}
+
+/// The conversation that led to a rated reply, from the stored session transcript,
+/// or from the turns saved with the feedback when the transcript lacks the reply.
+fn chat_example(project_name: &str, entry: &FeedbackEntry) -> Option<serde_json::Value> {
+    let context = &entry.feedback["context"];
+    let rated = &context["outputs"];
+    let stored = context["sessionId"]
+        .as_str()
+        .and_then(|session_id| sessions::read_session_turns(project_name, session_id).ok())
+        .and_then(|turns| turns_until_rated(turns, rated));
+    let turns = stored.or_else(|| {
+        let saved = context["sessionTurns"].as_array()?.clone();
+        turns_until_rated(saved, rated)
+    })?;
+    Some(serde_json::json!({ "messages": sessions::chat_messages(&turns) }))
+}
+
+fn to_jsonl(lines: impl Iterator<Item = serde_json::Value>) -> String {
+    lines.map(|line| serde_json::to_string(&line).unwrap() + "\n").collect()
+}
+
+// --- Commands ---
+
+/// Writes the matching feedback of a project to `request.path`.
+#[tauri::command]
+pub async fn export_feedback(
+    project_name: String,
+    request: FeedbackExportRequest,
+    state: tauri::State<'_, AppState>,
+) -> Result<FeedbackExport, ChanterelleError> {
+    let model_dir = feedback::model_dir(&state, &project_name);
+    let entries: Vec<FeedbackEntry> = state
+        .feedback
+        .list(&model_dir)?
+        .into_iter()
+        .filter(|entry| request.filter.matches(entry))
+        .collect();
+    let path = Path::new(&request.path);
+    let write = |content: String| {
+        fs::write(path, content)
+            .map_err(|e| ChanterelleError::io(format!("Failed to write export to '{}': {}", request.path, e)))
+    };
+
+    let (rows, columns) = match request.format {
+        FeedbackExportFormat::Jsonl => {
+            write(to_jsonl(entries.iter().map(dataset_row)))?;
+            (entries.len(), Vec::new())
+        }
+        FeedbackExportFormat::ChatJsonl => {
+            if !model_dir.join("interactive.json").exists() {
+                return Err(ChanterelleError::config("Chat exports are only available for interactive projects"));
+            }
+            let examples: Vec<serde_json::Value> =
+                entries.iter().filter_map(|entry| chat_example(&project_name, entry)).collect();
+            let rows = examples.len();
+            write(to_jsonl(examples.into_iter()))?;
+            (rows, Vec::new())
+        }
+        FeedbackExportFormat::Csv => {
+            let table = Table::new(&entries);
+            write(to_csv(&table))?;
+            (table.rows.len(), table.columns)
+        }
+        FeedbackExportFormat::Parquet => {
+            let table = Table::new(&entries);
+            write_parquet(path, &table)?;
+            (table.rows.len(), table.columns)
+        }
+    };
+    println!("Exported {} feedback rows of '{}' to {}", rows, project_name, request.path);
+    Ok(FeedbackExport { path: request.path, rows, columns })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Cells sorted by column, since object key order depends on serde_json features.
+    fn flattened(value: serde_json::Value) -> Vec<(String, serde_json::Value)> {
+        let mut out = Vec::new();
+        flatten("", &value, &mut out);
+        out.sort_by(|a, b| a.0.cmp(&b.0));
+        out
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed() {
+        assert_eq!(csv_field("plain text"), "plain text");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(csv_field("cr\r"), "\"cr\r\"");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn flatten_names_nested_values_by_path() {
+        let cells = flattened(json!({
+            "rating": "up",
+            "inputs": { "x": 1, "point": { "lat": 2.5 } },
+            "labels": ["a", "b"]
+        }));
+        assert_eq!(
+            cells,
+            vec![
+                ("inputs.point.lat".to_string(), json!(2.5)),
+                ("inputs.x".to_string(), json!(1)),
+                ("labels.0".to_string(), json!("a")),
+                ("labels.1".to_string(), json!("b")),
+                ("rating".to_string(), json!("up")),
+            ]
+        );
+    }
+
+    #[test]
+    fn flatten_keeps_empty_containers_and_scalars_as_cells() {
+        let cells = flattened(json!({ "empty": {}, "none": [], "nothing": null }));
+        assert_eq!(
+            cells,
+            vec![
+                ("empty".to_string(), json!({})),
+                ("none".to_string(), json!([])),
+                ("nothing".to_string(), serde_json::Value::Null),
+            ]
+        );
+        assert_eq!(flattened(json!(3)), vec![(String::new(), json!(3))]);
+    }
+}
@@ -0,0 +1,205 @@
+// src-tauri/src/feedback_outbox.rs
+// Tracks which feedback the handler's `feedback_fn` has received, so feedback given
+// while no process was running reaches it on the next warmup.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::error::ChanterelleError;
+use crate::feedback::{self, FeedbackEntry, FeedbackFilter};
+use crate::python_runner_io;
+use crate::state::AppState;
+use crate::storage;
+
+/// Failed deliveries retried by warmups before an entry is given up on.
+const MAX_ATTEMPTS: u32 = 5;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    /// Not yet handed to a running handler
+    Pending,
+    /// Accepted by `feedback_fn`
+    Delivered,
+    /// The handler had no `feedback_fn`; resent only by `replay_feedback`
+    Ignored,
+    /// `feedback_fn` raised or the handler did not answer; retried on the next warmup
+    Failed,
+    /// Failed `MAX_ATTEMPTS` times; resent only by `replay_feedback`
+    Abandoned,
+}
+
+/// Outbox state of a feedback entry.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeedbackDelivery {
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    /// Seconds since the Unix epoch
+    pub attempted_at: Option<u64>,
+    /// Short hash of the project files of the handler that last received the entry
+    pub artifacts_fingerprint: Option<String>,
+    pub error: Option<String>,
+}
+
+impl FeedbackDelivery {
+    fn is_due(&self) -> bool {
+        matches!(self.status, DeliveryStatus::Pending | DeliveryStatus::Failed)
+    }
+}
+
+/// How many entries a delivery handed over, by outcome.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct DeliveryReport {
+    pub attempted: usize,
+    pub delivered: usize,
+    pub ignored: usize,
+    pub failed: usize,
+}
+
+/// Only model handlers have a `feedback_fn`; interactive feedback is not delivered.
+fn has_feedback_fn(model_dir: &Path) -> bool {
+    !model_dir.join("interactive.json").exists()
+}
+
+/// Delivery state of a new entry.
+pub fn initial_delivery(model_dir: &Path) -> Option<FeedbackDelivery> {
+    has_feedback_fn(model_dir).then_some(FeedbackDelivery {
+        status: DeliveryStatus::Pending,
+        attempts: 0,
+        attempted_at: None,
+        artifacts_fingerprint: None,
+        error: None,
+    })
+}
+
+/// What `feedback_fn` receives: the submitted feedback with the entry id, so
+/// handlers can recognise replays, and the linked run.
+fn payload(entry: &FeedbackEntry) -> serde_json::Value {
+    let mut payload = entry.feedback.clone();
+    if let Some(fields) = payload.as_object_mut() {
+        fields.insert("id".into(), serde_json::json!(entry.id));
+        if let Some(run) = &entry.run {
+            fields.insert("run".into(), serde_json::json!(run));
+        }
+    }
+    payload
+}
+
+/// Hands entries to the running handler and records the outcome of each. Nothing
+/// is sent unless the process was started for this project. A handler that stops
+/// answering ends the delivery; the remaining entries keep their state.
+pub fn deliver(
+    state: &AppState,
+    project_name: &str,
+    model_dir: &Path,
+    entries: &[FeedbackEntry],
+) -> Result<DeliveryReport, ChanterelleError> {
+    let mut report = DeliveryReport::default();
+    if entries.is_empty() || !has_feedback_fn(model_dir) {
+        return Ok(report);
+    }
+    let active = state.active_project.lock().unwrap().clone();
+    let Some(active) = active.filter(|p| p.project_name == project_name) else {
+        return Ok(report);
+    };
+
+    let mut deliveries = HashMap::new();
+    {
+        let mut guard = state.python_process.lock().unwrap();
+        let Some(process) = guard.as_mut() else {
+            return Ok(report);
+        };
+        for entry in entries {
+            let (status, error) = match python_runner_io::send_feedback(process, &payload(entry)) {
+                Ok(response) => match response["status"].as_str() {
+                    Some("success") => (DeliveryStatus::Delivered, None),
+                    Some("ignored") => (DeliveryStatus::Ignored, None),
+                    _ => {
+                        let error = match &response["error"] {
+                            serde_json::Value::Null => format!("Unexpected response: {}", response),
+                            serde_json::Value::String(s) => s.clone(),
+                            other => other.to_string(),
+                        };
+                        (DeliveryStatus::Failed, Some(error))
+                    }
+                },
+                // feedback_fn raised; the handler is still answering
+                Err(ChanterelleError::Handler(handler)) => (DeliveryStatus::Failed, Some(handler.message)),
+                Err(e) => {
+                    println!("Stopping feedback delivery: {}", e);
+                    break;
+                }
+            };
+            let attempts = entry.delivery.as_ref().map_or(0, |d| d.attempts) + 1;
+            let status = match status {
+                DeliveryStatus::Failed if attempts >= MAX_ATTEMPTS => DeliveryStatus::Abandoned,
+                status => status,
+            };
+            report.attempted += 1;
+            match status {
+                DeliveryStatus::Delivered => report.delivered += 1,
+                DeliveryStatus::Ignored => report.ignored += 1,
+                _ => report.failed += 1,
+            }
+            deliveries.insert(
+                entry.id.clone(),
+                FeedbackDelivery {
+                    status,
+                    attempts,
+                    attempted_at: Some(storage::now_millis() / 1000),
+                    artifacts_fingerprint: active.artifacts_fingerprint.clone(),
+                    error,
+                },
+            );
+        }
+    }
+    state.feedback.record_deliveries(project_name, model_dir, &deliveries)?;
+    Ok(report)
+}
+
+/// Delivers the entries that are pending or failed fewer than `MAX_ATTEMPTS` times,
+/// oldest first. Called once the project's handler is loaded.
+pub fn deliver_pending(state: &AppState, project_name: &str, model_dir: &Path) -> Result<DeliveryReport, ChanterelleError> {
+    if !has_feedback_fn(model_dir) {
+        return Ok(DeliveryReport::default());
+    }
+    let due: Vec<FeedbackEntry> = state
+        .feedback
+        .list(model_dir)?
+        .into_iter()
+        .filter(|entry| entry.delivery.as_ref().is_some_and(|d| d.is_due()))
+        .collect();
+    deliver(state, project_name, model_dir, &due)
+}
+
+// --- Commands ---
+
+/// Re-sends feedback to the project's handler whatever its delivery state, e.g.
+/// after adding a `feedback_fn` or fixing one that gave up on entries. Sends the given `ids`, or every entry matching
+/// `filter`, oldest first. Loads the project first when it is not running.
+#[tauri::command]
+pub async fn replay_feedback(
+    project_name: String,
+    ids: Option<Vec<String>>,
+    filter: Option<FeedbackFilter>,
+    state: tauri::State<'_, AppState>,
+) -> Result<DeliveryReport, ChanterelleError> {
+    let model_dir = feedback::model_dir(&state, &project_name);
+    if !has_feedback_fn(&model_dir) {
+        return Err(ChanterelleError::config("Only model projects have a feedback_fn to replay feedback to"));
+    }
+    let projects_dir = state.settings.lock().unwrap().projects_directory.clone();
+    python_runner_io::ensure_model_loaded(&projects_dir, &project_name, state.clone()).await?;
+
+    let filter = filter.unwrap_or_default();
+    let entries: Vec<FeedbackEntry> = state
+        .feedback
+        .list(&model_dir)?
+        .into_iter()
+        .filter(|entry| ids.as_ref().is_none_or(|ids| ids.contains(&entry.id)))
+        .filter(|entry| filter.matches(entry))
+        .collect();
+    let report = deliver(&state, &project_name, &model_dir, &entries)?;
+    println!("Replayed feedback of '{}': {:?}", project_name, report);
+    Ok(report)
+}
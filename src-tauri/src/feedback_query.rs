@@ -0,0 +1,384 @@
+// src-tauri/src/feedback_query.rs
+// Paginated, filtered and sorted reads of feedback.jsonl through an in-memory index.
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use crate::error::ChanterelleError;
+use crate::feedback::{self, FeedbackEntry, FeedbackFilter};
+use crate::state::AppState;
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 500;
+
+/// Feedback key holding what the form showed; never searched
+const CONTEXT_KEY: &str = "context";
+
+/// Parsed entries of a feedback file with what queries look up, built when the file
+/// is read and kept by the store until the file changes.
+#[derive(Default)]
+pub struct FeedbackIndex {
+    /// File order, oldest first
+    entries: Vec<FeedbackEntry>,
+    /// Positions in `entries` ordered by timestamp
+    by_time: Vec<usize>,
+    /// Lowercased comment and other text of each entry
+    text: Vec<String>,
+}
+
+/// Text values of the feedback fields, e.g. the comment, a `text` field or labels.
+fn searchable_text(entry: &FeedbackEntry) -> String {
+    let mut text = String::new();
+    let Some(fields) = entry.feedback.as_object() else {
+        return text;
+    };
+    for (key, value) in fields {
+        if key == CONTEXT_KEY {
+            continue;
+        }
+        let values = match value {
+            serde_json::Value::Array(items) => items.iter().collect(),
+            other => vec![other],
+        };
+        for value in values {
+            if let Some(s) = value.as_str() {
+                text.push_str(&s.to_lowercase());
+                text.push('\n');
+            }
+        }
+    }
+    text
+}
+
+impl FeedbackIndex {
+    pub fn new(entries: Vec<FeedbackEntry>) -> Self {
+        let mut by_time: Vec<usize> = (0..entries.len()).collect();
+        by_time.sort_by_key(|&i| entries[i].timestamp);
+        let text = entries.iter().map(searchable_text).collect();
+        Self { entries, by_time, text }
+    }
+
+    /// Every entry, oldest first.
+    pub fn entries(&self) -> &[FeedbackEntry] {
+        &self.entries
+    }
+
+    /// Positions of the entries from `since` (inclusive) to `until` (exclusive).
+    fn in_range(&self, since: Option<u64>, until: Option<u64>) -> &[usize] {
+        let timestamp = |i: &usize| self.entries[*i].timestamp;
+        let start = since.map_or(0, |since| self.by_time.partition_point(|i| timestamp(i) < since));
+        let end = until.map_or(self.by_time.len(), |until| self.by_time.partition_point(|i| timestamp(i) < until));
+        &self.by_time[start..end.max(start)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// Filters, sorting and pagination for `query_feedback`. Every field is optional.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct FeedbackQuery {
+    /// Date range, model version and rating, as for exports
+    #[serde(flatten)]
+    pub filter: FeedbackFilter,
+    /// Feedback fields that must equal the given values. A field holding a list,
+    /// e.g. a label field with `multiple`, matches when it contains the value.
+    pub fields: serde_json::Map<String, serde_json::Value>,
+    /// Case-insensitive substring matched against the comment and other text fields
+    pub text: Option<String>,
+    /// Feedback field to sort by, e.g. "score"; entries without it come last.
+    /// Sorted by timestamp when unset.
+    pub sort_by: Option<String>,
+    pub order: SortOrder,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct FeedbackPage {
+    pub entries: Vec<FeedbackEntry>,
+    /// Number of entries matching the filters, before pagination
+    pub total: usize,
+    /// Cursor of the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Where an entry falls in the sort order. Timestamp and id break ties, so every
+/// entry has its own place and a cursor stays valid when entries are added or removed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SortKey {
+    value: serde_json::Value,
+    timestamp: u64,
+    id: String,
+}
+
+/// Position after the last entry of a page, for the same sort.
+#[derive(Serialize, Deserialize, Debug)]
+struct Cursor {
+    sort_by: Option<String>,
+    order: SortOrder,
+    after: SortKey,
+}
+
+fn sort_key(entry: &FeedbackEntry, sort_by: Option<&str>) -> SortKey {
+    SortKey {
+        value: sort_by.map_or(serde_json::Value::Null, |field| entry.feedback[field].clone()),
+        timestamp: entry.timestamp,
+        id: entry.id.clone(),
+    }
+}
+
+fn type_rank(value: &serde_json::Value) -> u8 {
+    match value {
+        serde_json::Value::Null => 0,
+        serde_json::Value::Bool(_) => 1,
+        serde_json::Value::Number(_) => 2,
+        serde_json::Value::String(_) => 3,
+        _ => 4,
+    }
+}
+
+fn compare_values(a: &serde_json::Value, b: &serde_json::Value) -> Ordering {
+    match (a, b) {
+        (serde_json::Value::Bool(a), serde_json::Value::Bool(b)) => a.cmp(b),
+        (serde_json::Value::Number(a), serde_json::Value::Number(b)) => {
+            a.as_f64().partial_cmp(&b.as_f64()).unwrap_or(Ordering::Equal)
+        }
+        (serde_json::Value::String(a), serde_json::Value::String(b)) => a.cmp(b),
+        _ => type_rank(a).cmp(&type_rank(b)).then_with(|| a.to_string().cmp(&b.to_string())),
+    }
+}
+
+fn compare(a: &SortKey, b: &SortKey, order: SortOrder) -> Ordering {
+    // Entries without the sort field come last in either order
+    match (a.value.is_null(), b.value.is_null()) {
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        _ => {}
+    }
+    let ordering = compare_values(&a.value, &b.value)
+        .then(a.timestamp.cmp(&b.timestamp))
+        .then_with(|| a.id.cmp(&b.id));
+    match order {
+        SortOrder::Asc => ordering,
+        SortOrder::Desc => ordering.reverse(),
+    }
+}
+
+fn field_matches(actual: &serde_json::Value, expected: &serde_json::Value) -> bool {
+    match actual {
+        serde_json::Value::Array(items) if !expected.is_array() => items.contains(expected),
+        _ => actual == expected,
+    }
+}
+
+fn decode_cursor(cursor: &str, query: &FeedbackQuery) -> Result<SortKey, ChanterelleError> {
+    let cursor: Cursor = serde_json::from_str(cursor)
+        .map_err(|_| ChanterelleError::config("Invalid feedback cursor"))?;
+    if cursor.sort_by != query.sort_by || cursor.order != query.order {
+        return Err(ChanterelleError::config("The feedback cursor belongs to a different sort order"));
+    }
+    Ok(cursor.after)
+}
+
+impl FeedbackQuery {
+    fn matches(&self, index: &FeedbackIndex, position: usize, needle: Option<&str>) -> bool {
+        let entry = &index.entries[position];
+        if !self.filter.matches(entry) {
+            return false;
+        }
+        if !self.fields.iter().all(|(key, expected)| field_matches(&entry.feedback[key.as_str()], expected)) {
+            return false;
+        }
+        needle.is_none_or(|needle| index.text[position].contains(needle))
+    }
+
+    /// One page of the entries matching the query.
+    pub fn run(&self, index: &FeedbackIndex) -> Result<FeedbackPage, ChanterelleError> {
+        let after = self.cursor.as_deref().map(|cursor| decode_cursor(cursor, self)).transpose()?;
+        let needle = self
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(|t| t.to_lowercase());
+        let sort_by = self.sort_by.as_deref();
+
+        let mut matching: Vec<(SortKey, usize)> = index
+            .in_range(self.filter.since, self.filter.until)
+            .iter()
+            .filter(|&&position| self.matches(index, position, needle.as_deref()))
+            .map(|&position| (sort_key(&index.entries[position], sort_by), position))
+            .collect();
+        matching.sort_by(|(a, _), (b, _)| compare(a, b, self.order));
+
+        let total = matching.len();
+        let start = after.map_or(0, |after| {
+            matching.partition_point(|(key, _)| compare(key, &after, self.order) != Ordering::Greater)
+        });
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+        let page = &matching[start..(start + limit).min(total)];
+        let next_cursor = match page.last() {
+            Some((key, _)) if start + page.len() < total => Some(
+                serde_json::to_string(&Cursor { sort_by: self.sort_by.clone(), order: self.order, after: key.clone() })
+                    .unwrap(),
+            ),
+            _ => None,
+        };
+        Ok(FeedbackPage {
+            entries: page.iter().map(|(_, position)| index.entries[*position].clone()).collect(),
+            total,
+            next_cursor,
+        })
+    }
+}
+
+// --- Commands ---
+
+/// Feedback of a project, filtered, sorted and paginated. Newest first by default.
+#[tauri::command]
+pub async fn query_feedback(
+    project_name: String,
+    query: Option<FeedbackQuery>,
+    state: tauri::State<'_, AppState>,
+) -> Result<FeedbackPage, ChanterelleError> {
+    let index = state.feedback.index(&feedback::model_dir(&state, &project_name))?;
+    query.unwrap_or_default().run(&index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entry(id: &str, timestamp: u64, feedback: serde_json::Value) -> FeedbackEntry {
+        FeedbackEntry { id: id.to_string(), timestamp, ..FeedbackEntry::new(feedback) }
+    }
+
+    fn ids(page: &FeedbackPage) -> Vec<&str> {
+        page.entries.iter().map(|e| e.id.as_str()).collect()
+    }
+
+    fn query(sort_by: Option<&str>, order: SortOrder, limit: usize) -> FeedbackQuery {
+        FeedbackQuery { sort_by: sort_by.map(String::from), order, limit: Some(limit), ..Default::default() }
+    }
+
+    /// Follows the cursors to the end and returns the ids of every page.
+    fn all_pages(index: &FeedbackIndex, mut query: FeedbackQuery) -> Vec<Vec<String>> {
+        let mut pages = Vec::new();
+        loop {
+            let page = query.run(index).unwrap();
+            pages.push(page.entries.iter().map(|e| e.id.clone()).collect());
+            match page.next_cursor {
+                Some(cursor) => query.cursor = Some(cursor),
+                None => return pages,
+            }
+        }
+    }
+
+    #[test]
+    fn pages_follow_the_cursor_to_the_end() {
+        let index = FeedbackIndex::new((1..=5).map(|t| entry(&format!("e{}", t), t, json!({}))).collect());
+        let pages = all_pages(&index, query(None, SortOrder::Desc, 2));
+        assert_eq!(pages, vec![vec!["e5", "e4"], vec!["e3", "e2"], vec!["e1"]]);
+
+        let first = query(None, SortOrder::Asc, 2).run(&index).unwrap();
+        assert_eq!(ids(&first), ["e1", "e2"]);
+        assert_eq!(first.total, 5);
+    }
+
+    #[test]
+    fn inserts_between_pages_neither_repeat_nor_skip_entries() {
+        let before = FeedbackIndex::new((1..=4).map(|t| entry(&format!("e{}", t), t, json!({}))).collect());
+        let first = query(None, SortOrder::Desc, 2).run(&before).unwrap();
+        assert_eq!(ids(&first), ["e4", "e3"]);
+
+        // A newer entry lands before the cursor, one with a tied timestamp after it
+        let mut entries = before.entries().to_vec();
+        entries.push(entry("e6", 6, json!({})));
+        entries.push(entry("e2b", 2, json!({})));
+        let after = FeedbackIndex::new(entries);
+        let second = FeedbackQuery { cursor: first.next_cursor, ..query(None, SortOrder::Desc, 5) }.run(&after).unwrap();
+        assert_eq!(ids(&second), ["e2b", "e2", "e1"]);
+        assert_eq!(second.total, 6);
+        assert!(second.next_cursor.is_none());
+    }
+
+    #[test]
+    fn entries_without_the_sort_field_come_last_in_both_orders() {
+        let index = FeedbackIndex::new(vec![
+            entry("two", 1, json!({ "score": 2 })),
+            entry("none", 2, json!({})),
+            entry("one", 3, json!({ "score": 1 })),
+            entry("three", 4, json!({ "score": 3.5 })),
+        ]);
+        let asc = query(Some("score"), SortOrder::Asc, 10).run(&index).unwrap();
+        assert_eq!(ids(&asc), ["one", "two", "three", "none"]);
+        let desc = query(Some("score"), SortOrder::Desc, 10).run(&index).unwrap();
+        assert_eq!(ids(&desc), ["three", "two", "one", "none"]);
+    }
+
+    #[test]
+    fn mixed_types_sort_by_type_then_value() {
+        let index = FeedbackIndex::new(vec![
+            entry("text", 1, json!({ "score": "b" })),
+            entry("number", 2, json!({ "score": 10 })),
+            entry("flag", 3, json!({ "score": true })),
+            entry("list", 4, json!({ "score": ["a"] })),
+            entry("missing", 5, json!({})),
+            entry("text-a", 6, json!({ "score": "a" })),
+        ]);
+        let asc = query(Some("score"), SortOrder::Asc, 10).run(&index).unwrap();
+        assert_eq!(ids(&asc), ["flag", "number", "text-a", "text", "list", "missing"]);
+        let pages = all_pages(&index, query(Some("score"), SortOrder::Desc, 2));
+        assert_eq!(pages.concat(), ["list", "text", "text-a", "number", "flag", "missing"]);
+    }
+
+    #[test]
+    fn desc_ties_break_on_timestamp_then_id_and_page_cleanly() {
+        let index = FeedbackIndex::new(vec![
+            entry("a", 1, json!({ "score": 4 })),
+            entry("c", 2, json!({ "score": 4 })),
+            entry("b", 2, json!({ "score": 4 })),
+            entry("d", 3, json!({ "score": 5 })),
+        ]);
+        let pages = all_pages(&index, query(Some("score"), SortOrder::Desc, 1));
+        assert_eq!(pages.concat(), ["d", "c", "b", "a"]);
+    }
+
+    #[test]
+    fn date_range_selects_from_since_up_to_until() {
+        // File order is not timestamp order, e.g. after entries were imported
+        let entries = [5, 1, 3, 2, 4, 3].iter().enumerate().map(|(i, &t)| entry(&format!("e{}", i), t, json!({})));
+        let index = FeedbackIndex::new(entries.collect());
+        let timestamps = |since, until| -> Vec<u64> {
+            index.in_range(since, until).iter().map(|&i| index.entries()[i].timestamp).collect()
+        };
+        assert_eq!(timestamps(Some(2), Some(4)), [2, 3, 3]);
+        assert_eq!(timestamps(Some(3), None), [3, 3, 4, 5]);
+        assert_eq!(timestamps(None, Some(2)), [1]);
+        assert!(timestamps(Some(4), Some(2)).is_empty());
+
+        let filter = FeedbackFilter { since: Some(2), until: Some(4), ..Default::default() };
+        let page = FeedbackQuery { filter, ..query(None, SortOrder::Asc, 10) }.run(&index).unwrap();
+        assert_eq!(page.total, 3);
+    }
+
+    #[test]
+    fn cursors_only_fit_the_sort_they_came_from() {
+        let index = FeedbackIndex::new((1..=3).map(|t| entry(&format!("e{}", t), t, json!({ "score": t }))).collect());
+        let first = query(Some("score"), SortOrder::Asc, 1).run(&index).unwrap();
+        let other_order = FeedbackQuery { cursor: first.next_cursor.clone(), ..query(Some("score"), SortOrder::Desc, 1) };
+        assert!(other_order.run(&index).is_err());
+        let other_field = FeedbackQuery { cursor: first.next_cursor, ..query(None, SortOrder::Asc, 1) };
+        assert!(other_field.run(&index).is_err());
+        let garbage = FeedbackQuery { cursor: Some("not a cursor".into()), ..FeedbackQuery::default() };
+        assert!(garbage.run(&index).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+// src-tauri/src/feedback_schema.rs
+// Checks feedback against the `feedback_schema` a project declares.
+use std::fs;
+use std::path::Path;
+
+use crate::error::{ChanterelleError, FieldError};
+use crate::output_contract;
+use crate::types::{FeedbackField, FeedbackFieldKind, FeedbackSchema};
+use crate::validation;
+
+/// Feedback key holding what the form showed; allowed next to the schema fields
+const CONTEXT_KEY: &str = "context";
+
+/// Reads `feedback_schema` from interactive.json or model_meta.json, whichever the
+/// project has. `None` when the project declares no schema.
+pub fn read_schema(model_dir: &Path) -> Result<Option<FeedbackSchema>, ChanterelleError> {
+    for filename in ["interactive.json", "model_meta.json"] {
+        let path = model_dir.join(filename);
+        if !path.exists() {
+            continue;
+        }
+        let content = fs::read_to_string(&path)
+            .map_err(|e| ChanterelleError::config(format!("Failed to read {}: {}", filename, e)))?;
+        let meta: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| ChanterelleError::config(format!("Failed to parse {}: {}", filename, e)))?;
+        return match meta.get("feedback_schema") {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(schema) => serde_json::from_value(schema.clone())
+                .map(Some)
+                .map_err(|e| ChanterelleError::config(format!("Invalid feedback_schema in {}: {}", filename, e))),
+        };
+    }
+    Ok(None)
+}
+
+fn invalid_feedback(fields: Vec<FieldError>) -> ChanterelleError {
+    let details: Vec<&str> = fields.iter().map(|f| f.message.as_str()).collect();
+    ChanterelleError::Validation { message: format!("Invalid feedback: {}", details.join("; ")), fields }
+}
+
+fn check_field(field: &FeedbackField, value: &serde_json::Value) -> Result<(), String> {
+    let label = &field.label;
+    match &field.kind {
+        FeedbackFieldKind::Rating { min, max } => {
+            let rating = value.as_i64().ok_or_else(|| format!("{} must be a whole number", label))?;
+            if rating < *min || rating > *max {
+                return Err(format!("{} must be from {} to {}", label, min, max));
+            }
+            Ok(())
+        }
+        FeedbackFieldKind::Label { options, multiple } => {
+            let chosen: Vec<&serde_json::Value> = match value {
+                serde_json::Value::Array(items) if *multiple => items.iter().collect(),
+                serde_json::Value::Array(_) => return Err(format!("{} takes a single option", label)),
+                other => vec![other],
+            };
+            for choice in chosen {
+                if !choice.as_str().is_some_and(|choice| options.iter().any(|o| o == choice)) {
+                    return Err(format!("{} must be one of: {}", label, options.join(", ")));
+                }
+            }
+            Ok(())
+        }
+        FeedbackFieldKind::Correction { output } => {
+            output_contract::check_field(output, value).map_err(|message| format!("{}: {}", label, message))
+        }
+        FeedbackFieldKind::Text { max_length } => {
+            let text = value.as_str().ok_or_else(|| format!("{} must be text", label))?;
+            match max_length {
+                Some(max) if text.chars().count() > *max => Err(format!("{} must be at most {} characters", label, max)),
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+impl FeedbackSchema {
+    /// Checks a submission: required fields must be given and every field must match
+    /// its declaration. Keys the schema does not declare are rejected, except `context`.
+    pub fn validate(&self, feedback: &serde_json::Value) -> Result<(), ChanterelleError> {
+        let Some(values) = feedback.as_object() else {
+            return Err(invalid_feedback(vec![FieldError {
+                field: String::new(),
+                message: "Feedback must be an object".to_string(),
+            }]));
+        };
+        let mut errors = Vec::new();
+        for field in &self.fields {
+            match values.get(&field.name).filter(|value| !validation::is_empty(value)) {
+                Some(value) => {
+                    if let Err(message) = check_field(field, value) {
+                        errors.push(FieldError { field: field.name.clone(), message });
+                    }
+                }
+                None if field.required.unwrap_or(false) => {
+                    errors.push(FieldError { field: field.name.clone(), message: format!("{} is required", field.label) });
+                }
+                None => {}
+            }
+        }
+        for key in values.keys() {
+            if key != CONTEXT_KEY && !self.fields.iter().any(|field| &field.name == key) {
+                errors.push(FieldError {
+                    field: key.clone(),
+                    message: format!("'{}' is not a field of the feedback schema", key),
+                });
+            }
+        }
+        if !errors.is_empty() {
+            return Err(invalid_feedback(errors));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> FeedbackSchema {
+        serde_json::from_value(json!({
+            "fields": [
+                { "name": "score", "label": "Score", "type": "rating", "min": 1, "max": 5, "required": true },
+                { "name": "tags", "label": "Tags", "type": "label", "options": ["wrong", "slow"], "multiple": true },
+                { "name": "verdict", "label": "Verdict", "type": "label", "options": ["good", "bad"] },
+                { "name": "comment", "label": "Comment", "type": "text", "max_length": 5 }
+            ]
+        }))
+        .unwrap()
+    }
+
+    /// Names of the fields a submission was rejected for.
+    fn rejected(feedback: serde_json::Value) -> Vec<String> {
+        match schema().validate(&feedback) {
+            Ok(()) => Vec::new(),
+            Err(ChanterelleError::Validation { fields, .. }) => fields.into_iter().map(|f| f.field).collect(),
+            Err(other) => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn accepts_valid_feedback_with_context() {
+        let feedback = json!({
+            "score": 4,
+            "tags": ["wrong", "slow"],
+            "verdict": "bad",
+            "comment": "meh",
+            "context": { "inputs": { "x": 1 } }
+        });
+        assert!(rejected(feedback).is_empty());
+        assert!(rejected(json!({ "score": 1, "comment": "" })).is_empty());
+    }
+
+    #[test]
+    fn requires_required_fields() {
+        assert_eq!(rejected(json!({ "verdict": "good" })), ["score"]);
+        assert_eq!(rejected(json!({ "score": null })), ["score"]);
+    }
+
+    #[test]
+    fn checks_each_field_against_its_kind() {
+        assert_eq!(rejected(json!({ "score": 6 })), ["score"]);
+        assert_eq!(rejected(json!({ "score": 2.5 })), ["score"]);
+        assert_eq!(rejected(json!({ "score": 3, "tags": ["wrong", "other"] })), ["tags"]);
+        assert_eq!(rejected(json!({ "score": 3, "verdict": ["good"] })), ["verdict"]);
+        assert_eq!(rejected(json!({ "score": 3, "comment": "too long" })), ["comment"]);
+        assert_eq!(rejected(json!({ "score": 3, "comment": 12 })), ["comment"]);
+    }
+
+    #[test]
+    fn rejects_undeclared_keys_and_non_objects() {
+        assert_eq!(rejected(json!({ "score": 3, "rating": "up" })), ["rating"]);
+        assert_eq!(rejected(json!("up")), [""]);
+    }
+}
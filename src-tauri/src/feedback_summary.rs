@@ -0,0 +1,269 @@
+// src-tauri/src/feedback_summary.rs
+// Aggregates feedback.jsonl into insight sections (see visualization-types.md).
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::ChanterelleError;
+use crate::feedback::{self, FeedbackEntry, FeedbackFilter};
+use crate::feedback_schema;
+use crate::report::{table, utc_date};
+use crate::state::AppState;
+use crate::types::{FeedbackFieldKind, FeedbackSchema};
+
+/// Version shown for feedback that is not linked to a run
+const UNKNOWN_VERSION: &str = "unknown";
+
+/// A feedback field counted per value: the default thumbs rating, or a `rating` or
+/// `label` field of the project's feedback_schema.
+struct Dimension {
+    key: String,
+    label: String,
+    /// Declared values first, then any others found in the entries
+    values: Vec<String>,
+    /// Ratings also get a mean per version and per day
+    numeric: bool,
+}
+
+fn dimensions(schema: Option<&FeedbackSchema>, entries: &[FeedbackEntry]) -> Vec<Dimension> {
+    let mut dimensions: Vec<Dimension> = schema
+        .into_iter()
+        .flat_map(|schema| &schema.fields)
+        .filter_map(|field| {
+            let (values, numeric) = match &field.kind {
+                FeedbackFieldKind::Rating { min, max } => ((*min..=*max).map(|v| v.to_string()).collect(), true),
+                FeedbackFieldKind::Label { options, .. } => (options.clone(), false),
+                _ => return None,
+            };
+            Some(Dimension { key: field.name.clone(), label: field.label.clone(), values, numeric })
+        })
+        .collect();
+    let has_thumbs = entries.iter().any(|entry| entry.feedback["rating"].is_string());
+    if has_thumbs && !dimensions.iter().any(|d| d.key == "rating") {
+        dimensions.push(Dimension {
+            key: "rating".to_string(),
+            label: "Rating".to_string(),
+            values: vec!["up".to_string(), "down".to_string()],
+            numeric: false,
+        });
+    }
+    for dimension in &mut dimensions {
+        for entry in entries {
+            for value in entry_values(entry, &dimension.key) {
+                if !dimension.values.contains(&value) {
+                    dimension.values.push(value);
+                }
+            }
+        }
+    }
+    dimensions
+}
+
+/// Values an entry gives a field; labels that allow several give a list.
+fn entry_values(entry: &FeedbackEntry, key: &str) -> Vec<String> {
+    match &entry.feedback[key] {
+        serde_json::Value::String(s) => vec![s.clone()],
+        serde_json::Value::Number(n) => vec![n.to_string()],
+        serde_json::Value::Array(items) => items.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn version(entry: &FeedbackEntry) -> String {
+    entry
+        .run
+        .as_ref()
+        .and_then(|run| run.model_version.clone())
+        .unwrap_or_else(|| UNKNOWN_VERSION.to_string())
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Counts of each value, and the numbers for means, grouped by `group`.
+struct Counts {
+    counts: BTreeMap<String, HashMap<String, usize>>,
+    numbers: BTreeMap<String, Vec<f64>>,
+}
+
+impl Counts {
+    fn new(entries: &[FeedbackEntry], dimension: &Dimension, group: impl Fn(&FeedbackEntry) -> String) -> Self {
+        let mut counts: BTreeMap<String, HashMap<String, usize>> = BTreeMap::new();
+        let mut numbers: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+        for entry in entries {
+            let key = group(entry);
+            let group_counts = counts.entry(key.clone()).or_default();
+            for value in entry_values(entry, &dimension.key) {
+                *group_counts.entry(value).or_default() += 1;
+            }
+            if let Some(number) = entry.feedback[&dimension.key].as_f64() {
+                numbers.entry(key).or_default().push(number);
+            }
+        }
+        Self { counts, numbers }
+    }
+
+    fn count(&self, group: &str, value: &str) -> usize {
+        self.counts.get(group).and_then(|c| c.get(value)).copied().unwrap_or(0)
+    }
+
+    fn mean(&self, group: &str) -> Option<f64> {
+        self.numbers.get(group).and_then(|numbers| mean(numbers))
+    }
+}
+
+fn overview_section(entries: &[FeedbackEntry]) -> serde_json::Value {
+    let mut versions: BTreeMap<String, (usize, u64, u64)> = BTreeMap::new();
+    for entry in entries {
+        let (count, first, last) = versions.entry(version(entry)).or_insert((0, u64::MAX, 0));
+        *count += 1;
+        *first = (*first).min(entry.timestamp);
+        *last = (*last).max(entry.timestamp);
+    }
+    let rows = versions
+        .into_iter()
+        .map(|(version, (count, first, last))| {
+            serde_json::json!({
+                "version": version,
+                "entries": count,
+                "first": utc_date(first),
+                "last": utc_date(last),
+            })
+        })
+        .collect();
+    let linked = entries.iter().filter(|entry| entry.run.is_some()).count();
+    serde_json::json!({
+        "type": "section",
+        "id": "feedback_overview",
+        "title": "Feedback",
+        "description": format!("{} entries, {} linked to a run", entries.len(), linked),
+        "items": [table(
+            "By model version",
+            &[("Model version", "version"), ("Entries", "entries"), ("First", "first"), ("Last", "last")],
+            rows,
+        )],
+    })
+}
+
+fn dimension_section(entries: &[FeedbackEntry], dimension: &Dimension) -> serde_json::Value {
+    let id = |suffix: &str| format!("feedback_{}_{}", dimension.key, suffix);
+    let totals = Counts::new(entries, dimension, |_| String::new());
+    let by_version = Counts::new(entries, dimension, version);
+    let by_day = Counts::new(entries, dimension, |entry| utc_date(entry.timestamp));
+
+    let bars: Vec<serde_json::Value> = dimension
+        .values
+        .iter()
+        .map(|value| serde_json::json!({ "label": value, "value": totals.count("", value) }))
+        .collect();
+    let mut items = vec![serde_json::json!({
+        "type": "bar_chart",
+        "id": id("totals"),
+        "title": format!("{} counts", dimension.label),
+        "data": {
+            "bars": bars,
+            "axis": { "x": { "label": dimension.label }, "y": { "label": "Entries" } }
+        }
+    })];
+
+    // Every line needs a point for each day, since the chart labels come from the first line
+    let days: Vec<&String> = by_day.counts.keys().collect();
+    let lines: Vec<serde_json::Value> = dimension
+        .values
+        .iter()
+        .map(|value| {
+            let points: Vec<serde_json::Value> = days
+                .iter()
+                .map(|day| serde_json::json!({ "x": day, "y": by_day.count(day, value) }))
+                .collect();
+            serde_json::json!({ "id": value, "points": points })
+        })
+        .collect();
+    items.push(serde_json::json!({
+        "type": "line_chart",
+        "id": id("over_time"),
+        "title": format!("{} per day", dimension.label),
+        "data": {
+            "lines": lines,
+            "axis": { "x": { "label": "Day (UTC)" }, "y": { "label": "Entries" } }
+        }
+    }));
+    if dimension.numeric {
+        let points: Vec<serde_json::Value> = days
+            .iter()
+            .filter_map(|day| Some(serde_json::json!({ "x": day, "y": by_day.mean(day)? })))
+            .collect();
+        items.push(serde_json::json!({
+            "type": "line_chart",
+            "id": id("mean"),
+            "title": format!("Mean {} per day", dimension.label),
+            "data": {
+                "lines": [{ "id": "mean", "points": points }],
+                "axis": { "x": { "label": "Day (UTC)" }, "y": { "label": dimension.label } }
+            }
+        }));
+    }
+
+    let fields: Vec<String> = (0..dimension.values.len()).map(|i| format!("v{}", i)).collect();
+    let mut columns: Vec<(&str, &str)> = vec![("Model version", "version")];
+    columns.extend(dimension.values.iter().zip(&fields).map(|(value, field)| (value.as_str(), field.as_str())));
+    if dimension.numeric {
+        columns.push(("Mean", "mean"));
+    }
+    let rows = by_version
+        .counts
+        .keys()
+        .map(|version| {
+            let mut row = serde_json::Map::new();
+            row.insert("version".into(), serde_json::json!(version));
+            for (value, field) in dimension.values.iter().zip(&fields) {
+                row.insert(field.clone(), serde_json::json!(by_version.count(version, value)));
+            }
+            if dimension.numeric {
+                let mean = by_version.mean(version).map(|m| format!("{:.2}", m));
+                row.insert("mean".into(), serde_json::json!(mean.unwrap_or_else(|| "—".to_string())));
+            }
+            serde_json::Value::Object(row)
+        })
+        .collect();
+    items.push(table(&format!("{} by model version", dimension.label), &columns, rows));
+
+    serde_json::json!({
+        "type": "section",
+        "id": id("summary"),
+        "title": dimension.label,
+        "items_per_row": 2,
+        "items": items,
+    })
+}
+
+pub fn summary_sections(entries: &[FeedbackEntry], schema: Option<&FeedbackSchema>) -> Vec<serde_json::Value> {
+    let mut sections = vec![overview_section(entries)];
+    sections.extend(dimensions(schema, entries).iter().map(|dimension| dimension_section(entries, dimension)));
+    sections
+}
+
+// --- Commands ---
+
+/// Feedback of a project aggregated per model version and per rating or label,
+/// as insight sections.
+#[tauri::command]
+pub async fn get_feedback_summary(
+    project_name: String,
+    filter: Option<FeedbackFilter>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<serde_json::Value>, ChanterelleError> {
+    let model_dir = feedback::model_dir(&state, &project_name);
+    let filter = filter.unwrap_or_default();
+    let entries: Vec<FeedbackEntry> = state
+        .feedback
+        .list(&model_dir)?
+        .into_iter()
+        .filter(|entry| filter.matches(entry))
+        .collect();
+    // A broken schema still leaves the thumbs rating to summarize
+    let schema = feedback_schema::read_schema(&model_dir).unwrap_or_else(|e| {
+        println!("Ignoring feedback schema: {}", e);
+        None
+    });
+    Ok(summary_sections(&entries, schema.as_ref()))
+}
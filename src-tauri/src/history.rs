@@ -0,0 +1,312 @@
+// src-tauri/src/history.rs
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::error::ChanterelleError;
+use crate::state::AppState;
+use crate::storage;
+use crate::types::PythonEnvironment;
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 500;
+
+/// One `invoke_model` call as recorded in the project's history.jsonl.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HistoryEntry {
+    pub id: String,
+    /// Milliseconds since the Unix epoch, taken when the call started
+    pub timestamp: u64,
+    pub project_name: String,
+    pub model_version: Option<String>,
+    pub inputs: HashMap<String, serde_json::Value>,
+    pub outputs: Option<serde_json::Value>,
+    pub error: Option<ChanterelleError>,
+    pub latency_ms: u64,
+    pub pid: Option<u32>,
+    pub env_fingerprint: Option<String>,
+    /// Short hash of the handler and other project files the run used
+    #[serde(default)]
+    pub artifacts_fingerprint: Option<String>,
+    /// Response fields that broke the declared outputs
+    #[serde(default)]
+    pub contract_violations: usize,
+    /// Served from the prediction cache instead of the handler
+    #[serde(default)]
+    pub cached: bool,
+}
+
+impl HistoryEntry {
+    pub fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryStatus {
+    Ok,
+    Error,
+}
+
+/// Filters for `query_history`. Every field is optional.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct HistoryQuery {
+    /// Inclusive lower bound, milliseconds since the Unix epoch
+    pub since: Option<u64>,
+    /// Exclusive upper bound, milliseconds since the Unix epoch
+    pub until: Option<u64>,
+    pub status: Option<HistoryStatus>,
+    pub model_version: Option<String>,
+    /// Case-insensitive substring matched against inputs, outputs and error
+    pub text: Option<String>,
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
+
+impl HistoryQuery {
+    fn matches(&self, entry: &HistoryEntry) -> bool {
+        if self.since.is_some_and(|since| entry.timestamp < since) {
+            return false;
+        }
+        if self.until.is_some_and(|until| entry.timestamp >= until) {
+            return false;
+        }
+        match self.status {
+            Some(HistoryStatus::Ok) if entry.is_error() => return false,
+            Some(HistoryStatus::Error) if !entry.is_error() => return false,
+            _ => {}
+        }
+        if let Some(version) = &self.model_version {
+            if entry.model_version.as_deref() != Some(version.as_str()) {
+                return false;
+            }
+        }
+        if let Some(text) = self.text.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+            let needle = text.to_lowercase();
+            let haystack = serde_json::to_string(&(&entry.inputs, &entry.outputs, &entry.error))
+                .unwrap_or_default()
+                .to_lowercase();
+            if !haystack.contains(&needle) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct HistoryPage {
+    /// Newest first
+    pub entries: Vec<HistoryEntry>,
+    /// Number of entries matching the filters, before pagination
+    pub total: usize,
+}
+
+/// Append-only log of model invocations, one history.jsonl per project
+/// under the app data directory.
+#[derive(Default)]
+pub struct History {
+    // Serializes appends with the rewrites done by deletes
+    lock: Mutex<()>,
+}
+
+fn history_path(project_name: &str) -> Result<PathBuf, ChanterelleError> {
+    Ok(storage::project_data_dir(project_name)?.join("history.jsonl"))
+}
+
+fn read_entries(project_name: &str) -> Result<Vec<HistoryEntry>, ChanterelleError> {
+    let path = history_path(project_name)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let file = fs::File::open(&path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to open history file: {}", e)))?;
+    let mut entries = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| ChanterelleError::io(format!("Failed to read line: {}", e)))?;
+        if line.trim().is_empty() { continue; }
+        match serde_json::from_str::<HistoryEntry>(&line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => println!("Failed to parse history entry: {}", e),
+        }
+    }
+    Ok(entries)
+}
+
+fn write_entries(project_name: &str, entries: &[HistoryEntry]) -> Result<(), ChanterelleError> {
+    let mut contents = Vec::new();
+    for entry in entries {
+        contents.extend(serde_json::to_vec(entry).unwrap());
+        contents.push(b'\n');
+    }
+    storage::write_atomic(&history_path(project_name)?, &contents)
+}
+
+/// Short, stable hash of the Python environment config and interpreter, so runs
+/// made from different environments can be told apart.
+pub fn env_fingerprint(python_environment: &Option<PythonEnvironment>, program: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(serde_json::to_vec(python_environment).unwrap_or_default());
+    hasher.update(program.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+impl History {
+    pub fn append(&self, entry: &HistoryEntry) -> Result<(), ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(history_path(&entry.project_name)?)
+            .map_err(|e| ChanterelleError::io(format!("Failed to open history file: {}", e)))?;
+        writeln!(file, "{}", serde_json::to_string(entry).unwrap())
+            .map_err(|e| ChanterelleError::io(format!("Failed to write history entry: {}", e)))
+    }
+
+    /// Every entry matching the filters, newest first; `offset` and `limit` are ignored.
+    pub fn matching(&self, project_name: &str, query: &HistoryQuery) -> Result<Vec<HistoryEntry>, ChanterelleError> {
+        let entries = {
+            let _guard = self.lock.lock().unwrap();
+            read_entries(project_name)?
+        };
+        Ok(entries.into_iter().rev().filter(|e| query.matches(e)).collect())
+    }
+
+    pub fn query(&self, project_name: &str, query: &HistoryQuery) -> Result<HistoryPage, ChanterelleError> {
+        let matching = self.matching(project_name, query)?;
+        let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE);
+        Ok(HistoryPage {
+            total: matching.len(),
+            entries: matching.into_iter().skip(query.offset).take(limit).collect(),
+        })
+    }
+
+    pub fn get(&self, project_name: &str, id: &str) -> Result<Option<HistoryEntry>, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        Ok(read_entries(project_name)?.into_iter().find(|e| e.id == id))
+    }
+
+    /// Removes the given entries; returns how many were found.
+    pub fn delete(&self, project_name: &str, ids: &[String]) -> Result<usize, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let entries = read_entries(project_name)?;
+        let before = entries.len();
+        let kept: Vec<HistoryEntry> = entries.into_iter().filter(|e| !ids.contains(&e.id)).collect();
+        let removed = before - kept.len();
+        if removed > 0 {
+            write_entries(project_name, &kept)?;
+        }
+        Ok(removed)
+    }
+
+    pub fn clear(&self, project_name: &str) -> Result<(), ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let path = history_path(project_name)?;
+        if path.exists() {
+            fs::remove_file(&path)
+                .map_err(|e| ChanterelleError::io(format!("Failed to remove history file: {}", e)))?;
+        }
+        Ok(())
+    }
+}
+
+/// How an `invoke_model` call went, apart from its inputs and result.
+#[derive(Debug, Clone, Copy)]
+pub struct RunStats {
+    /// Milliseconds since the Unix epoch, taken when the call started
+    pub timestamp: u64,
+    pub latency_ms: u64,
+    /// 0 when no Python process was running
+    pub pid: u32,
+    pub contract_violations: usize,
+    pub cached: bool,
+}
+
+/// Records one `invoke_model` call. Failures to write are logged, never surfaced,
+/// so history can't break predictions. Returns the new entry id when it was saved.
+pub fn record_run(
+    state: &AppState,
+    project_name: &str,
+    inputs: HashMap<String, serde_json::Value>,
+    result: &Result<serde_json::Value, ChanterelleError>,
+    stats: RunStats,
+) -> Option<String> {
+    let active = state.active_project.lock().unwrap().clone();
+    let active = active.filter(|p| p.project_name == project_name);
+
+    let entry = HistoryEntry {
+        id: uuid::Uuid::new_v4().to_string(),
+        timestamp: stats.timestamp,
+        project_name: project_name.to_string(),
+        model_version: active.as_ref().and_then(|p| p.model_version.clone()),
+        inputs,
+        outputs: result.as_ref().ok().cloned(),
+        error: result.as_ref().err().cloned(),
+        latency_ms: stats.latency_ms,
+        pid: (stats.pid != 0).then_some(stats.pid),
+        env_fingerprint: active.as_ref().map(|p| p.env_fingerprint.clone()),
+        artifacts_fingerprint: active.and_then(|p| p.artifacts_fingerprint),
+        contract_violations: stats.contract_violations,
+        cached: stats.cached,
+    };
+
+    match state.history.append(&entry) {
+        Ok(()) => Some(entry.id),
+        Err(e) => {
+            println!("Failed to record run history: {}", e);
+            None
+        }
+    }
+}
+
+// --- Commands ---
+
+/// Past model runs of a project, newest first, filtered and paginated.
+#[tauri::command]
+pub async fn query_history(
+    project_name: String,
+    query: Option<HistoryQuery>,
+    state: tauri::State<'_, AppState>,
+) -> Result<HistoryPage, ChanterelleError> {
+    state.history.query(&project_name, &query.unwrap_or_default())
+}
+
+#[tauri::command]
+pub async fn get_history_entry(
+    project_name: String,
+    id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<HistoryEntry, ChanterelleError> {
+    state
+        .history
+        .get(&project_name, &id)?
+        .ok_or_else(|| ChanterelleError::config(format!("History entry '{}' not found", id)))
+}
+
+#[tauri::command]
+pub async fn delete_history_entries(
+    project_name: String,
+    ids: Vec<String>,
+    state: tauri::State<'_, AppState>,
+) -> Result<usize, ChanterelleError> {
+    state.history.delete(&project_name, &ids)
+}
+
+#[tauri::command]
+pub async fn clear_history(
+    project_name: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    state.history.clear(&project_name)
+}
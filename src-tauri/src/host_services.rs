@@ -0,0 +1,240 @@
+// src-tauri/src/host_services.rs
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::mpsc;
+use tauri::Emitter;
+
+use crate::error::ChanterelleError;
+use crate::kv_store::DEFAULT_NAMESPACE;
+use crate::state::{ActiveProject, AppState};
+use crate::storage;
+
+/// Marks a handler message as a call into the app (see chanterelle_host.py)
+pub const HOST_CALL_KEY: &str = "_chanterelle_host_call";
+/// Marks the reply Rust writes back to the handler
+pub const HOST_RESULT_KEY: &str = "_chanterelle_host_result";
+
+/// Every service a handler can call through `chanterelle_host`.
+pub const ALL_SERVICES: &[&str] = &[
+    "notify",
+    "pick_file",
+    "kv_get",
+    "kv_set",
+    "kv_delete",
+    "kv_keys",
+    "log_metric",
+];
+
+/// Request-scoped handles a host call can use.
+pub struct HostContext<'a> {
+    pub window: &'a tauri::Window,
+    pub state: &'a AppState,
+}
+
+#[derive(Deserialize)]
+struct HostCall {
+    service: String,
+    #[serde(default)]
+    args: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetricRecord {
+    pub name: String,
+    pub value: f64,
+    pub step: Option<i64>,
+    pub tags: Option<serde_json::Map<String, serde_json::Value>>,
+    pub timestamp: u64,
+}
+
+/// Runs one `_chanterelle_host_call` message and builds the reply line for Python.
+/// Requests that have no window (e.g. feedback) get an error reply instead.
+pub fn handle_host_call(call: &serde_json::Value, ctx: Option<&HostContext>) -> serde_json::Value {
+    let id = call.get("id").cloned().unwrap_or(serde_json::Value::Null);
+
+    let result = serde_json::from_value::<HostCall>(call.clone())
+        .map_err(|e| ChanterelleError::protocol(format!("Invalid host call: {}", e)))
+        .and_then(|call| match ctx {
+            Some(ctx) => dispatch(&call, ctx),
+            None => Err(ChanterelleError::config("Host services are not available for this request")),
+        });
+
+    match result {
+        Ok(value) => serde_json::json!({ HOST_RESULT_KEY: { "id": id, "ok": true, "result": value } }),
+        Err(e) => {
+            println!("Host call failed: {}", e);
+            serde_json::json!({ HOST_RESULT_KEY: { "id": id, "ok": false, "error": e.message() } })
+        }
+    }
+}
+
+fn dispatch(call: &HostCall, ctx: &HostContext) -> Result<serde_json::Value, ChanterelleError> {
+    let project = ctx
+        .state
+        .active_project
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| ChanterelleError::config("No project is loaded"))?;
+
+    if !ALL_SERVICES.contains(&call.service.as_str()) {
+        return Err(ChanterelleError::config(format!("Unknown host service '{}'", call.service)));
+    }
+    if !project.allows_host_service(&call.service) {
+        return Err(ChanterelleError::config(format!(
+            "Host service '{}' is not enabled for project '{}' (see host_services)",
+            call.service, project.project_name
+        )));
+    }
+
+    let args = &call.args;
+    match call.service.as_str() {
+        "notify" => notify(ctx.window, &project, args),
+        "pick_file" => pick_file(ctx.window, args),
+        "kv_get" | "kv_set" | "kv_delete" | "kv_keys" => kv_call(&call.service, ctx.state, &project, args),
+        "log_metric" => log_metric(ctx.window, &project, args),
+        other => Err(ChanterelleError::config(format!("Unknown host service '{}'", other))),
+    }
+}
+
+fn str_arg<'a>(args: &'a serde_json::Value, name: &str) -> Option<&'a str> {
+    args.get(name).and_then(|v| v.as_str())
+}
+
+fn required_str_arg<'a>(args: &'a serde_json::Value, name: &str) -> Result<&'a str, ChanterelleError> {
+    str_arg(args, name).ok_or_else(|| ChanterelleError::protocol(format!("Missing string argument '{}'", name)))
+}
+
+fn notify(
+    window: &tauri::Window,
+    project: &ActiveProject,
+    args: &serde_json::Value,
+) -> Result<serde_json::Value, ChanterelleError> {
+    let payload = serde_json::json!({
+        "project_name": project.project_name,
+        "title": required_str_arg(args, "title")?,
+        "body": str_arg(args, "body").unwrap_or(""),
+        "level": str_arg(args, "level").unwrap_or("info"),
+    });
+    window.emit("host:notification", &payload).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
+    Ok(serde_json::Value::Null)
+}
+
+/// Opens the native file picker and blocks until the user chooses or cancels.
+fn pick_file(window: &tauri::Window, args: &serde_json::Value) -> Result<serde_json::Value, ChanterelleError> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let mut dialog = window.dialog().file();
+    if let Some(title) = str_arg(args, "title") {
+        dialog = dialog.set_title(title);
+    }
+    let extensions: Vec<String> = args
+        .get("extensions")
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(|ext| ext.trim_start_matches('.').to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    if !extensions.is_empty() {
+        let extensions: Vec<&str> = extensions.iter().map(|s| s.as_str()).collect();
+        dialog = dialog.add_filter("Allowed files", &extensions);
+    }
+
+    let (tx, rx) = mpsc::channel();
+    dialog.pick_file(move |path| {
+        let _ = tx.send(path.map(|p| p.to_string()));
+    });
+    let picked = rx.recv().map_err(|_| ChanterelleError::io("File picker closed unexpectedly"))?;
+    Ok(picked.map(serde_json::Value::String).unwrap_or(serde_json::Value::Null))
+}
+
+/// Reads and writes the project's persistent KV store. Every call takes an optional `namespace`.
+fn kv_call(
+    service: &str,
+    state: &AppState,
+    project: &ActiveProject,
+    args: &serde_json::Value,
+) -> Result<serde_json::Value, ChanterelleError> {
+    let store = &state.kv_store;
+    let project_name = &project.project_name;
+    let namespace = str_arg(args, "namespace").unwrap_or(DEFAULT_NAMESPACE);
+
+    match service {
+        "kv_get" => {
+            let key = required_str_arg(args, "key")?;
+            Ok(store.get(project_name, namespace, key)?.unwrap_or(serde_json::Value::Null))
+        }
+        "kv_set" => {
+            let key = required_str_arg(args, "key")?;
+            let value = args.get("value").cloned().unwrap_or(serde_json::Value::Null);
+            store.set(project_name, namespace, key, value)?;
+            Ok(serde_json::Value::Null)
+        }
+        "kv_delete" => {
+            let key = required_str_arg(args, "key")?;
+            Ok(serde_json::Value::Bool(store.delete(project_name, namespace, key)?))
+        }
+        _ => Ok(serde_json::json!(store.keys(project_name, namespace)?)),
+    }
+}
+
+/// Appends a metric to the project's metrics.jsonl and forwards it to the UI.
+fn log_metric(
+    window: &tauri::Window,
+    project: &ActiveProject,
+    args: &serde_json::Value,
+) -> Result<serde_json::Value, ChanterelleError> {
+    let record = MetricRecord {
+        name: required_str_arg(args, "name")?.to_string(),
+        value: args
+            .get("value")
+            .and_then(|v| v.as_f64())
+            .ok_or_else(|| ChanterelleError::protocol("Metric value must be a number"))?,
+        step: args.get("step").and_then(|v| v.as_i64()),
+        tags: args.get("tags").and_then(|v| v.as_object()).cloned(),
+        timestamp: storage::now_millis(),
+    };
+
+    let path = storage::project_data_dir(&project.project_name)?.join("metrics.jsonl");
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to open metrics file: {}", e)))?;
+    writeln!(file, "{}", serde_json::to_string(&record).unwrap())
+        .map_err(|e| ChanterelleError::io(format!("Failed to write metric: {}", e)))?;
+
+    let mut payload = serde_json::to_value(&record).unwrap();
+    payload["project_name"] = serde_json::json!(project.project_name);
+    window.emit("host:metric", &payload).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
+    Ok(serde_json::Value::Null)
+}
+
+// --- Commands ---
+
+/// Metrics a project's handlers logged through `chanterelle_host.log_metric`, oldest first.
+#[tauri::command]
+pub async fn get_project_metrics(project_name: String) -> Result<Vec<MetricRecord>, ChanterelleError> {
+    let path = storage::project_data_dir(&project_name)?.join("metrics.jsonl");
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let file = fs::File::open(&path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to open metrics file: {}", e)))?;
+    let mut metrics = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| ChanterelleError::io(format!("Failed to read line: {}", e)))?;
+        if line.trim().is_empty() { continue; }
+        match serde_json::from_str::<MetricRecord>(&line) {
+            Ok(record) => metrics.push(record),
+            Err(e) => println!("Failed to parse metric record: {}", e),
+        }
+    }
+    Ok(metrics)
+}
@@ -0,0 +1,272 @@
+// src-tauri/src/kv_store.rs
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::error::ChanterelleError;
+use crate::state::AppState;
+use crate::storage;
+
+pub const DEFAULT_NAMESPACE: &str = "default";
+const MAX_KEY_LEN: usize = 256;
+/// Largest single value, serialized as JSON
+const MAX_VALUE_BYTES: usize = 1024 * 1024;
+/// Largest namespace, summed over its serialized values
+const MAX_NAMESPACE_BYTES: usize = 16 * 1024 * 1024;
+const MAX_NAMESPACES: usize = 64;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KvEntry {
+    pub value: serde_json::Value,
+    pub updated_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+struct KvNamespaceFile {
+    entries: BTreeMap<String, KvEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KvNamespaceSummary {
+    pub namespace: String,
+    pub keys: usize,
+    pub bytes: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KvEntrySummary {
+    pub key: String,
+    pub value: serde_json::Value,
+    pub bytes: usize,
+    pub updated_at: u64,
+}
+
+/// Persistent key-value store for handler state, one JSON file per project namespace
+/// under the app data directory. Survives process restarts and warm/cold switching.
+#[derive(Default)]
+pub struct KvStore {
+    // Serializes read-modify-write of namespace files
+    lock: Mutex<()>,
+}
+
+fn kv_dir(project_name: &str) -> Result<PathBuf, ChanterelleError> {
+    let dir = storage::project_data_dir(project_name)?.join("kv");
+    fs::create_dir_all(&dir)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create kv directory: {}", e)))?;
+    Ok(dir)
+}
+
+fn namespace_path(project_name: &str, namespace: &str) -> Result<PathBuf, ChanterelleError> {
+    storage::validate_id(namespace)?;
+    Ok(kv_dir(project_name)?.join(format!("{}.json", namespace)))
+}
+
+fn value_size(value: &serde_json::Value) -> usize {
+    serde_json::to_vec(value).map(|v| v.len()).unwrap_or(0)
+}
+
+fn read_namespace(project_name: &str, namespace: &str) -> Result<KvNamespaceFile, ChanterelleError> {
+    let path = namespace_path(project_name, namespace)?;
+    if !path.exists() {
+        return Ok(KvNamespaceFile::default());
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to read kv namespace '{}': {}", namespace, e)))?;
+    serde_json::from_str(&content)
+        .map_err(|e| ChanterelleError::io(format!("Failed to parse kv namespace '{}': {}", namespace, e)))
+}
+
+fn write_namespace(project_name: &str, namespace: &str, file: &KvNamespaceFile) -> Result<(), ChanterelleError> {
+    let path = namespace_path(project_name, namespace)?;
+    if file.entries.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path)
+                .map_err(|e| ChanterelleError::io(format!("Failed to remove kv namespace '{}': {}", namespace, e)))?;
+        }
+        return Ok(());
+    }
+    storage::write_atomic(&path, &serde_json::to_vec(file).unwrap())
+}
+
+fn namespace_names(project_name: &str) -> Result<Vec<String>, ChanterelleError> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(kv_dir(project_name)?)
+        .map_err(|e| ChanterelleError::io(format!("Failed to read kv directory: {}", e)))?
+    {
+        let path = entry
+            .map_err(|e| ChanterelleError::io(format!("Failed to read directory entry: {}", e)))?
+            .path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") { continue; }
+        if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+            names.push(stem.to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+impl KvStore {
+    pub fn get(
+        &self,
+        project_name: &str,
+        namespace: &str,
+        key: &str,
+    ) -> Result<Option<serde_json::Value>, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        Ok(read_namespace(project_name, namespace)?
+            .entries
+            .remove(key)
+            .map(|entry| entry.value))
+    }
+
+    pub fn set(
+        &self,
+        project_name: &str,
+        namespace: &str,
+        key: &str,
+        value: serde_json::Value,
+    ) -> Result<(), ChanterelleError> {
+        if key.is_empty() || key.len() > MAX_KEY_LEN {
+            return Err(ChanterelleError::config(format!("KV keys must be 1-{} bytes long", MAX_KEY_LEN)));
+        }
+        let size = value_size(&value);
+        if size > MAX_VALUE_BYTES {
+            return Err(ChanterelleError::config(format!(
+                "KV value for '{}' is {} bytes; the limit is {} bytes",
+                key, size, MAX_VALUE_BYTES
+            )));
+        }
+
+        let _guard = self.lock.lock().unwrap();
+        let path = namespace_path(project_name, namespace)?;
+        if !path.exists() && namespace_names(project_name)?.len() >= MAX_NAMESPACES {
+            return Err(ChanterelleError::config(format!(
+                "Project already has {} KV namespaces",
+                MAX_NAMESPACES
+            )));
+        }
+
+        let mut file = read_namespace(project_name, namespace)?;
+        let others: usize = file
+            .entries
+            .iter()
+            .filter(|(k, _)| k.as_str() != key)
+            .map(|(_, entry)| value_size(&entry.value))
+            .sum();
+        if others + size > MAX_NAMESPACE_BYTES {
+            return Err(ChanterelleError::config(format!(
+                "KV namespace '{}' would exceed its {} byte limit",
+                namespace, MAX_NAMESPACE_BYTES
+            )));
+        }
+
+        file.entries.insert(
+            key.to_string(),
+            KvEntry {
+                value,
+                updated_at: storage::now_millis(),
+            },
+        );
+        write_namespace(project_name, namespace, &file)
+    }
+
+    /// Removes a key; returns whether it existed.
+    pub fn delete(&self, project_name: &str, namespace: &str, key: &str) -> Result<bool, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut file = read_namespace(project_name, namespace)?;
+        let existed = file.entries.remove(key).is_some();
+        if existed {
+            write_namespace(project_name, namespace, &file)?;
+        }
+        Ok(existed)
+    }
+
+    pub fn keys(&self, project_name: &str, namespace: &str) -> Result<Vec<String>, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        Ok(read_namespace(project_name, namespace)?.entries.into_keys().collect())
+    }
+
+    pub fn namespaces(&self, project_name: &str) -> Result<Vec<KvNamespaceSummary>, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        namespace_names(project_name)?
+            .into_iter()
+            .map(|namespace| {
+                let file = read_namespace(project_name, &namespace)?;
+                Ok(KvNamespaceSummary {
+                    keys: file.entries.len(),
+                    bytes: file.entries.values().map(|entry| value_size(&entry.value)).sum(),
+                    namespace,
+                })
+            })
+            .collect()
+    }
+
+    pub fn entries(&self, project_name: &str, namespace: &str) -> Result<Vec<KvEntrySummary>, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        Ok(read_namespace(project_name, namespace)?
+            .entries
+            .into_iter()
+            .map(|(key, entry)| KvEntrySummary {
+                key,
+                bytes: value_size(&entry.value),
+                value: entry.value,
+                updated_at: entry.updated_at,
+            })
+            .collect())
+    }
+
+    /// Clears one namespace, or every namespace of the project when `namespace` is None.
+    pub fn clear(&self, project_name: &str, namespace: Option<&str>) -> Result<(), ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let namespaces = match namespace {
+            Some(namespace) => vec![namespace.to_string()],
+            None => namespace_names(project_name)?,
+        };
+        for namespace in namespaces {
+            write_namespace(project_name, &namespace, &KvNamespaceFile::default())?;
+        }
+        Ok(())
+    }
+}
+
+// --- Commands ---
+
+#[tauri::command]
+pub async fn list_kv_namespaces(
+    project_name: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<KvNamespaceSummary>, ChanterelleError> {
+    state.kv_store.namespaces(&project_name)
+}
+
+#[tauri::command]
+pub async fn get_kv_entries(
+    project_name: String,
+    namespace: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<KvEntrySummary>, ChanterelleError> {
+    let namespace = namespace.unwrap_or_else(|| DEFAULT_NAMESPACE.to_string());
+    state.kv_store.entries(&project_name, &namespace)
+}
+
+#[tauri::command]
+pub async fn delete_kv_entry(
+    project_name: String,
+    namespace: Option<String>,
+    key: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<bool, ChanterelleError> {
+    let namespace = namespace.unwrap_or_else(|| DEFAULT_NAMESPACE.to_string());
+    state.kv_store.delete(&project_name, &namespace, &key)
+}
+
+#[tauri::command]
+pub async fn clear_kv_store(
+    project_name: String,
+    namespace: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    state.kv_store.clear(&project_name, namespace.as_deref())
+}
@@ -6,19 +6,55 @@ use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use tauri::Manager;
 
+mod benchmark;
+mod compare;
+mod editor;
+mod error;
+mod feedback;
+mod feedback_export;
+mod feedback_outbox;
+mod feedback_query;
+mod feedback_schema;
+mod feedback_summary;
+mod history;
+mod host_services;
+mod kv_store;
+mod output_contract;
+mod prediction_cache;
+mod output_diff;
+mod project_tests;
 mod projects;
 mod python_runner_io;
+mod replay;
+mod report;
+mod sessions;
 mod settings;
+mod storage;
+mod sweep;
 mod types;
+mod validation;
 mod state;
 
+use error::ChanterelleError;
 use state::AppState;
 
+#[derive(serde::Serialize)]
+struct ModelRunResponse {
+    /// Id of the history entry, when the run could be recorded
+    run_id: Option<String>,
+    outputs: serde_json::Value,
+    /// Fields that break the declared outputs; errors instead in strict mode
+    warnings: Vec<error::ContractViolation>,
+    /// Served from the prediction cache instead of the handler
+    cached: bool,
+}
+
 #[derive(serde::Serialize)]
 struct WarmupResponse {
     warmup: bool,
-    error: Option<String>,
+    error: Option<ChanterelleError>,
     allow_feedback: Option<bool>,
+    feedback_schema: Option<types::FeedbackSchema>,
 }
 
 fn read_allow_feedback(projects_dir: &str, project_name: &str) -> Option<bool> {
@@ -41,24 +77,31 @@ fn read_allow_feedback(projects_dir: &str, project_name: &str) -> Option<bool> {
 async fn warmup_model(
     project_name: String,
     state: tauri::State<'_, AppState>,
-) -> Result<WarmupResponse, String> {
+) -> Result<WarmupResponse, ChanterelleError> {
     let projects_dir = {
         let settings = state.settings.lock().unwrap();
         settings.projects_directory.clone()
     };
 
     let allow_feedback = read_allow_feedback(&projects_dir, &project_name);
+    let feedback_schema = feedback_schema::read_schema(&std::path::Path::new(&projects_dir).join(&project_name))
+        .unwrap_or_else(|e| {
+            println!("Ignoring feedback schema: {}", e);
+            None
+        });
 
     match python_runner_io::load_model(&projects_dir, &project_name, state).await {
         Ok(_) => Ok(WarmupResponse {
             warmup: true,
             error: None,
             allow_feedback,
+            feedback_schema,
         }),
         Err(e) => Ok(WarmupResponse {
             warmup: false,
-            error: Some(format!("Failed to warm up model: {}", e)),
+            error: Some(e.context("Failed to warm up model")),
             allow_feedback,
+            feedback_schema,
         }),
     }
 }
@@ -67,13 +110,58 @@ async fn warmup_model(
 async fn invoke_model(
     project_name: String,
     inputs: HashMap<String, serde_json::Value>,
+    window: tauri::Window,
     state: tauri::State<'_, AppState>,
-) -> Result<serde_json::Value, String> {
+) -> Result<ModelRunResponse, ChanterelleError> {
     let projects_dir = {
         let settings = state.settings.lock().unwrap();
         settings.projects_directory.clone()
     };
-    python_runner_io::run_model(&projects_dir, &project_name, inputs, state).await
+    let model_dir = std::path::Path::new(&projects_dir).join(&project_name);
+    let pid = state.python_pid.load(Ordering::SeqCst);
+    let timestamp = storage::now_millis();
+    // Checked before the history records the inputs, so it holds what the handler
+    // receives, e.g. 3 for "3"
+    let inputs = match validation::check_model_inputs(&model_dir, inputs.clone()) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            let stats = history::RunStats { timestamp, latency_ms: 0, pid, contract_violations: 0, cached: false };
+            history::record_run(&state, &project_name, inputs, &Err(e.clone()), stats);
+            return Err(e);
+        }
+    };
+    let started = std::time::Instant::now();
+    let cache_slot = prediction_cache::slot_for(&state, &model_dir, &project_name, &inputs);
+    let hit = cache_slot.as_ref().and_then(|slot| state.prediction_cache.get(&project_name, slot));
+    let cached = hit.is_some();
+    let result = match hit {
+        Some(outputs) => Ok(outputs),
+        None => python_runner_io::run_model(&projects_dir, &project_name, inputs.clone(), window, state.clone()).await,
+    };
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let contract = output_contract::OutputContract::read(&model_dir);
+    let (result, warnings) = match result {
+        Ok(outputs) => match contract.enforce(&outputs) {
+            Ok(warnings) => (Ok(outputs), warnings),
+            Err(e) => (Err(e), Vec::new()),
+        },
+        Err(e) => (Err(e), Vec::new()),
+    };
+    let contract_violations = match &result {
+        Err(ChanterelleError::Contract { violations, .. }) => violations.len(),
+        _ => warnings.len(),
+    };
+
+    if let (Some(slot), Ok(outputs), false) = (&cache_slot, &result, cached) {
+        if let Err(e) = state.prediction_cache.put(&project_name, slot, outputs) {
+            println!("Failed to cache prediction: {}", e);
+        }
+    }
+
+    let stats = history::RunStats { timestamp, latency_ms, pid, contract_violations, cached };
+    let run_id = history::record_run(&state, &project_name, inputs, &result, stats);
+    result.map(|outputs| ModelRunResponse { run_id, outputs, warnings, cached })
 }
 
 #[tauri::command]
@@ -81,28 +169,52 @@ async fn invoke_interactive(
     project_name: String,
     inputs: HashMap<String, serde_json::Value>,
     request_id: Option<String>,
+    session_id: Option<String>,
     session_turns: Option<Vec<serde_json::Value>>,
     window: tauri::Window,
     state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), ChanterelleError> {
     let projects_dir = {
         let settings = state.settings.lock().unwrap();
         settings.projects_directory.clone()
     };
-    python_runner_io::run_interactive(&projects_dir, &project_name, inputs, request_id, session_turns, window, state).await
+    let request = python_runner_io::InteractiveRequest {
+        inputs,
+        request_id,
+        session_id,
+        session_turns,
+    };
+    python_runner_io::run_interactive(&projects_dir, &project_name, request, window).await
+}
+
+#[tauri::command]
+async fn respond_interactive(
+    interaction_id: String,
+    response: serde_json::Value,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    python_runner_io::respond_interactive(interaction_id, response, state).await
+}
+
+#[tauri::command]
+async fn close_interactive_session(
+    session_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<bool, ChanterelleError> {
+    python_runner_io::close_interactive_session(session_id, state).await
 }
 
 #[tauri::command]
 async fn stop_interactive(
     request_id: Option<String>,
     state: tauri::State<'_, AppState>,
-) -> Result<bool, String> {
+) -> Result<bool, ChanterelleError> {
     python_runner_io::stop_interactive(request_id, state).await
 }
 
 // Settings commands
 #[tauri::command]
-async fn get_settings(state: tauri::State<'_, AppState>) -> Result<settings::Settings, String> {
+async fn get_settings(state: tauri::State<'_, AppState>) -> Result<settings::Settings, ChanterelleError> {
     let settings = state.settings.lock().unwrap();
     Ok(settings.clone())
 }
@@ -111,15 +223,40 @@ async fn get_settings(state: tauri::State<'_, AppState>) -> Result<settings::Set
 async fn set_projects_directory(
     path: String,
     state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), ChanterelleError> {
     let mut settings = state.settings.lock().unwrap();
     settings.projects_directory = path;
-    settings.save().map_err(|e| e.to_string())?;
+    settings.save().map_err(|e| ChanterelleError::io(e.to_string()))?;
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_editor(
+    editor: settings::EditorSetting,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    let mut settings = state.settings.lock().unwrap();
+    settings.editor = editor;
+    settings.save().map_err(|e| ChanterelleError::io(e.to_string()))?;
     Ok(())
 }
 
 #[tauri::command]
-async fn open_directory_dialog(app: tauri::AppHandle) -> Result<Option<String>, String> {
+async fn set_project_discovery(
+    discovery: settings::ProjectDiscovery,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    if discovery.max_depth == 0 {
+        return Err(ChanterelleError::config("Project discovery depth must be at least 1"));
+    }
+    let mut settings = state.settings.lock().unwrap();
+    settings.project_discovery = discovery;
+    settings.save().map_err(|e| ChanterelleError::io(e.to_string()))?;
+    Ok(())
+}
+
+#[tauri::command]
+async fn open_directory_dialog(app: tauri::AppHandle) -> Result<Option<String>, ChanterelleError> {
     use tauri_plugin_dialog::DialogExt;
     let (tx, rx) = tokio::sync::oneshot::channel();
 
@@ -133,221 +270,31 @@ async fn open_directory_dialog(app: tauri::AppHandle) -> Result<Option<String>,
     match rx.await {
         Ok(Some(path)) => Ok(Some(path.to_string())),
         Ok(None) => Ok(None),
-        Err(_) => Err("Failed to pick folder".to_string()),
-    }
-}
-
-#[tauri::command]
-async fn submit_feedback(
-    project_name: String,
-    feedback: serde_json::Value,
-    state: tauri::State<'_, AppState>,
-) -> Result<u64, String> {
-    let projects_dir = {
-        let settings = state.settings.lock().unwrap();
-        settings.projects_directory.clone()
-    };
-    
-    // Save to file
-    let feedback_file = std::path::Path::new(&projects_dir)
-        .join(&project_name)
-        .join("feedback.jsonl");
-
-    let timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-        
-    let entry = serde_json::json!({
-        "timestamp": timestamp,
-        "feedback": feedback
-    });
-    
-    use std::io::Write;
-    let mut file = std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(feedback_file)
-        .map_err(|e| format!("Failed to open feedback file: {}", e))?;
-        
-    writeln!(file, "{}", serde_json::to_string(&entry).unwrap())
-        .map_err(|e| format!("Failed to write feedback: {}", e))?;
-
-    // Send to python if running
-    python_runner_io::submit_feedback(feedback, state).await.map(|_| timestamp).map_err(|e| e)
-}
-
-#[tauri::command]
-async fn delete_feedback(
-    project_name: String,
-    timestamp: u64,
-    state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
-    let projects_dir = {
-        let settings = state.settings.lock().unwrap();
-        settings.projects_directory.clone()
-    };
-    
-    let feedback_file = std::path::Path::new(&projects_dir)
-        .join(&project_name)
-        .join("feedback.jsonl");
-        
-    if !feedback_file.exists() {
-        return Ok(());
-    }
-
-    // Read all lines
-    let file = std::fs::File::open(&feedback_file)
-        .map_err(|e| format!("Failed to open feedback file: {}", e))?;
-    let reader = std::io::BufReader::new(file);
-    
-    use std::io::BufRead;
-    let mut lines = Vec::new(); // Store valid lines
-    
-    for line in reader.lines() {
-        let line = line.map_err(|e| format!("Failed to read line: {}", e))?;
-        if line.trim().is_empty() { continue; }
-        
-        match serde_json::from_str::<serde_json::Value>(&line) {
-            Ok(entry) => {
-                // If timestamp doesn't match, keep it
-                let entry_ts = entry["timestamp"].as_u64().unwrap_or(0);
-                if entry_ts != timestamp {
-                    lines.push(line);
-                }
-            },
-            Err(_) => lines.push(line), // Keep malformed lines to be safe? Or maybe drop them. Let's keep.
-        }
-    }
-
-    // Write back
-    use std::io::Write;
-    let mut file = std::fs::File::create(&feedback_file)
-        .map_err(|e| format!("Failed to open feedback file for writing: {}", e))?;
-        
-    for line in lines {
-        writeln!(file, "{}", line)
-            .map_err(|e| format!("Failed to write feedback: {}", e))?;
-    }
-    
-    Ok(())
-}
-
-#[tauri::command]
-async fn update_feedback(
-    project_name: String,
-    timestamp: u64,
-    context: serde_json::Value,
-    state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
-    let projects_dir = {
-        let settings = state.settings.lock().unwrap();
-        settings.projects_directory.clone()
-    };
-
-    let feedback_file = std::path::Path::new(&projects_dir)
-        .join(&project_name)
-        .join("feedback.jsonl");
-
-    if !feedback_file.exists() {
-        return Err("Feedback file not found".to_string());
-    }
-
-    let file = std::fs::File::open(&feedback_file)
-        .map_err(|e| format!("Failed to open feedback file: {}", e))?;
-    let reader = std::io::BufReader::new(file);
-
-    use std::io::BufRead;
-    let mut lines = Vec::new();
-
-    for line in reader.lines() {
-        let line = line.map_err(|e| format!("Failed to read line: {}", e))?;
-        if line.trim().is_empty() { continue; }
-
-        match serde_json::from_str::<serde_json::Value>(&line) {
-            Ok(mut entry) => {
-                let entry_ts = entry["timestamp"].as_u64().unwrap_or(0);
-                if entry_ts == timestamp {
-                    // Update the context within the feedback object
-                    if let Some(fb) = entry.get_mut("feedback") {
-                        fb["context"] = context.clone();
-                    }
-                    lines.push(serde_json::to_string(&entry).unwrap());
-                } else {
-                    lines.push(line);
-                }
-            },
-            Err(_) => lines.push(line),
-        }
-    }
-
-    use std::io::Write;
-    let mut file = std::fs::File::create(&feedback_file)
-        .map_err(|e| format!("Failed to open feedback file for writing: {}", e))?;
-
-    for line in lines {
-        writeln!(file, "{}", line)
-            .map_err(|e| format!("Failed to write feedback: {}", e))?;
-    }
-
-    Ok(())
-}
-
-#[tauri::command]
-async fn get_feedback_history(
-    project_name: String,
-    state: tauri::State<'_, AppState>,
-) -> Result<Vec<serde_json::Value>, String> {
-    let projects_dir = {
-        let settings = state.settings.lock().unwrap();
-        settings.projects_directory.clone()
-    };
-    
-    let feedback_file = std::path::Path::new(&projects_dir)
-        .join(&project_name)
-        .join("feedback.jsonl");
-        
-    if !feedback_file.exists() {
-        return Ok(Vec::new());
-    }
-
-    let file = std::fs::File::open(feedback_file)
-        .map_err(|e| format!("Failed to open feedback file: {}", e))?;
-    let reader = std::io::BufReader::new(file);
-    
-    use std::io::BufRead;
-    let mut history = Vec::new();
-    
-    for line in reader.lines() {
-        let line = line.map_err(|e| format!("Failed to read line: {}", e))?;
-        if line.trim().is_empty() { continue; }
-        
-        match serde_json::from_str::<serde_json::Value>(&line) {
-            Ok(entry) => history.push(entry),
-            Err(e) => println!("Failed to parse feedback line: {}", e),
-        }
+        Err(_) => Err(ChanterelleError::io("Failed to pick folder")),
     }
-    
-    // Reverse to show newest first
-    history.reverse();
-    Ok(history)
 }
 
 #[tauri::command]
 async fn cleanup_python_process(
     state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), ChanterelleError> {
     python_runner_io::cleanup_python_process(state).await
 }
 
 #[tauri::command]
 async fn force_kill_python_process(
     state: tauri::State<'_, AppState>,
-) -> Result<bool, String> {
+) -> Result<bool, ChanterelleError> {
     python_runner_io::force_kill_python_process(state).await
 }
 
 fn main() {
+    // Headless mode for CI: `chanterelle test <project-folder> [--junit <file>]`
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(|a| a.as_str()) == Some("test") {
+        std::process::exit(project_tests::run_cli(&args[2..]));
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .manage(AppState::default())
@@ -392,15 +339,46 @@ fn main() {
             invoke_model,
             invoke_interactive,
             stop_interactive,
+            respond_interactive,
+            close_interactive_session,
             get_settings,
             set_projects_directory,
+            set_editor,
+            set_project_discovery,
             open_directory_dialog,
+            editor::open_in_editor,
+            history::query_history,
+            history::get_history_entry,
+            history::delete_history_entries,
+            history::clear_history,
+            replay::replay_history,
+            compare::compare_models,
+            project_tests::run_project_tests,
+            benchmark::benchmark_model,
+            sweep::sweep_input,
+            prediction_cache::get_prediction_cache,
+            prediction_cache::clear_prediction_cache,
+            host_services::get_project_metrics,
+            kv_store::list_kv_namespaces,
+            kv_store::get_kv_entries,
+            kv_store::delete_kv_entry,
+            kv_store::clear_kv_store,
+            sessions::list_interactive_sessions,
+            sessions::load_interactive_session,
+            sessions::resume_interactive_session,
+            sessions::rename_interactive_session,
+            sessions::delete_interactive_session,
+            sessions::export_interactive_session,
             cleanup_python_process,
             force_kill_python_process,
-            submit_feedback,
-            get_feedback_history,
-            delete_feedback,
-            update_feedback,
+            feedback::submit_feedback,
+            feedback::get_feedback_history,
+            feedback::delete_feedback,
+            feedback::update_feedback,
+            feedback_export::export_feedback,
+            feedback_outbox::replay_feedback,
+            feedback_query::query_feedback,
+            feedback_summary::get_feedback_summary,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,136 @@
+// src-tauri/src/output_contract.rs
+// Checks handler responses against the `outputs` declared in model_meta.json.
+use std::fs;
+use std::path::Path;
+
+use crate::error::{ChanterelleError, ContractViolation};
+use crate::output_diff;
+use crate::types::{ModelOutput, ModelOutputOptions, ModelOutputType};
+
+/// The declared outputs of a project and whether breaking them is an error.
+#[derive(Debug, Clone, Default)]
+pub struct OutputContract {
+    pub outputs: Vec<ModelOutput>,
+    pub strict: bool,
+}
+
+impl OutputContract {
+    /// Reads the contract from model_meta.json; empty when missing or invalid.
+    pub fn read(model_dir: &Path) -> Self {
+        let strict = fs::read_to_string(model_dir.join("model_meta.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|meta| meta["strict_outputs"].as_bool())
+            .unwrap_or(false);
+        Self { outputs: output_diff::read_model_outputs(model_dir), strict }
+    }
+
+    /// Every field of the response that breaks its declaration. Outputs are matched
+    /// to the fields named like them (see `output_diff::output_fields`); outputs the
+    /// response does not contain are not reported.
+    pub fn check(&self, response: &serde_json::Value) -> Vec<ContractViolation> {
+        // Errors reported by the handler have no outputs to check
+        if response.get("error").is_some() {
+            return Vec::new();
+        }
+        let mut violations = Vec::new();
+        for output in &self.outputs {
+            for (path, value) in output_diff::output_fields(response, &output.name) {
+                if let Err(message) = check_field(output, &value) {
+                    violations.push(ContractViolation {
+                        output: output.name.clone(),
+                        message: format!("{} at {}: {}", output.name, path, message),
+                        path,
+                    });
+                }
+            }
+        }
+        violations
+    }
+
+    /// Violations as warnings, or an error in strict mode.
+    pub fn enforce(&self, response: &serde_json::Value) -> Result<Vec<ContractViolation>, ChanterelleError> {
+        let violations = self.check(response);
+        if self.strict && !violations.is_empty() {
+            return Err(ChanterelleError::contract(violations));
+        }
+        Ok(violations)
+    }
+}
+
+pub fn check_field(output: &ModelOutput, value: &serde_json::Value) -> Result<(), String> {
+    match output.output_type {
+        ModelOutputType::Float | ModelOutputType::Int => {
+            let number = value.as_f64().ok_or_else(|| format!("expected a number, got {}", value))?;
+            if matches!(output.output_type, ModelOutputType::Int) && number.fract() != 0.0 {
+                return Err(format!("expected a whole number, got {}", value));
+            }
+            if let Some(min) = output.min.filter(|min| number < *min) {
+                return Err(format!("{} is below the minimum {}", number, min));
+            }
+            if let Some(max) = output.max.filter(|max| number > *max) {
+                return Err(format!("{} is above the maximum {}", number, max));
+            }
+            Ok(())
+        }
+        ModelOutputType::String => {
+            let text = value.as_str().ok_or_else(|| format!("expected text, got {}", value))?;
+            let allowed: Option<Vec<&str>> = output.options.as_ref().map(|options| match options {
+                ModelOutputOptions::Strings(options) => options.iter().map(|o| o.as_str()).collect(),
+                ModelOutputOptions::Objects(options) => options.iter().map(|o| o.value.as_str()).collect(),
+            });
+            match allowed {
+                Some(allowed) if !allowed.contains(&text) => Err(format!("'{}' is not one of the allowed options", text)),
+                _ => Ok(()),
+            }
+        }
+        ModelOutputType::Boolean if value.is_boolean() => Ok(()),
+        ModelOutputType::Boolean => Err(format!("expected true or false, got {}", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn contract() -> OutputContract {
+        let outputs = serde_json::from_value(json!([
+            { "name": "price", "label": "Price", "type": "float", "min": 0 },
+            { "name": "label", "label": "Label", "type": "string", "options": ["cheap", "dear"] }
+        ]))
+        .unwrap();
+        OutputContract { outputs, strict: true }
+    }
+
+    #[test]
+    fn items_keyed_by_an_output_name_are_not_checked_as_its_value() {
+        let response = json!({
+            "sections": [{
+                "id": "price",
+                "type": "group",
+                "items": [{ "type": "text", "id": "price", "title": "Price", "price": 12.5 }]
+            }],
+            "label": "cheap"
+        });
+        assert!(contract().check(&response).is_empty());
+        assert!(contract().enforce(&response).is_ok());
+    }
+
+    #[test]
+    fn fields_named_like_an_output_are_checked() {
+        let response = json!({
+            "sections": [{ "id": "summary", "items": [{ "id": "price", "price": -1.0 }] }],
+            "label": "free"
+        });
+        let violations = contract().check(&response);
+        let paths: Vec<&str> = violations.iter().map(|v| v.path.as_str()).collect();
+        assert_eq!(paths, ["sections/summary/items/price/price", "label"]);
+        assert!(contract().enforce(&response).is_err());
+    }
+
+    #[test]
+    fn handler_errors_are_not_checked() {
+        assert!(contract().check(&json!({ "error": "boom", "price": "n/a" })).is_empty());
+    }
+}
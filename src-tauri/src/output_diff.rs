@@ -0,0 +1,161 @@
+// src-tauri/src/output_diff.rs
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use crate::types::{ModelOutput, ModelOutputType};
+
+/// Absolute tolerance for float outputs (and unmatched numbers) when none is configured
+pub const DEFAULT_ABS_TOLERANCE: f64 = 1e-6;
+/// Relative tolerance applied on top of the absolute one
+pub const DEFAULT_REL_TOLERANCE: f64 = 1e-6;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffKind {
+    Changed,
+    Added,
+    Removed,
+}
+
+/// One leaf of the output JSON that differs between two runs.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FieldDiff {
+    /// Slash-separated path; sections and items are addressed by `id` when they have one
+    pub path: String,
+    /// `ModelOutput` the field was matched to, if any
+    pub output: Option<String>,
+    pub kind: DiffKind,
+    pub baseline: Option<serde_json::Value>,
+    pub current: Option<serde_json::Value>,
+    /// current - baseline, for numeric fields
+    pub delta: Option<f64>,
+}
+
+/// How fields are compared: the project's declared outputs plus per-output tolerances.
+#[derive(Debug, Clone, Default)]
+pub struct DiffOptions {
+    pub outputs: Vec<ModelOutput>,
+    /// Absolute tolerance per output name
+    pub tolerances: HashMap<String, f64>,
+    /// Absolute tolerance for float fields without their own entry
+    pub default_tolerance: Option<f64>,
+}
+
+impl DiffOptions {
+    fn output_for(&self, path: &str) -> Option<&ModelOutput> {
+        path.rsplit('/')
+            .find_map(|segment| self.outputs.iter().find(|o| o.name == segment))
+    }
+
+    fn tolerance_for(&self, output: Option<&ModelOutput>) -> f64 {
+        output
+            .and_then(|o| self.tolerances.get(&o.name).copied())
+            .or(self.default_tolerance)
+            .unwrap_or(DEFAULT_ABS_TOLERANCE)
+    }
+
+    fn values_match(&self, output: Option<&ModelOutput>, a: &serde_json::Value, b: &serde_json::Value) -> bool {
+        let numbers = a.as_f64().zip(b.as_f64());
+        match (output.map(|o| &o.output_type), numbers) {
+            (Some(ModelOutputType::Int), Some((x, y))) => x == y,
+            (Some(ModelOutputType::String | ModelOutputType::Boolean), _) => a == b,
+            (_, Some((x, y))) => {
+                let tolerance = self.tolerance_for(output);
+                (x - y).abs() <= tolerance + DEFAULT_REL_TOLERANCE * x.abs().max(y.abs())
+            }
+            _ => a == b,
+        }
+    }
+}
+
+/// Reads the declared `outputs` of a project's model_meta.json; empty when missing or invalid.
+pub fn read_model_outputs(model_dir: &Path) -> Vec<ModelOutput> {
+    fs::read_to_string(model_dir.join("model_meta.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|meta| meta.get("outputs").cloned())
+        .and_then(|outputs| serde_json::from_value(outputs).ok())
+        .unwrap_or_default()
+}
+
+/// Flattens a JSON document into path -> leaf value. Array elements with a string
+/// `id` are keyed by it, so reordering sections does not show up as a change.
+fn flatten(value: &serde_json::Value, prefix: &str, out: &mut BTreeMap<String, serde_json::Value>) {
+    let join = |key: &str| if prefix.is_empty() { key.to_string() } else { format!("{}/{}", prefix, key) };
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                flatten(child, &join(key), out);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                let key = child
+                    .get("id")
+                    .and_then(|id| id.as_str())
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| index.to_string());
+                flatten(child, &join(&key), out);
+            }
+        }
+        leaf => {
+            out.insert(prefix.to_string(), leaf.clone());
+        }
+    }
+}
+
+/// Leaves of a handler response named `name`, i.e. whose last path segment is the
+/// name. Sections and items merely keyed by the name (their `id`) don't match, so
+/// their `type` or `title` are not taken for the output's value.
+pub fn output_fields(response: &serde_json::Value, name: &str) -> Vec<(String, serde_json::Value)> {
+    let mut fields = BTreeMap::new();
+    flatten(response, "", &mut fields);
+    fields
+        .into_iter()
+        .filter(|(path, _)| path.rsplit('/').next() == Some(name))
+        .collect()
+}
+
+/// First numeric field of a handler response named `name` (see `output_fields`).
+pub fn output_number(response: &serde_json::Value, name: &str) -> Option<serde_json::Value> {
+    output_fields(response, name).into_iter().map(|(_, value)| value).find(|value| value.is_number())
+}
+
+/// Compares two handler responses field by field.
+pub fn diff_outputs(baseline: &serde_json::Value, current: &serde_json::Value, options: &DiffOptions) -> Vec<FieldDiff> {
+    let mut before = BTreeMap::new();
+    let mut after = BTreeMap::new();
+    flatten(baseline, "", &mut before);
+    flatten(current, "", &mut after);
+
+    let mut diffs = Vec::new();
+    for (path, old) in &before {
+        let output = options.output_for(path);
+        match after.get(path) {
+            Some(new) if options.values_match(output, old, new) => {}
+            new => diffs.push(FieldDiff {
+                path: path.clone(),
+                output: output.map(|o| o.name.clone()),
+                kind: if new.is_some() { DiffKind::Changed } else { DiffKind::Removed },
+                baseline: Some(old.clone()),
+                current: new.cloned(),
+                delta: new.and_then(|n| n.as_f64()).zip(old.as_f64()).map(|(n, o)| n - o),
+            }),
+        }
+    }
+    for (path, new) in &after {
+        if !before.contains_key(path) {
+            diffs.push(FieldDiff {
+                path: path.clone(),
+                output: options.output_for(path).map(|o| o.name.clone()),
+                kind: DiffKind::Added,
+                baseline: None,
+                current: Some(new.clone()),
+                delta: None,
+            });
+        }
+    }
+    diffs
+}
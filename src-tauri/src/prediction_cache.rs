@@ -0,0 +1,370 @@
+// src-tauri/src/prediction_cache.rs
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use crate::error::ChanterelleError;
+use crate::state::AppState;
+use crate::storage;
+use crate::types::PredictionCacheConfig;
+
+const DEFAULT_MAX_ENTRIES: usize = 500;
+const DEFAULT_MAX_BYTES: u64 = 256 * 1024 * 1024;
+const DEFAULT_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// One cached response, stored as `<key>.json` in the project's cache directory.
+/// The file is written once, so its mtime is the creation time pruning applies the TTL to.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CacheEntry {
+    key: String,
+    /// Milliseconds since the Unix epoch
+    created_at: u64,
+    model_version: Option<String>,
+    inputs: HashMap<String, serde_json::Value>,
+    outputs: serde_json::Value,
+}
+
+/// Hit counter of an entry, stored next to it as `<key>.hits` so a hit doesn't rewrite
+/// the entry. Its mtime is the last use pruning orders by.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+struct CacheHits {
+    hits: u64,
+    last_hit_at: Option<u64>,
+}
+
+fn hits_path(entry_path: &Path) -> PathBuf {
+    entry_path.with_extension("hits")
+}
+
+fn read_hits(entry_path: &Path) -> CacheHits {
+    fs::read(hits_path(entry_path))
+        .ok()
+        .and_then(|content| serde_json::from_slice(&content).ok())
+        .unwrap_or_default()
+}
+
+fn remove_entry(entry_path: &Path) {
+    let _ = fs::remove_file(entry_path);
+    let _ = fs::remove_file(hits_path(entry_path));
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).ok()?.modified().ok()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CacheEntrySummary {
+    pub key: String,
+    pub created_at: u64,
+    pub last_hit_at: Option<u64>,
+    pub hits: u64,
+    pub model_version: Option<String>,
+    pub inputs: HashMap<String, serde_json::Value>,
+    pub bytes: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CacheSummary {
+    /// Whether model_meta.json turns the cache on
+    pub enabled: bool,
+    pub max_entries: usize,
+    pub max_bytes: u64,
+    pub ttl_seconds: u64,
+    pub total_bytes: u64,
+    /// Most recently used first
+    pub entries: Vec<CacheEntrySummary>,
+}
+
+/// Where a cacheable call is stored, and the limits that apply to it.
+#[derive(Debug, Clone)]
+pub struct CacheSlot {
+    pub key: String,
+    pub config: PredictionCacheConfig,
+    pub model_version: Option<String>,
+    pub inputs: HashMap<String, serde_json::Value>,
+}
+
+/// Opt-in cache of model responses, one file per entry under the app data directory,
+/// so it survives restarts. Entries are keyed by project, `model_version`, a fingerprint
+/// of the project files and the canonicalized inputs.
+#[derive(Default)]
+pub struct PredictionCache {
+    // Serializes writes and pruning of cache files
+    lock: Mutex<()>,
+}
+
+fn cache_dir(project_name: &str) -> Result<PathBuf, ChanterelleError> {
+    let dir = storage::project_data_dir(project_name)?.join("prediction_cache");
+    fs::create_dir_all(&dir)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create cache directory: {}", e)))?;
+    Ok(dir)
+}
+
+fn entry_path(project_name: &str, key: &str) -> Result<PathBuf, ChanterelleError> {
+    storage::validate_id(key)?;
+    Ok(cache_dir(project_name)?.join(format!("{}.json", key)))
+}
+
+fn max_entries(config: &PredictionCacheConfig) -> usize {
+    config.max_entries.unwrap_or(DEFAULT_MAX_ENTRIES)
+}
+
+fn max_bytes(config: &PredictionCacheConfig) -> u64 {
+    config.max_bytes.unwrap_or(DEFAULT_MAX_BYTES)
+}
+
+fn ttl(config: &PredictionCacheConfig) -> Duration {
+    Duration::from_secs(config.ttl_seconds.unwrap_or(DEFAULT_TTL_SECONDS))
+}
+
+/// The `prediction_cache` section of model_meta.json; `None` when caching is off.
+pub fn read_config(model_dir: &Path) -> Option<PredictionCacheConfig> {
+    let content = fs::read_to_string(model_dir.join("model_meta.json")).ok()?;
+    let meta: serde_json::Value = serde_json::from_str(&content).ok()?;
+    serde_json::from_value(meta.get("prediction_cache")?.clone()).ok()
+}
+
+/// JSON with object keys sorted at every level, so equal inputs hash the same.
+fn canonical(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            serde_json::Value::Object(keys.into_iter().map(|k| (k.clone(), canonical(&map[k]))).collect())
+        }
+        serde_json::Value::Array(items) => serde_json::Value::Array(items.iter().map(canonical).collect()),
+        other => other.clone(),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn is_skipped_dir(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    name.starts_with('.') || name == "__pycache__" || name == "node_modules" || path.join("pyvenv.cfg").exists()
+}
+
+fn collect_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if !is_skipped_dir(&path) {
+                collect_files(&path, out);
+            }
+        } else {
+            out.push(path);
+        }
+    }
+}
+
+/// Short hash of the project folder: the contents of Python files, and the size and
+/// modification time of everything else (model artifacts can be large). Hidden folders,
+/// `__pycache__`, virtual environments and `storage::PROJECT_APP_FILES` (they change
+/// while the handler stays the same) are left out.
+pub fn artifacts_fingerprint(model_dir: &Path) -> String {
+    let mut files = Vec::new();
+    collect_files(model_dir, &mut files);
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for path in files {
+        let relative = path.strip_prefix(model_dir).unwrap_or(&path);
+        if storage::PROJECT_APP_FILES.iter().any(|name| relative == Path::new(name)) {
+            continue;
+        }
+        hasher.update(relative.to_string_lossy().as_bytes());
+        if path.extension().and_then(|e| e.to_str()) == Some("py") {
+            hasher.update(fs::read(&path).unwrap_or_default());
+        } else if let Ok(metadata) = fs::metadata(&path) {
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|m| m.duration_since(SystemTime::UNIX_EPOCH).ok())
+                .map(|d| d.as_nanos())
+                .unwrap_or(0);
+            hasher.update(metadata.len().to_le_bytes());
+            hasher.update(modified.to_le_bytes());
+        }
+    }
+    hex(&hasher.finalize()[..8])
+}
+
+/// Cache slot for a call, when the project has caching on and is the loaded one.
+/// Expects validated inputs, so `3` and `"3"` share an entry.
+pub fn slot_for(
+    state: &AppState,
+    model_dir: &Path,
+    project_name: &str,
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Option<CacheSlot> {
+    let config = read_config(model_dir)?;
+    let active = state.active_project.lock().unwrap().clone();
+    let active = active.filter(|p| p.project_name == project_name)?;
+
+    let keyed = serde_json::json!({
+        "project_name": project_name,
+        "model_version": active.model_version,
+        "artifacts": active.artifacts_fingerprint,
+        "inputs": inputs,
+    });
+    let key = hex(&Sha256::digest(serde_json::to_vec(&canonical(&keyed)).unwrap()));
+    Some(CacheSlot { key, config, model_version: active.model_version, inputs: inputs.clone() })
+}
+
+impl PredictionCache {
+    /// Cached outputs for a slot, unless missing or older than the TTL.
+    pub fn get(&self, project_name: &str, slot: &CacheSlot) -> Option<serde_json::Value> {
+        let _guard = self.lock.lock().unwrap();
+        let path = entry_path(project_name, &slot.key).ok()?;
+        let content = fs::read_to_string(&path).ok()?;
+        let Ok(entry) = serde_json::from_str::<CacheEntry>(&content) else {
+            remove_entry(&path);
+            return None;
+        };
+        let now = storage::now_millis();
+        if now.saturating_sub(entry.created_at) > ttl(&slot.config).as_millis() as u64 {
+            remove_entry(&path);
+            return None;
+        }
+        let mut hits = read_hits(&path);
+        hits.hits += 1;
+        hits.last_hit_at = Some(now);
+        if let Err(e) = storage::write_atomic(&hits_path(&path), &serde_json::to_vec(&hits).unwrap()) {
+            println!("Failed to update cache hits: {}", e);
+        }
+        Some(entry.outputs)
+    }
+
+    /// Stores outputs for a slot, then evicts expired and least recently used entries.
+    pub fn put(&self, project_name: &str, slot: &CacheSlot, outputs: &serde_json::Value) -> Result<(), ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let entry = CacheEntry {
+            key: slot.key.clone(),
+            created_at: storage::now_millis(),
+            model_version: slot.model_version.clone(),
+            inputs: slot.inputs.clone(),
+            outputs: outputs.clone(),
+        };
+        let path = entry_path(project_name, &slot.key)?;
+        // A replaced entry starts counting hits again
+        let _ = fs::remove_file(hits_path(&path));
+        storage::write_atomic(&path, &serde_json::to_vec(&entry).unwrap())?;
+        self.prune(project_name, &slot.config)
+    }
+
+    fn prune(&self, project_name: &str, config: &PredictionCacheConfig) -> Result<(), ChanterelleError> {
+        let dir = cache_dir(project_name)?;
+        // (path, created, last used, bytes), from file metadata only
+        let mut files: Vec<(PathBuf, SystemTime, SystemTime, u64)> = fs::read_dir(&dir)
+            .map_err(|e| ChanterelleError::io(format!("Failed to read cache directory: {}", e)))?
+            .flatten()
+            .filter(|entry| entry.path().extension().and_then(|e| e.to_str()) == Some("json"))
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                let created = metadata.modified().ok()?;
+                let used = modified(&hits_path(&entry.path())).map_or(created, |hit| hit.max(created));
+                Some((entry.path(), created, used, metadata.len()))
+            })
+            .collect();
+        // Most recently used first
+        files.sort_by_key(|(_, _, used, _)| std::cmp::Reverse(*used));
+
+        let now = SystemTime::now();
+        let mut kept = 0;
+        let mut kept_bytes = 0;
+        for (path, created, _, bytes) in files {
+            let expired = now.duration_since(created).is_ok_and(|age| age > ttl(config));
+            if expired || kept >= max_entries(config) || kept_bytes + bytes > max_bytes(config) {
+                remove_entry(&path);
+            } else {
+                kept += 1;
+                kept_bytes += bytes;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn summary(&self, project_name: &str, config: Option<&PredictionCacheConfig>) -> Result<CacheSummary, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let dir = cache_dir(project_name)?;
+        let mut entries = Vec::new();
+        for file in fs::read_dir(&dir)
+            .map_err(|e| ChanterelleError::io(format!("Failed to read cache directory: {}", e)))?
+            .flatten()
+        {
+            let path = file.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let Ok(content) = fs::read_to_string(&path) else { continue };
+            let Ok(entry) = serde_json::from_str::<CacheEntry>(&content) else { continue };
+            let hits = read_hits(&path);
+            entries.push(CacheEntrySummary {
+                key: entry.key,
+                created_at: entry.created_at,
+                last_hit_at: hits.last_hit_at,
+                hits: hits.hits,
+                model_version: entry.model_version,
+                inputs: entry.inputs,
+                bytes: content.len() as u64,
+            });
+        }
+        entries.sort_by_key(|e| std::cmp::Reverse(e.last_hit_at.unwrap_or(e.created_at)));
+
+        let defaults = PredictionCacheConfig::default();
+        let limits = config.unwrap_or(&defaults);
+        Ok(CacheSummary {
+            enabled: config.is_some(),
+            max_entries: max_entries(limits),
+            max_bytes: max_bytes(limits),
+            ttl_seconds: ttl(limits).as_secs(),
+            total_bytes: entries.iter().map(|e| e.bytes).sum(),
+            entries,
+        })
+    }
+
+    /// Removes every cached entry of a project; returns how many there were.
+    pub fn clear(&self, project_name: &str) -> Result<usize, ChanterelleError> {
+        let _guard = self.lock.lock().unwrap();
+        let dir = cache_dir(project_name)?;
+        let count = fs::read_dir(&dir)
+            .map_err(|e| ChanterelleError::io(format!("Failed to read cache directory: {}", e)))?
+            .flatten()
+            .filter(|entry| entry.path().extension().and_then(|e| e.to_str()) == Some("json"))
+            .count();
+        fs::remove_dir_all(&dir)
+            .map_err(|e| ChanterelleError::io(format!("Failed to clear cache directory: {}", e)))?;
+        Ok(count)
+    }
+}
+
+// --- Commands ---
+
+/// Cached responses of a project with the limits that apply to them.
+#[tauri::command]
+pub async fn get_prediction_cache(
+    project_name: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<CacheSummary, ChanterelleError> {
+    let projects_dir = {
+        let settings = state.settings.lock().unwrap();
+        settings.projects_directory.clone()
+    };
+    let config = read_config(&Path::new(&projects_dir).join(&project_name));
+    state.prediction_cache.summary(&project_name, config.as_ref())
+}
+
+#[tauri::command]
+pub async fn clear_prediction_cache(
+    project_name: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<usize, ChanterelleError> {
+    state.prediction_cache.clear(&project_name)
+}
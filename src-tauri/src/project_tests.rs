@@ -0,0 +1,393 @@
+// src-tauri/src/project_tests.rs
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::error::ChanterelleError;
+use crate::output_diff::{self, DiffKind, DiffOptions, FieldDiff};
+use crate::python_runner_io;
+use crate::report::{cell, table};
+use crate::state::AppState;
+use crate::validation;
+
+/// Folder inside a project holding golden test files
+const TESTS_DIR: &str = "tests";
+
+/// One golden test: inputs, the outputs they should produce and how strictly to compare.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TestCase {
+    pub name: Option<String>,
+    pub inputs: HashMap<String, serde_json::Value>,
+    /// Expected handler response. Only the fields it contains are checked; when omitted
+    /// the case only checks that the run succeeds
+    pub expected: Option<serde_json::Value>,
+    /// Absolute tolerance per `ModelOutput` name
+    #[serde(default)]
+    pub tolerances: HashMap<String, f64>,
+    pub default_tolerance: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    /// The handler raised instead of returning outputs
+    Error,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TestCaseResult {
+    pub name: String,
+    /// File the case came from, or "model_meta.json"
+    pub source: String,
+    pub status: TestStatus,
+    pub diffs: Vec<FieldDiff>,
+    pub error: Option<ChanterelleError>,
+    pub outputs: Option<serde_json::Value>,
+    pub duration_ms: u64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TestRunReport {
+    pub project_name: String,
+    pub model_version: Option<String>,
+    pub passed: usize,
+    pub failed: usize,
+    pub errors: usize,
+    /// Spawn through ping, in milliseconds
+    pub warmup_ms: u64,
+    pub duration_ms: u64,
+    pub cases: Vec<TestCaseResult>,
+    pub sections: Vec<serde_json::Value>,
+    pub junit_xml: String,
+}
+
+impl TestRunReport {
+    pub fn all_passed(&self) -> bool {
+        self.failed == 0 && self.errors == 0
+    }
+}
+
+fn parse_cases(value: serde_json::Value, source: &str) -> Result<Vec<(String, TestCase)>, ChanterelleError> {
+    let values = match value {
+        serde_json::Value::Array(values) => values,
+        value => vec![value],
+    };
+    values
+        .into_iter()
+        .map(|value| {
+            serde_json::from_value::<TestCase>(value)
+                .map(|case| (source.to_string(), case))
+                .map_err(|e| ChanterelleError::config(format!("Invalid test case in {}: {}", source, e)))
+        })
+        .collect()
+}
+
+/// Collects the cases from `tests/*.json` and `test_cases` in model_meta.json.
+pub fn load_test_cases(model_dir: &Path) -> Result<Vec<(String, TestCase)>, ChanterelleError> {
+    let mut cases = Vec::new();
+
+    let meta_path = model_dir.join("model_meta.json");
+    if meta_path.exists() {
+        let content = fs::read_to_string(&meta_path)
+            .map_err(|e| ChanterelleError::io(format!("Failed to read model_meta.json: {}", e)))?;
+        let mut meta: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| ChanterelleError::config(format!("Failed to parse model_meta.json: {}", e)))?;
+        crate::projects::resolve_json_refs(&mut meta, model_dir)?;
+        if let Some(test_cases) = meta.get_mut("test_cases").map(serde_json::Value::take) {
+            cases.extend(parse_cases(test_cases, "model_meta.json")?);
+        }
+    }
+
+    let tests_dir = model_dir.join(TESTS_DIR);
+    if tests_dir.is_dir() {
+        let mut files: Vec<_> = fs::read_dir(&tests_dir)
+            .map_err(|e| ChanterelleError::io(format!("Failed to read tests directory: {}", e)))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("json"))
+            .collect();
+        files.sort();
+        for path in files {
+            let source = format!("{}/{}", TESTS_DIR, path.file_name().unwrap().to_string_lossy());
+            let content = fs::read_to_string(&path)
+                .map_err(|e| ChanterelleError::io(format!("Failed to read {}: {}", source, e)))?;
+            let mut value: serde_json::Value = serde_json::from_str(&content)
+                .map_err(|e| ChanterelleError::config(format!("Failed to parse {}: {}", source, e)))?;
+            crate::projects::resolve_json_refs(&mut value, model_dir)?;
+            cases.extend(parse_cases(value, &source)?);
+        }
+    }
+    Ok(cases)
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn junit_xml(project_name: &str, cases: &[TestCaseResult], failed: usize, errors: usize, duration_ms: u64) -> String {
+    let seconds = |ms: u64| format!("{:.3}", ms as f64 / 1000.0);
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{}\">\n",
+        xml_escape(project_name),
+        cases.len(),
+        failed,
+        errors,
+        seconds(duration_ms)
+    ));
+    for case in cases {
+        xml.push_str(&format!(
+            "  <testcase classname=\"{}\" name=\"{}\" file=\"{}\" time=\"{}\"",
+            xml_escape(project_name),
+            xml_escape(&case.name),
+            xml_escape(&case.source),
+            seconds(case.duration_ms)
+        ));
+        match case.status {
+            TestStatus::Passed => xml.push_str("/>\n"),
+            TestStatus::Failed => {
+                let details: Vec<String> = case
+                    .diffs
+                    .iter()
+                    .map(|d| format!("{}: expected {}, got {}", d.path, cell(d.baseline.as_ref()), cell(d.current.as_ref())))
+                    .collect();
+                xml.push_str(&format!(
+                    ">\n    <failure message=\"{} field(s) differ from expected\">{}</failure>\n  </testcase>\n",
+                    case.diffs.len(),
+                    xml_escape(&details.join("\n"))
+                ));
+            }
+            TestStatus::Error => {
+                let error = case.error.as_ref().map(|e| e.message()).unwrap_or_default();
+                xml.push_str(&format!(
+                    ">\n    <error message=\"{}\"/>\n  </testcase>\n",
+                    xml_escape(error)
+                ));
+            }
+        }
+    }
+    xml.push_str("</testsuite>\n");
+    xml
+}
+
+fn report_sections(report: &TestRunReport) -> Vec<serde_json::Value> {
+    let case_rows = report
+        .cases
+        .iter()
+        .map(|case| serde_json::json!({
+            "case": case.name,
+            "source": case.source,
+            "status": case.status,
+            "details": match case.status {
+                TestStatus::Passed => String::new(),
+                TestStatus::Failed => format!("{} field(s) differ", case.diffs.len()),
+                TestStatus::Error => case.error.as_ref().map(|e| e.message()).unwrap_or_default().to_string(),
+            },
+            "time": case.duration_ms,
+        }))
+        .collect();
+    let mut sections = vec![serde_json::json!({
+        "type": "section",
+        "id": "project_tests",
+        "title": "Test Results",
+        "color": if report.all_passed() { "green" } else { "red" },
+        "description": format!(
+            "{} passed, {} failed, {} errors in {} ms",
+            report.passed, report.failed, report.errors, report.duration_ms
+        ),
+        "items": [table(
+            "Cases",
+            &[("Case", "case"), ("Source", "source"), ("Status", "status"), ("Details", "details"), ("Time (ms)", "time")],
+            case_rows,
+        )],
+    })];
+
+    let diff_rows: Vec<serde_json::Value> = report
+        .cases
+        .iter()
+        .flat_map(|case| {
+            case.diffs.iter().map(move |diff| serde_json::json!({
+                "case": case.name,
+                "field": diff.output.clone().unwrap_or_else(|| diff.path.clone()),
+                "path": diff.path,
+                "expected": cell(diff.baseline.as_ref()),
+                "actual": cell(diff.current.as_ref()),
+            }))
+        })
+        .collect();
+    if !diff_rows.is_empty() {
+        sections.push(serde_json::json!({
+            "type": "section",
+            "id": "project_test_diffs",
+            "title": "Differences",
+            "items": [table(
+                "Fields that differ from the expected outputs",
+                &[("Case", "case"), ("Field", "field"), ("Path", "path"), ("Expected", "expected"), ("Actual", "actual")],
+                diff_rows,
+            )],
+        }));
+    }
+    sections
+}
+
+/// Runs every golden test of a project in a fresh Python process of its own.
+pub fn run_tests(projects_dir: &str, project_name: &str) -> Result<TestRunReport, ChanterelleError> {
+    let model_dir = Path::new(projects_dir).join(project_name);
+    let cases = load_test_cases(&model_dir)?;
+    if cases.is_empty() {
+        return Err(ChanterelleError::config(format!(
+            "No test cases found in {}/ or test_cases of model_meta.json",
+            TESTS_DIR
+        )));
+    }
+    let outputs = output_diff::read_model_outputs(&model_dir);
+    let declared = validation::read_model_inputs(&model_dir)?;
+
+    let started = Instant::now();
+    let (mut process, project) = python_runner_io::spawn_project_process(projects_dir, project_name, None)?;
+    python_runner_io::check_detached_model_ready(&mut process)?;
+    let warmup_ms = started.elapsed().as_millis() as u64;
+
+    let mut results = Vec::with_capacity(cases.len());
+    for (index, (source, case)) in cases.into_iter().enumerate() {
+        let name = case.name.clone().unwrap_or_else(|| format!("case {}", index + 1));
+        let case_started = Instant::now();
+        let result = validation::validate_inputs(&declared, case.inputs.clone())
+            .and_then(|inputs| python_runner_io::run_detached_model(&mut process, &inputs));
+        let duration_ms = case_started.elapsed().as_millis() as u64;
+
+        let options = DiffOptions {
+            outputs: outputs.clone(),
+            tolerances: case.tolerances,
+            default_tolerance: case.default_tolerance,
+        };
+        let (status, diffs, error, outputs) = match result {
+            Err(e) => (TestStatus::Error, Vec::new(), Some(e), None),
+            Ok(actual) => {
+                let diffs = case
+                    .expected
+                    .as_ref()
+                    .map(|expected| output_diff::diff_outputs(expected, &actual, &options))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|diff| diff.kind != DiffKind::Added)
+                    .collect::<Vec<_>>();
+                let status = if diffs.is_empty() { TestStatus::Passed } else { TestStatus::Failed };
+                (status, diffs, None, Some(actual))
+            }
+        };
+        results.push(TestCaseResult { name, source, status, diffs, error, outputs, duration_ms });
+    }
+    drop(process);
+
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    let (passed, failed, errors) = (count(TestStatus::Passed), count(TestStatus::Failed), count(TestStatus::Error));
+    let duration_ms = started.elapsed().as_millis() as u64;
+    let mut report = TestRunReport {
+        project_name: project_name.to_string(),
+        model_version: project.model_version,
+        passed,
+        failed,
+        errors,
+        warmup_ms,
+        duration_ms,
+        junit_xml: junit_xml(project_name, &results, failed, errors, duration_ms),
+        cases: results,
+        sections: Vec::new(),
+    };
+    report.sections = report_sections(&report);
+    Ok(report)
+}
+
+/// `chanterelle test <project-folder> [--junit <file>]`: runs a project's golden tests
+/// without opening a window, for CI. Returns the process exit code.
+pub fn run_cli(args: &[String]) -> i32 {
+    let mut project_path = None;
+    let mut junit_path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--junit" => junit_path = args.next().cloned(),
+            other => project_path = Some(other.to_string()),
+        }
+    }
+    let Some(project_path) = project_path else {
+        eprintln!("Usage: chanterelle test <project-folder> [--junit <file>]");
+        return 2;
+    };
+
+    // Resolves `.` and `..`, which have no folder name of their own
+    let project_path = match Path::new(&project_path).canonicalize() {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("Invalid project folder {}: {}", project_path, e);
+            return 2;
+        }
+    };
+    let (Some(projects_dir), Some(project_name)) = (
+        project_path.parent().map(|p| p.to_string_lossy().to_string()),
+        project_path.file_name().map(|n| n.to_string_lossy().to_string()),
+    ) else {
+        eprintln!("Invalid project folder: {}", project_path.display());
+        return 2;
+    };
+
+    match run_tests(&projects_dir, &project_name) {
+        Ok(report) => {
+            for case in &report.cases {
+                println!("[{:?}] {} ({})", case.status, case.name, case.source);
+                for diff in &case.diffs {
+                    println!("    {}: expected {}, got {}", diff.path, cell(diff.baseline.as_ref()), cell(diff.current.as_ref()));
+                }
+                if let Some(error) = &case.error {
+                    println!("    {}", error);
+                }
+            }
+            println!("{} passed, {} failed, {} errors", report.passed, report.failed, report.errors);
+            if let Some(path) = junit_path {
+                if let Err(e) = fs::write(&path, &report.junit_xml) {
+                    eprintln!("Failed to write JUnit report to {}: {}", path, e);
+                    return 1;
+                }
+            }
+            if report.all_passed() { 0 } else { 1 }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        }
+    }
+}
+
+// --- Commands ---
+
+/// Runs the project's golden tests; writes the JUnit XML to `junit_path` when given.
+/// The tests get their own Python process, so the loaded model is left untouched.
+#[tauri::command]
+pub async fn run_project_tests(
+    project_name: String,
+    junit_path: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> Result<TestRunReport, ChanterelleError> {
+    let projects_dir = {
+        let settings = state.settings.lock().unwrap();
+        settings.projects_directory.clone()
+    };
+    let report = run_tests(&projects_dir, &project_name)?;
+    if let Some(path) = junit_path {
+        fs::write(&path, &report.junit_xml)
+            .map_err(|e| ChanterelleError::io(format!("Failed to write JUnit report to {}: {}", path, e)))?;
+    }
+    println!(
+        "Tests for {}: {} passed, {} failed, {} errors",
+        project_name, report.passed, report.failed, report.errors
+    );
+    Ok(report)
+}
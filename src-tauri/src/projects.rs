@@ -1,10 +1,16 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use crate::error::ChanterelleError;
+use crate::settings::ProjectDiscovery;
 use crate::types::ProjectMeta;
 use crate::state::AppState;
+use crate::storage;
 use std::collections::HashMap;
 
+/// Files that make a folder a project
+const PROJECT_FILES: [&str; 3] = ["model_meta.json", "interactive.json", "analytics.json"];
+
 #[derive(Serialize, Deserialize)]
 pub struct ModelDetails {
     pub model: serde_json::Value,
@@ -19,7 +25,7 @@ pub struct AnalyticsDetails {
 }
 
 /// Recursively resolves $href references in JSON
-pub fn resolve_json_refs(value: &mut serde_json::Value, base_dir: &Path) -> Result<(), String> {
+pub fn resolve_json_refs(value: &mut serde_json::Value, base_dir: &Path) -> Result<(), ChanterelleError> {
     match value {
         serde_json::Value::Object(map) => {
             if let Some(href_value) = map.get("$href") {
@@ -27,14 +33,14 @@ pub fn resolve_json_refs(value: &mut serde_json::Value, base_dir: &Path) -> Resu
                     let referenced_file = base_dir.join(href_str);
                     if referenced_file.exists() {
                         let content = fs::read_to_string(&referenced_file)
-                            .map_err(|e| format!("Failed to read referenced file '{}': {}", referenced_file.display(), e))?;
+                            .map_err(|e| ChanterelleError::io(format!("Failed to read referenced file '{}': {}", referenced_file.display(), e)))?;
                         let mut referenced_json: serde_json::Value = serde_json::from_str(&content)
-                            .map_err(|e| format!("Failed to parse JSON in referenced file '{}': {}", referenced_file.display(), e))?;
+                            .map_err(|e| ChanterelleError::config(format!("Failed to parse JSON in referenced file '{}': {}", referenced_file.display(), e)))?;
                         resolve_json_refs(&mut referenced_json, base_dir)?;
                         *value = referenced_json;
                         return Ok(());
                     } else {
-                        return Err(format!("Referenced file not found: '{}' (referenced as '{}')", referenced_file.display(), href_str));
+                        return Err(ChanterelleError::config(format!("Referenced file not found: '{}' (referenced as '{}')", referenced_file.display(), href_str)));
                     }
                 }
             }
@@ -52,154 +58,203 @@ pub fn resolve_json_refs(value: &mut serde_json::Value, base_dir: &Path) -> Resu
     Ok(())
 }
 
-async fn internal_list_projects(projects_dir: &str) -> Result<Vec<ProjectMeta>, String> {
+/// Turns `ignore` patterns into regexes matching whole folder names.
+fn ignore_patterns(discovery: &ProjectDiscovery) -> Vec<regex::Regex> {
+    discovery
+        .ignore
+        .iter()
+        .filter_map(|pattern| {
+            let pattern = format!("^{}$", regex::escape(pattern).replace(r"\*", ".*"));
+            regex::Regex::new(&pattern).ok()
+        })
+        .collect()
+}
+
+/// Folders below `root` that hold a project file, with their path relative to
+/// `root` joined by `/` as the project id. Project folders are not searched further.
+fn discover_project_dirs(root: &Path, discovery: &ProjectDiscovery) -> Result<Vec<(PathBuf, String)>, ChanterelleError> {
+    let ignore = ignore_patterns(discovery);
+    let mut found = Vec::new();
+    let mut pending = vec![(root.to_path_buf(), String::new(), 0)];
+    while let Some((dir, id, depth)) = pending.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            // Only an unreadable projects directory is an error; unreadable subfolders are skipped
+            Err(e) if depth == 0 => {
+                return Err(ChanterelleError::io(format!("Failed to read projects directory '{}': {}", dir.display(), e)));
+            }
+            Err(e) => {
+                println!("Skipping '{}': {}", dir.display(), e);
+                continue;
+            }
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()).map(|n| n.to_string()) else {
+                continue;
+            };
+            if !path.is_dir() || ignore.iter().any(|pattern| pattern.is_match(&name)) || path.join("pyvenv.cfg").exists() {
+                continue;
+            }
+            let child_id = if id.is_empty() { name } else { format!("{}/{}", id, name) };
+            if PROJECT_FILES.iter().any(|file| path.join(file).exists()) {
+                found.push((path, child_id));
+            } else if depth + 1 < discovery.max_depth {
+                pending.push((path, child_id, depth + 1));
+            }
+        }
+    }
+    found.sort_by(|a, b| a.1.cmp(&b.1));
+    Ok(found)
+}
+
+async fn internal_list_projects(projects_dir: &str, discovery: &ProjectDiscovery) -> Result<Vec<ProjectMeta>, ChanterelleError> {
     let projects_path = Path::new(projects_dir);
 
     if !projects_path.exists() {
-        return Err(format!("Projects directory does not exist: {}", projects_dir));
+        return Err(ChanterelleError::config(format!("Projects directory does not exist: {}", projects_dir)));
     }
 
     let mut projects = Vec::new();
 
-    for entry in fs::read_dir(projects_path).map_err(|e| format!("Failed to read projects directory '{}': {}", projects_dir, e))? {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry in '{}': {}", projects_dir, e))?;
-        let path = entry.path();
-
-        if path.is_dir() {
-            let project_id = path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("")
-                .to_string();
-
-            let model_meta_path = path.join("model_meta.json");
-            let analytics_meta_path = path.join("analytics.json");
-            let interactive_meta_path = path.join("interactive.json");
-            
-            // Check for modeling project
-            if model_meta_path.exists() {
-                 match fs::read_to_string(&model_meta_path) {
-                    Ok(metadata_content) => {
-                        match serde_json::from_str::<serde_json::Value>(&metadata_content) {
-                            Ok(metadata) => {
-                                projects.push(ProjectMeta {
-                                    project_name: project_id.clone(),
-                                    project_title: metadata["model_name"].as_str().unwrap_or(&project_id).to_string(),
-                                    description: metadata["description"].as_str().unwrap_or("").to_string(),
-                                    description_short: metadata["description_short"].as_str().unwrap_or("").to_string(),
-                                    tags: metadata.get("tags").and_then(|t| t.as_object()).map(|t| {
-                                        t.iter()
-                                            .map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string()))
-                                            .collect::<HashMap<_, _>>()
-                                    }),
-                                    kind: "model".to_string(),
-                                    allow_feedback: metadata.get("allow_feedback").and_then(|v| v.as_bool()),
-                                });
-                            }
-                            Err(e) => {
-                                projects.push(ProjectMeta {
-                                    project_name: project_id.clone(),
-                                    project_title: format!("Error in {}", project_id),
-                                    description: format!("Failed to parse JSON: {}", e),
-                                    description_short: "Error".to_string(),
-                                    tags: None,
-                                    kind: "model".to_string(),
-                                    allow_feedback: None,
-                                });
-                            }
+    for (path, project_id) in discover_project_dirs(projects_path, discovery)? {
+        // Folder path above the project, e.g. "team/domain" for "team/domain/project"
+        let category = project_id.rsplit_once('/').map(|(category, _)| category.to_string());
+        let model_meta_path = path.join("model_meta.json");
+        let analytics_meta_path = path.join("analytics.json");
+        let interactive_meta_path = path.join("interactive.json");
+        
+        // Check for modeling project
+        if model_meta_path.exists() {
+             match fs::read_to_string(&model_meta_path) {
+                Ok(metadata_content) => {
+                    match serde_json::from_str::<serde_json::Value>(&metadata_content) {
+                        Ok(metadata) => {
+                            projects.push(ProjectMeta {
+                                project_name: project_id.clone(),
+                                category: category.clone(),
+                                project_title: metadata["model_name"].as_str().unwrap_or(&project_id).to_string(),
+                                description: metadata["description"].as_str().unwrap_or("").to_string(),
+                                description_short: metadata["description_short"].as_str().unwrap_or("").to_string(),
+                                tags: metadata.get("tags").and_then(|t| t.as_object()).map(|t| {
+                                    t.iter()
+                                        .map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string()))
+                                        .collect::<HashMap<_, _>>()
+                                }),
+                                kind: "model".to_string(),
+                                allow_feedback: metadata.get("allow_feedback").and_then(|v| v.as_bool()),
+                            });
+                        }
+                        Err(e) => {
+                            projects.push(ProjectMeta {
+                                project_name: project_id.clone(),
+                                category: category.clone(),
+                                project_title: format!("Error in {}", project_id),
+                                description: format!("Failed to parse JSON: {}", e),
+                                description_short: "Error".to_string(),
+                                tags: None,
+                                kind: "model".to_string(),
+                                allow_feedback: None,
+                            });
                         }
                     }
-                    Err(_) => {}
                 }
-            } else if interactive_meta_path.exists() {
-                // Check for interactive project
-                match fs::read_to_string(&interactive_meta_path) {
-                    Ok(metadata_content) => {
-                        match serde_json::from_str::<serde_json::Value>(&metadata_content) {
-                            Ok(metadata) => {
-                                projects.push(ProjectMeta {
-                                    project_name: project_id.clone(),
-                                    project_title: metadata["interactive_name"].as_str().unwrap_or(&project_id).to_string(),
-                                    description: metadata["description"].as_str().unwrap_or("Interactive Project").to_string(),
-                                    description_short: metadata["description_short"].as_str().unwrap_or("Interactive Project").to_string(),
-                                    tags: metadata.get("tags").and_then(|t| t.as_object()).map(|t| {
-                                        t.iter()
-                                            .map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string()))
-                                            .collect::<HashMap<_, _>>()
-                                    }),
-                                    kind: "interactive".to_string(),
-                                    allow_feedback: metadata.get("allow_feedback").and_then(|v| v.as_bool()),
-                                });
-                            }
-                            Err(e) => {
-                                projects.push(ProjectMeta {
-                                    project_name: project_id.clone(),
-                                    project_title: format!("Error in {}", project_id),
-                                    description: format!("Failed to parse JSON: {}", e),
-                                    description_short: "Error".to_string(),
-                                    tags: None,
-                                    kind: "interactive".to_string(),
-                                    allow_feedback: None,
-                                });
-                            }
+                Err(_) => {}
+            }
+        } else if interactive_meta_path.exists() {
+            // Check for interactive project
+            match fs::read_to_string(&interactive_meta_path) {
+                Ok(metadata_content) => {
+                    match serde_json::from_str::<serde_json::Value>(&metadata_content) {
+                        Ok(metadata) => {
+                            projects.push(ProjectMeta {
+                                project_name: project_id.clone(),
+                                category: category.clone(),
+                                project_title: metadata["interactive_name"].as_str().unwrap_or(&project_id).to_string(),
+                                description: metadata["description"].as_str().unwrap_or("Interactive Project").to_string(),
+                                description_short: metadata["description_short"].as_str().unwrap_or("Interactive Project").to_string(),
+                                tags: metadata.get("tags").and_then(|t| t.as_object()).map(|t| {
+                                    t.iter()
+                                        .map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string()))
+                                        .collect::<HashMap<_, _>>()
+                                }),
+                                kind: "interactive".to_string(),
+                                allow_feedback: metadata.get("allow_feedback").and_then(|v| v.as_bool()),
+                            });
+                        }
+                        Err(e) => {
+                            projects.push(ProjectMeta {
+                                project_name: project_id.clone(),
+                                category: category.clone(),
+                                project_title: format!("Error in {}", project_id),
+                                description: format!("Failed to parse JSON: {}", e),
+                                description_short: "Error".to_string(),
+                                tags: None,
+                                kind: "interactive".to_string(),
+                                allow_feedback: None,
+                            });
                         }
                     }
-                    Err(e) => {
-                        projects.push(ProjectMeta {
-                            project_name: project_id.clone(),
-                            project_title: format!("Error in {}", project_id),
-                            description: format!("Failed to read file: {}", e),
-                            description_short: "Error".to_string(),
-                            tags: None,
-                            kind: "interactive".to_string(),
-                            allow_feedback: None,
-                        });
-                    }
                 }
-            } else if analytics_meta_path.exists() {
-                // Check for analytics project
-                match fs::read_to_string(&analytics_meta_path) {
-                    Ok(metadata_content) => {
-                        match serde_json::from_str::<serde_json::Value>(&metadata_content) {
-                            Ok(metadata) => {
-                                projects.push(ProjectMeta {
-                                    project_name: project_id.clone(),
-                                    project_title: metadata["analysis_name"].as_str().unwrap_or(&project_id).to_string(),
-                                    description: metadata["description"].as_str().unwrap_or("Analytics Project").to_string(),
-                                    description_short: metadata["description_short"].as_str().unwrap_or("Analytics Project").to_string(),
-                                    tags: metadata.get("tags").and_then(|t| t.as_object()).map(|t| {
-                                        t.iter()
-                                            .map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string()))
-                                            .collect::<HashMap<_, _>>()
-                                    }),
-                                    kind: "analytics".to_string(),
-                                    allow_feedback: None,
-                                });
-                            }
-                            Err(e) => {
-                                projects.push(ProjectMeta {
-                                    project_name: project_id.clone(),
-                                    project_title: format!("Error in {}", project_id),
-                                    description: format!("Failed to parse JSON: {}", e),
-                                    description_short: "Error".to_string(),
-                                    tags: None,
-                                    kind: "analytics".to_string(),
-                                    allow_feedback: None,
-                                });
-                            }
+                Err(e) => {
+                    projects.push(ProjectMeta {
+                        project_name: project_id.clone(),
+                        category: category.clone(),
+                        project_title: format!("Error in {}", project_id),
+                        description: format!("Failed to read file: {}", e),
+                        description_short: "Error".to_string(),
+                        tags: None,
+                        kind: "interactive".to_string(),
+                        allow_feedback: None,
+                    });
+                }
+            }
+        } else if analytics_meta_path.exists() {
+            // Check for analytics project
+            match fs::read_to_string(&analytics_meta_path) {
+                Ok(metadata_content) => {
+                    match serde_json::from_str::<serde_json::Value>(&metadata_content) {
+                        Ok(metadata) => {
+                            projects.push(ProjectMeta {
+                                project_name: project_id.clone(),
+                                category: category.clone(),
+                                project_title: metadata["analysis_name"].as_str().unwrap_or(&project_id).to_string(),
+                                description: metadata["description"].as_str().unwrap_or("Analytics Project").to_string(),
+                                description_short: metadata["description_short"].as_str().unwrap_or("Analytics Project").to_string(),
+                                tags: metadata.get("tags").and_then(|t| t.as_object()).map(|t| {
+                                    t.iter()
+                                        .map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string()))
+                                        .collect::<HashMap<_, _>>()
+                                }),
+                                kind: "analytics".to_string(),
+                                allow_feedback: None,
+                            });
+                        }
+                        Err(e) => {
+                            projects.push(ProjectMeta {
+                                project_name: project_id.clone(),
+                                category: category.clone(),
+                                project_title: format!("Error in {}", project_id),
+                                description: format!("Failed to parse JSON: {}", e),
+                                description_short: "Error".to_string(),
+                                tags: None,
+                                kind: "analytics".to_string(),
+                                allow_feedback: None,
+                            });
                         }
                     }
-                    Err(e) => {
-                        projects.push(ProjectMeta {
-                            project_name: project_id.clone(),
-                            project_title: format!("Error in {}", project_id),
-                            description: format!("Failed to read file: {}", e),
-                            description_short: "Error".to_string(),
-                            tags: None,
-                            kind: "analytics".to_string(),
-                            allow_feedback: None,
-                        });
-                    }
+                }
+                Err(e) => {
+                    projects.push(ProjectMeta {
+                        project_name: project_id.clone(),
+                        category: category.clone(),
+                        project_title: format!("Error in {}", project_id),
+                        description: format!("Failed to read file: {}", e),
+                        description_short: "Error".to_string(),
+                        tags: None,
+                        kind: "analytics".to_string(),
+                        allow_feedback: None,
+                    });
                 }
             }
         }
@@ -210,27 +265,27 @@ async fn internal_list_projects(projects_dir: &str) -> Result<Vec<ProjectMeta>,
 pub async fn internal_get_model_details(
     projects_dir: &str,
     project_name: &str,
-) -> Result<ModelDetails, String> {
+) -> Result<ModelDetails, ChanterelleError> {
     let model_dir = Path::new(projects_dir).join(project_name);
 
     if !model_dir.exists() {
-        return Err(format!("Model directory not found: {}", model_dir.display()));
+        return Err(ChanterelleError::config(format!("Model directory not found: {}", model_dir.display())));
     }
 
     let metadata_path = model_dir.join("model_meta.json");
     let mut metadata = if metadata_path.exists() {
-        let content = fs::read_to_string(&metadata_path).map_err(|e| format!("Failed to read file '{}': {}", metadata_path.display(), e))?;
-        serde_json::from_str(&content).map_err(|e| format!("Failed to parse JSON in file '{}': {}", metadata_path.display(), e))?
+        let content = fs::read_to_string(&metadata_path).map_err(|e| ChanterelleError::io(format!("Failed to read file '{}': {}", metadata_path.display(), e)))?;
+        serde_json::from_str(&content).map_err(|e| ChanterelleError::config(format!("Failed to parse JSON in file '{}': {}", metadata_path.display(), e)))?
     } else {
-        return Err(format!("Required file not found: {}", metadata_path.display()));
+        return Err(ChanterelleError::config(format!("Required file not found: {}", metadata_path.display())));
     };
 
     resolve_json_refs(&mut metadata, &model_dir)?;
 
     let findings_path = model_dir.join("model_findings.json");
     let mut findings = if findings_path.exists() {
-        let content = fs::read_to_string(&findings_path).map_err(|e| format!("Failed to read file '{}': {}", findings_path.display(), e))?;
-        Some(serde_json::from_str(&content).map_err(|e| format!("Failed to parse JSON in file '{}': {}", findings_path.display(), e))?)
+        let content = fs::read_to_string(&findings_path).map_err(|e| ChanterelleError::io(format!("Failed to read file '{}': {}", findings_path.display(), e)))?;
+        Some(serde_json::from_str(&content).map_err(|e| ChanterelleError::config(format!("Failed to parse JSON in file '{}': {}", findings_path.display(), e)))?)
     } else {
         None
     };
@@ -249,19 +304,19 @@ pub async fn internal_get_model_details(
 pub async fn internal_get_analytics_details(
     projects_dir: &str,
     project_name: &str,
-) -> Result<AnalyticsDetails, String> {
+) -> Result<AnalyticsDetails, ChanterelleError> {
     let project_dir = Path::new(projects_dir).join(project_name);
     
     if !project_dir.exists() {
-        return Err(format!("Project directory not found: {}", project_dir.display()));
+        return Err(ChanterelleError::config(format!("Project directory not found: {}", project_dir.display())));
     }
 
     let meta_path = project_dir.join("analytics.json");
      let mut insights = if meta_path.exists() {
-        let content = fs::read_to_string(&meta_path).map_err(|e| format!("Failed to read file '{}': {}", meta_path.display(), e))?;
-        serde_json::from_str(&content).map_err(|e| format!("Failed to parse JSON in file '{}': {}", meta_path.display(), e))?
+        let content = fs::read_to_string(&meta_path).map_err(|e| ChanterelleError::io(format!("Failed to read file '{}': {}", meta_path.display(), e)))?;
+        serde_json::from_str(&content).map_err(|e| ChanterelleError::config(format!("Failed to parse JSON in file '{}': {}", meta_path.display(), e)))?
     } else {
-        return Err(format!("Required file not found: {}", meta_path.display()));
+        return Err(ChanterelleError::config(format!("Required file not found: {}", meta_path.display())));
     };
 
     resolve_json_refs(&mut insights, &project_dir)?;
@@ -278,7 +333,7 @@ pub async fn internal_get_analytics_details(
 #[tauri::command]
 pub async fn list_projects(
     state: tauri::State<'_, AppState>,
-) -> Result<Vec<ProjectMeta>, String> {
+) -> Result<Vec<ProjectMeta>, ChanterelleError> {
     {
         let mut guard = state.python_process.lock().unwrap();
         if let Some(existing_process) = guard.take() {
@@ -288,23 +343,25 @@ pub async fn list_projects(
         }
     }
     
-    let projects_dir = {
+    let (projects_dir, discovery) = {
         let settings = state.settings.lock().unwrap();
-        settings.projects_directory.clone()
+        (settings.projects_directory.clone(), settings.project_discovery.clone())
     };
 
     if projects_dir.is_empty() {
-        return Err("No projects directory set. Please configure it in settings.".to_string());
+        return Err(ChanterelleError::config("No projects directory set. Please configure it in settings."));
     }
 
-    internal_list_projects(&projects_dir).await
+    let projects = internal_list_projects(&projects_dir, &discovery).await?;
+    storage::migrate_legacy_dirs(&projects_dir, projects.iter().map(|p| p.project_name.as_str()));
+    Ok(projects)
 }
 
 #[tauri::command]
 pub async fn get_model_details(
     project_name: String,
     state: tauri::State<'_, AppState>,
-) -> Result<ModelDetails, String> {
+) -> Result<ModelDetails, ChanterelleError> {
     let projects_dir = {
         let settings = state.settings.lock().unwrap();
         settings.projects_directory.clone()
@@ -317,7 +374,7 @@ pub async fn get_model_details(
 pub async fn get_analytics_details(
     project_name: String,
     state: tauri::State<'_, AppState>,
-) -> Result<AnalyticsDetails, String> {
+) -> Result<AnalyticsDetails, ChanterelleError> {
     let projects_dir = {
         let settings = state.settings.lock().unwrap();
         settings.projects_directory.clone()
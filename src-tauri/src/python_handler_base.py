@@ -14,6 +14,7 @@ from typing import Any, Dict, Optional, TextIO
 # Protocol IO isolation: keep a dedicated handle to original stdout for JSON messages
 _PROTOCOL_OUT: Optional[TextIO] = None
 _IO_ISOLATED: bool = False
+_PARTIAL_KEY = "_chanterelle_partial"
 
 
 def _setup_io_isolation() -> None:
@@ -55,6 +56,41 @@ def _send_protocol_json(obj: Dict[str, Any]) -> None:
     out.flush()
 
 
+def _install_host_services() -> None:
+    """Connect chanterelle_host to the protocol pipes so handlers can call into the app."""
+    try:
+        import chanterelle_host
+    except ImportError:
+        return
+    chanterelle_host._install(_send_protocol_json, sys.stdin.readline)
+
+
+def _normalize_partial_payload(payload: Any) -> Dict[str, Any]:
+    """Shape a streamed yield into a partial event with append/replace semantics.
+
+    A yield may be a list of sections, a single section, or a dict carrying
+    ``outputs`` plus optional ``append``/``event_type`` overrides.
+    """
+    if isinstance(payload, dict) and payload.get("type") != "section":
+        event = dict(payload)
+        outputs = event.get("outputs") or []
+    elif isinstance(payload, list):
+        event = {}
+        outputs = payload
+    else:
+        event = {}
+        outputs = [payload] if payload else []
+
+    if isinstance(outputs, dict):
+        outputs = [outputs]
+
+    event["outputs"] = outputs
+    event.setdefault("event_type", "partial")
+    event.setdefault("append", True)
+    event[_PARTIAL_KEY] = True
+    return event
+
+
 def load_user_handler_module(handler_path: str):
     """Load the user's handler_io.py module dynamically."""
     spec = importlib.util.spec_from_file_location("handler_io", handler_path)
@@ -66,13 +102,14 @@ def load_user_handler_module(handler_path: str):
     return module
 
 
-def format_detailed_error(exception: Exception, context: str = "") -> dict:
+def format_detailed_error(exception: Exception, context: str = "", stage: Optional[str] = None) -> dict:
     """
     Format a detailed error message with line numbers and stack trace.
     
     Args:
         exception: The caught exception
         context: Additional context about where the error occurred
+        stage: Handler function that raised, e.g. "predict_fn"
         
     Returns:
         Dictionary with detailed error information
@@ -96,6 +133,7 @@ def format_detailed_error(exception: Exception, context: str = "") -> dict:
             if 'python_handler_base.py' not in frame.filename:
                 line_info.append({
                     "file": os.path.basename(frame.filename),
+                    "path": os.path.abspath(frame.filename) if os.path.isfile(frame.filename) else None,
                     "line": frame.lineno,
                     "function": frame.name,
                     "code": frame.line.strip() if frame.line else "N/A"
@@ -109,6 +147,7 @@ def format_detailed_error(exception: Exception, context: str = "") -> dict:
         "error": error_msg,
         "error_type": error_type,
         "context": context,
+        "stage": stage,
         "traceback": line_info
     }
     
@@ -172,7 +211,7 @@ class MLModelHandler:
             
             return {"status": "ready", "message": "Model loaded successfully"}
         except Exception as e:
-            detailed_error = format_detailed_error(e, "model initialization")
+            detailed_error = format_detailed_error(e, "model initialization", "model_fn")
             return {"status": "error", **detailed_error}
 
     def health_check(self):
@@ -203,7 +242,7 @@ class MLModelHandler:
                 try:
                     processed_input = self._call_user_function_with_optional_resources('input_fn', original_input)
                 except Exception as e:
-                    return format_detailed_error(e, "input processing (input_fn)")
+                    return format_detailed_error(e, "input processing (input_fn)", "input_fn")
             else:
                 processed_input = original_input
             
@@ -212,18 +251,25 @@ class MLModelHandler:
                 try:
                     prediction = self._call_user_function_with_optional_resources('predict_fn', processed_input, self.model)
                 except Exception as e:
-                    return format_detailed_error(e, "model prediction (predict_fn)")
+                    return format_detailed_error(e, "model prediction (predict_fn)", "predict_fn")
             else:
                 return {"error": "Handler must implement predict_fn()"}
+
+            # A generator predict_fn streams each stage; every yield goes through output_fn
+            if inspect.isgenerator(prediction):
+                return self._stream_partials(prediction, "model prediction (predict_fn)", "predict_fn", original_input)
             
             # Step 3: Transform output (user's output_fn)
             if hasattr(self.handler_module, 'output_fn'):
                 try:
                     result = self._call_user_function_with_optional_resources('output_fn', prediction, original_input)
                 except Exception as e:
-                    return format_detailed_error(e, "output processing (output_fn)")
+                    return format_detailed_error(e, "output processing (output_fn)", "output_fn")
             else:
                 result = prediction
+
+            if inspect.isgenerator(result):
+                return self._stream_partials(result, "output processing (output_fn)", "output_fn")
             
             return result
             
@@ -231,6 +277,39 @@ class MLModelHandler:
             # Catch-all for any other errors (like JSON parsing)
             return format_detailed_error(e, "request handling")
 
+    def _stream_partials(self, stream, context: str, stage: str, original_input=None):
+        """
+        Drain a generator returned by predict_fn or output_fn, sending each yield
+        as a partial event before the final response.
+
+        When original_input is given the yields come from predict_fn and are
+        formatted through output_fn (if implemented) before being sent.
+
+        Returns:
+            The merged list of sections, or a detailed error dict
+        """
+        merged = []
+        while True:
+            try:
+                partial = next(stream)
+            except StopIteration:
+                break
+            except Exception as e:
+                return format_detailed_error(e, context, stage)
+
+            if original_input is not None and hasattr(self.handler_module, 'output_fn'):
+                try:
+                    partial = self._call_user_function_with_optional_resources('output_fn', partial, original_input)
+                except Exception as e:
+                    stream.close()
+                    return format_detailed_error(e, "output processing (output_fn)", "output_fn")
+
+            event = _normalize_partial_payload(partial)
+            merged = merged + list(event["outputs"]) if event["append"] else list(event["outputs"])
+            _send_protocol_json(event)
+
+        return merged
+
     def handle_feedback(self, feedback_data):
         """Process feedback using feedback_fn."""
         if not self.is_initialized:
@@ -245,7 +324,7 @@ class MLModelHandler:
                 self._call_user_function_with_optional_resources('feedback_fn', feedback_data)
                 return {"status": "success", "message": "Feedback processed by handler"}
             except Exception as e:
-                return format_detailed_error(e, "feedback processing (feedback_fn)")
+                return format_detailed_error(e, "feedback processing (feedback_fn)", "feedback_fn")
         else:
             # It is not an error if the user didn't implement it
             return {"status": "ignored", "message": "No feedback_fn implemented"}
@@ -263,6 +342,7 @@ class MLModelHandler:
         else:
             # Announce readiness once for the Rust-side handshake
             _send_protocol_json({"status": "ready", "message": "Model loaded successfully"})
+            _install_host_services()
 
         print("Model ready. Enter JSON requests (one per line):", file=sys.stderr)
 
@@ -321,12 +401,12 @@ if __name__ == "__main__":
         handler.run_communication_loop()
     except Exception as e:
         # Format detailed error for handler initialization failures
-        detailed_error = format_detailed_error(e, "handler initialization")
+        detailed_error = format_detailed_error(e, "handler initialization", "import")
         error_message = f"Error: Failed to initialize handler: {detailed_error['summary']}"
         
         # Print summary to stderr for immediate visibility
         # print(error_message, file=sys.stderr)
-        _send_protocol_json({"status": "error", "error": error_message})
+        _send_protocol_json({"status": "error", **detailed_error, "summary": error_message})
 
         # Also print detailed error info to stderr for debugging
         print(f"Detailed error info: {json.dumps(detailed_error, indent=2)}", file=sys.stderr)
@@ -15,8 +15,24 @@ _PROTOCOL_OUT: Optional[TextIO] = None
 _IO_ISOLATED: bool = False
 _TURN_END_KEY = "_chanterelle_turn_end"
 _RESPONSE_SEQ = 0
+_INTERACTION_SEQ = 0
 _CANCELED_REQUEST_IDS = set()
 _CANCEL_ALL = False
+_DEFAULT_SESSION_ID = "default"
+
+
+class SessionState(dict):
+    """Per-session state handed to handlers that declare a ``session`` parameter.
+
+    Behaves like a plain dict; ``session_id`` identifies the conversation it belongs to.
+    """
+
+    def __init__(self, session_id: str):
+        super().__init__()
+        self.session_id = session_id
+
+
+_SESSIONS: Dict[str, SessionState] = {}
 
 
 def _setup_io_isolation() -> None:
@@ -58,18 +74,64 @@ def _send_protocol_json(obj: Dict[str, Any]) -> None:
     out.flush()
 
 
+def _install_host_services() -> None:
+    """Connect chanterelle_host to the protocol pipes so handlers can call into the app."""
+    try:
+        import chanterelle_host
+    except ImportError:
+        return
+    chanterelle_host._install(_send_protocol_json, sys.stdin.readline)
+
+
 def _next_response_id() -> str:
     global _RESPONSE_SEQ
     _RESPONSE_SEQ += 1
     return f"r{_RESPONSE_SEQ}"
 
 
+def _next_interaction_id() -> str:
+    global _INTERACTION_SEQ
+    _INTERACTION_SEQ += 1
+    return f"i{_INTERACTION_SEQ}"
+
+
+class _Cancelled:
+    """Sentinel for a mid-turn request the user (or Rust) cancelled."""
+
+
+def _await_interaction_response(interaction_id: str) -> Any:
+    """Block on stdin until Rust answers the pending mid-turn request.
+
+    Returns the user's response, or ``_Cancelled`` when the turn was stopped.
+    """
+    while True:
+        line = sys.stdin.readline()
+        if not line:
+            return _Cancelled
+        line = line.strip()
+        if not line:
+            continue
+        try:
+            message = json.loads(line)
+        except json.JSONDecodeError:
+            continue
+        if not isinstance(message, dict):
+            continue
+        if message.get("command") in {"cancel", "stop"}:
+            return _Cancelled
+        if message.get("command") == "respond" and message.get("interaction_id") == interaction_id:
+            if message.get("cancelled") is True:
+                return _Cancelled
+            return message.get("response")
+
+
 def _normalize_event_payload(
     payload: Any,
     response_id: str,
     request_id: Optional[str],
     default_event_type: str,
     default_append: bool,
+    session_id: Optional[str] = None,
 ) -> Dict[str, Any]:
     if isinstance(payload, dict):
         event = dict(payload)
@@ -79,6 +141,8 @@ def _normalize_event_payload(
     event["response_id"] = event.get("response_id") or response_id
     if request_id is not None and "request_id" not in event:
         event["request_id"] = request_id
+    if session_id is not None and "session_id" not in event:
+        event["session_id"] = session_id
 
     if "event_type" not in event:
         if event.get("next_inputs") is not None:
@@ -105,13 +169,14 @@ def load_user_handler_module(handler_path: str):
     return module
 
 
-def format_detailed_error(exception: Exception, context: str = "") -> dict:
+def format_detailed_error(exception: Exception, context: str = "", stage: Optional[str] = None) -> dict:
     """
     Format a detailed error message with line numbers and stack trace.
     
     Args:
         exception: The caught exception
         context: Additional context about where the error occurred
+        stage: Handler function that raised, e.g. "predict_fn"
         
     Returns:
         Dictionary with detailed error information
@@ -135,6 +200,7 @@ def format_detailed_error(exception: Exception, context: str = "") -> dict:
             if 'python_interactive_handler_base.py' not in frame.filename:
                 line_info.append({
                     "file": os.path.basename(frame.filename),
+                    "path": os.path.abspath(frame.filename) if os.path.isfile(frame.filename) else None,
                     "line": frame.lineno,
                     "function": frame.name,
                     "code": frame.line.strip() if frame.line else "N/A"
@@ -148,6 +214,7 @@ def format_detailed_error(exception: Exception, context: str = "") -> dict:
         "error": error_msg,
         "error_type": error_type,
         "context": context,
+        "stage": stage,
         "traceback": line_info
     }
     
@@ -165,6 +232,24 @@ class InteractiveHandler:
     def __init__(self, handler_module_path: str):
         self.module = load_user_handler_module(handler_module_path)
 
+    @staticmethod
+    def _accepts(fn, name: str) -> bool:
+        import inspect
+        try:
+            return name in inspect.signature(fn).parameters
+        except (TypeError, ValueError):
+            return False
+
+    @staticmethod
+    def _session_state(session_id: str, reset: bool = False) -> SessionState:
+        if reset or session_id not in _SESSIONS:
+            _SESSIONS[session_id] = SessionState(session_id)
+        return _SESSIONS[session_id]
+
+    @staticmethod
+    def close_session(session_id: str) -> bool:
+        return _SESSIONS.pop(session_id, None) is not None
+
     @staticmethod
     def _extract_conversation_history(session_turns: list) -> list:
         """Convert UI-level session turns into simplified role/content pairs."""
@@ -216,30 +301,37 @@ class InteractiveHandler:
     def handle_message(self, data: Dict[str, Any]) -> Any:
         try:
             command = data.get("command")
+            session_id = str(data.get("session_id") or _DEFAULT_SESSION_ID)
             if command == "initialize":
+                # (Re)initializing a session always starts from a clean state object.
+                session = self._session_state(session_id, reset=True)
                 if hasattr(self.module, "initialize"):
+                    kwargs = {}
+                    if self._accepts(self.module.initialize, "session"):
+                        kwargs["session"] = session
                     # If session_turns are provided, build conversation_history
                     # and pass it if initialize() accepts the argument.
                     session_turns = data.get("session_turns")
                     if session_turns and isinstance(session_turns, list):
-                        import inspect
-                        sig = inspect.signature(self.module.initialize)
-                        if "conversation_history" in sig.parameters:
-                            history = self._extract_conversation_history(session_turns)
-                            return self.module.initialize(conversation_history=history)
-                    return self.module.initialize()
+                        if self._accepts(self.module.initialize, "conversation_history"):
+                            kwargs["conversation_history"] = self._extract_conversation_history(session_turns)
+                    return self.module.initialize(**kwargs)
                 else:
                     return {"status": "ready", "message": "Initialized (no initialize() method found)"}
             elif hasattr(self.module, "on_input"):
                 # Most user handlers expect just the input dict, not the protocol envelope.
                 # If the envelope includes an "inputs" field, pass that through.
                 payload = data.get("inputs") if isinstance(data, dict) else None
-                return self.module.on_input(payload if isinstance(payload, dict) else data)
+                inputs = payload if isinstance(payload, dict) else data
+                if self._accepts(self.module.on_input, "session"):
+                    return self.module.on_input(inputs, session=self._session_state(session_id))
+                return self.module.on_input(inputs)
             else:
                 return {"status": "error", "error": "Module does not implement on_input()"}
                     
         except Exception as e:
-            return format_detailed_error(e, f"handling command '{data.get('command', 'unknown')}'")
+            stage = "initialize" if data.get("command") == "initialize" else "on_input"
+            return format_detailed_error(e, f"handling command '{data.get('command', 'unknown')}'", stage)
 
 
 if __name__ == "__main__":
@@ -257,6 +349,7 @@ if __name__ == "__main__":
         # Verify module has required methods or at least input_fn if we want to fallback?
         # For now, just say we are alive.
         _send_protocol_json({"status": "ready", "handler": "interactive"})
+        _install_host_services()
 
         import types
 
@@ -288,6 +381,21 @@ if __name__ == "__main__":
                     })
                     continue
 
+                # Answer to a mid-turn request that already ended; nothing is waiting for it
+                if isinstance(data, dict) and data.get("command") == "respond":
+                    continue
+
+                # Drop the state of a conversation the user closed
+                if isinstance(data, dict) and data.get("command") == "close_session":
+                    closed_id = str(data.get("session_id") or _DEFAULT_SESSION_ID)
+                    _send_protocol_json({
+                        "status": "closed",
+                        "session_id": closed_id,
+                        "closed": InteractiveHandler.close_session(closed_id),
+                    })
+                    _send_protocol_json({_TURN_END_KEY: True})
+                    continue
+
                 # Back-compat: Rust may send plain inputs without an explicit command.
                 if isinstance(data, dict) and "command" not in data:
                     if len(data) == 0:
@@ -297,13 +405,39 @@ if __name__ == "__main__":
 
                 response_id = _next_response_id()
                 request_id = data.get("request_id") if isinstance(data, dict) else None
+                session_id = data.get("session_id") if isinstance(data, dict) else None
                 result = handler.handle_message(data if isinstance(data, dict) else {"command": "on_input", "inputs": data})
 
                 if isinstance(result, types.GeneratorType):
-                    for partial in result:
+                    # Values sent back into the generator answer its mid-turn requests:
+                    #   approved = yield {"request": {"type": "confirm", "message": "..."}}
+                    send_value = None
+                    while True:
+                        try:
+                            partial = result.send(send_value)
+                        except StopIteration:
+                            break
+                        send_value = None
+
                         canceled = _CANCEL_ALL or (
                             request_id is not None and str(request_id) in _CANCELED_REQUEST_IDS
                         )
+                        if isinstance(partial, dict) and partial.get("request") is not None and not canceled:
+                            interaction_id = _next_interaction_id()
+                            _send_protocol_json({
+                                "event_type": "request",
+                                "interaction_id": interaction_id,
+                                "request": partial["request"],
+                                "response_id": response_id,
+                                "request_id": request_id,
+                                "session_id": session_id,
+                            })
+                            answer = _await_interaction_response(interaction_id)
+                            if answer is not _Cancelled:
+                                send_value = answer
+                                continue
+                            canceled = True
+
                         if canceled:
                             try:
                                 result.close()
@@ -312,6 +446,7 @@ if __name__ == "__main__":
                             _send_protocol_json({
                                 "response_id": response_id,
                                 "request_id": request_id,
+                                "session_id": session_id,
                                 "event_type": "final",
                                 "append": False,
                                 "stopped": True,
@@ -324,6 +459,7 @@ if __name__ == "__main__":
                             request_id=request_id,
                             default_event_type="partial",
                             default_append=True,
+                            session_id=session_id,
                         )
                         _send_protocol_json(payload)
                         # Hand control back to UI as soon as next inputs are requested.
@@ -344,6 +480,7 @@ if __name__ == "__main__":
                         request_id=request_id,
                         default_event_type="final",
                         default_append=False,
+                        session_id=session_id,
                     )
                     _send_protocol_json(payload)
 
@@ -362,5 +499,5 @@ if __name__ == "__main__":
                 _send_protocol_json({_TURN_END_KEY: True})
 
     except Exception as e:
-        _send_protocol_json(format_detailed_error(e, "loading handler module"))
+        _send_protocol_json({"status": "error", **format_detailed_error(e, "loading handler module", "import")})
         sys.exit(1)
@@ -1,10 +1,11 @@
 use std::io::{BufRead, BufReader, Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 // use HashMap
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
-use tauri::Emitter;
+use std::sync::mpsc;
+use tauri::{Emitter, Manager};
 
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
@@ -63,9 +64,27 @@ impl Drop for PythonProcess {
     }
 }
 
-use crate::state::AppState;
+use crate::error::{ChanterelleError, HandlerError};
+use crate::feedback_outbox;
+use crate::host_services::{self, HostContext};
+use crate::sessions;
+use crate::storage;
+use crate::history;
+use crate::prediction_cache;
+use crate::state::{ActiveProject, AppState};
 use crate::types::{ModelMeta, PythonEnvironment};
 
+/// Marker set by the base handler on streamed partial events of a model request.
+const PARTIAL_KEY: &str = "_chanterelle_partial";
+
+/// How long the handler may take to load the model before the handshake gives up.
+const HANDSHAKE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+
+/// How long a mid-turn request waits for the user before it is cancelled.
+const INTERACTION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30 * 60);
+/// How often a waiting request checks that its window still exists.
+const INTERACTION_POLL: std::time::Duration = std::time::Duration::from_secs(1);
+
 // load_model flow:
 // spawn Python
 // wait for the single init JSON on stdout
@@ -76,7 +95,7 @@ pub async fn load_model(
     projects_dir: &str,
     project_name: &str,
     state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), ChanterelleError> {
     println!("Loading model for project: {}", project_name);
     
     // Clean up any existing Python process first
@@ -90,59 +109,59 @@ pub async fn load_model(
         state.python_pid.store(0, Ordering::SeqCst);
     }
     
+    let (python_process, mut active_project) = spawn_project_process(projects_dir, project_name, None)?;
+    // Walks the whole project folder, so detached processes go without it
+    active_project.artifacts_fingerprint =
+        Some(prediction_cache::artifacts_fingerprint(&Path::new(projects_dir).join(project_name)));
+
+    // Only store the process after successful handshake
+    state.python_pid.store(python_process.id(), Ordering::SeqCst);
+    *state.python_process.lock().unwrap() = Some(python_process);
+    *state.active_project.lock().unwrap() = Some(active_project);
+
+    // Handshake already ensured the process is running and ready; proceed to model readiness check
+
+    // Verify the model is actually loaded and ready
+    match check_model_ready(state.clone()).await {
+        Ok(true) => {
+            println!("Model health check passed for project: {}", project_name);
+            let model_dir = Path::new(projects_dir).join(project_name);
+            match feedback_outbox::deliver_pending(&state, project_name, &model_dir) {
+                Ok(report) if report.attempted > 0 => println!("Replayed pending feedback: {:?}", report),
+                Ok(_) => {}
+                Err(e) => println!("Failed to replay pending feedback: {}", e),
+            }
+            Ok(())
+        }
+        Ok(false) => Err(ChanterelleError::protocol("Model health check failed: model not ready")),
+        Err(e) => Err(e.context("Model health check failed")),
+    }
+}
+
+/// Spawns the project's handler and waits for its ready handshake. The caller owns
+/// the process; `env_override` replaces the project's own `python_environment`.
+pub fn spawn_project_process(
+    projects_dir: &str,
+    project_name: &str,
+    env_override: Option<PythonEnvironment>,
+) -> Result<(PythonProcess, ActiveProject), ChanterelleError> {
     let model_dir = Path::new(projects_dir).join(project_name);
     let handler_py = model_dir.join("handler_io.py");
     if !handler_py.exists() {
-        return Err("handler_io.py not found".to_string());
+        return Err(ChanterelleError::config("handler_io.py not found"));
     }
     
-    // Determine project type and load configuration
-    let interactive_meta_path = model_dir.join("interactive.json");
-    let is_interactive = interactive_meta_path.exists();
-
-    let mut python_environment = None;
+    let ProjectConfig { is_interactive, mut python_environment, host_services: host_services_config, model_version } =
+        read_project_config(&model_dir)?;
 
-    if is_interactive {
-        let content = std::fs::read_to_string(&interactive_meta_path)
-            .map_err(|e| format!("Failed to read interactive.json: {}", e))?;
-        
-        let mut value: serde_json::Value = serde_json::from_str(&content)
-            .map_err(|e| format!("Failed to parse interactive.json: {}", e))?;
-            
-        crate::projects::resolve_json_refs(&mut value, &model_dir)?;
-        
-        if let Some(env_val) = value.get("python_environment") {
-             let env: crate::types::PythonEnvironment = serde_json::from_value(env_val.clone())
-                .map_err(|e| format!("Failed to parse python_environment from interactive.json: {}", e))?;
-             python_environment = Some(env);
-        }
-    }
-
-    if python_environment.is_none() {
-        let metadata_path = model_dir.join("model_meta.json");
-        if metadata_path.exists() {
-            let metadata_content = std::fs::read_to_string(&metadata_path)
-                .map_err(|e| format!("Failed to read model_meta.json: {}", e))?;
-            
-            let mut metadata_value: serde_json::Value = serde_json::from_str(&metadata_content)
-                .map_err(|e| format!("Failed to parse model_meta.json: {}", e))?;
-            
-            crate::projects::resolve_json_refs(&mut metadata_value, &model_dir)?;
-            
-            let model_meta: ModelMeta = serde_json::from_value(metadata_value)
-                .map_err(|e| format!("Failed to parse model_meta.json: {}", e))?;
-            python_environment = model_meta.python_environment;
+    for service in host_services_config.iter().flatten() {
+        if !host_services::ALL_SERVICES.contains(&service.as_str()) {
+            println!("Warning: unknown host service '{}' in project '{}'", service, project_name);
         }
     }
     
     // Ensure the base handler files exist in the project directory
-    if is_interactive {
-        println!("Project is interactive. Ensuring interactive handler exists.");
-        ensure_interactive_handler_exists(&model_dir)?;
-    } else {
-        println!("Project is standard. Ensuring base handler exists.");
-        ensure_base_handler_exists(&model_dir)?;
-    }
+    ensure_handler_files(&model_dir)?;
     
     let handler_script = if is_interactive {
         "python_interactive_handler_base.py"
@@ -150,10 +169,15 @@ pub async fn load_model(
         "python_handler_base.py"
     };
 
+    if env_override.is_some() {
+        python_environment = env_override;
+    }
+
     // Build the appropriate Python command based on environment configuration
     let mut command = build_python_command(&python_environment, &model_dir)?;
     
     let python_exe_for_error = format!("{:?}", command.get_program());
+    let env_fingerprint = history::env_fingerprint(&python_environment, &python_exe_for_error);
 
     let mut child = command
         .arg("-u") // Unbuffered output
@@ -163,7 +187,7 @@ pub async fn load_model(
         .stdout(Stdio::piped())
         .stderr(Stdio::piped()) // Capture stderr for debugging
         .spawn()
-        .map_err(|e| format!("Failed to start Python (exe={}): {}", python_exe_for_error, e))?;
+        .map_err(|e| ChanterelleError::spawn(format!("Failed to start Python (exe={}): {}", python_exe_for_error, e)))?;
 
     let stdin = child.stdin.take().unwrap();
     let mut stdout = BufReader::new(child.stdout.take().unwrap());
@@ -173,10 +197,27 @@ pub async fn load_model(
     let process_id = child.id();
     println!("Started Python process with PID: {}", process_id);
 
-    // Handshake: expect a single JSON line announcing readiness or an error
-    let mut init_line = String::new();
-    // read_line waits until it sees a newline, EOF, or an error.
-    match stdout.read_line(&mut init_line) {
+    // Handshake: expect a single JSON line announcing readiness or an error.
+    // read_line waits until it sees a newline, EOF, or an error, so it runs on a
+    // helper thread to turn a hanging model_fn into a timeout.
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut init_line = String::new();
+        let result = stdout.read_line(&mut init_line);
+        let _ = tx.send((stdout, init_line, result));
+    });
+    let (stdout, init_line, read_result) = match rx.recv_timeout(HANDSHAKE_TIMEOUT) {
+        Ok(received) => received,
+        Err(_) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(ChanterelleError::timeout(format!(
+                "Python did not finish initialization within {} seconds",
+                HANDSHAKE_TIMEOUT.as_secs()
+            )));
+        }
+    };
+    match read_result {
         Ok(0) => { // 0 bytes from stdout means EOF with zero bytes read on that call
             // Process may have exited without stdout message; try to provide diagnostics
             match child.try_wait() {
@@ -188,64 +229,61 @@ pub async fn load_model(
                     } else {
                         format!("Python initialization failed. Stderr: {}", err_buf.trim())
                     };
-                    return Err(msg);
+                    return Err(ChanterelleError::spawn(msg));
                 }
                 Ok(None) => {
-                    return Err("Python stdout closed (EOF) while process still running".to_string());
+                    return Err(ChanterelleError::protocol("Python stdout closed (EOF) while process still running"));
                 }
                 Err(e) => {
-                    return Err(format!("Failed checking Python status after EOF: {}", e));
+                    return Err(ChanterelleError::spawn(format!("Failed checking Python status after EOF: {}", e)));
                 }
             }
         }
         Ok(_) => {
             println!("Init from Python: {}", init_line.trim());
             let val: serde_json::Value = serde_json::from_str(init_line.trim())
-                .map_err(|e| format!("Failed to parse Python init JSON: {}. Raw: '{}'", e, init_line.trim()))?;
+                .map_err(|e| ChanterelleError::protocol(format!("Failed to parse Python init JSON: {}. Raw: '{}'", e, init_line.trim())))?;
             if let Some(status) = val.get("status").and_then(|v| v.as_str()) {
                 if status != "ready" {
                     // Prefer detailed error if present
+                    if val.get("error_type").is_some() {
+                        return Err(ChanterelleError::Handler(HandlerError::from_detailed(&val)));
+                    }
                     let err_msg = val
-                        .get("summary")
+                        .get("error")
                         .and_then(|v| v.as_str())
-                        .or_else(|| val.get("error").and_then(|v| v.as_str()))
                         .unwrap_or("Model initialization error");
-                    return Err(format!("Python reported initialization failure: {}", err_msg));
+                    return Err(ChanterelleError::spawn(format!("Python reported initialization failure: {}", err_msg)));
                 }
+            } else if val.get("error_type").is_some() {
+                return Err(ChanterelleError::Handler(HandlerError::from_detailed(&val)));
             } else {
-                return Err("Invalid initialization message from Python (missing 'status')".to_string());
+                return Err(ChanterelleError::protocol("Invalid initialization message from Python (missing 'status')"));
             }
         }
         Err(e) => {
-            return Err(format!("Failed to read Python initialization message: {}", e));
+            return Err(ChanterelleError::protocol(format!("Failed to read Python initialization message: {}", e)));
         }
     }
 
-    // Only store the process after successful handshake
     let python_process = PythonProcess {
         child,
         stdin,
         stdout,
         stderr,
     };
-    *state.python_process.lock().unwrap() = Some(python_process);
-    state.python_pid.store(process_id, Ordering::SeqCst);
-
-    // Handshake already ensured the process is running and ready; proceed to model readiness check
-
-    // Verify the model is actually loaded and ready
-    match check_model_ready(state).await {
-        Ok(true) => {
-            println!("Model health check passed for project: {}", project_name);
-            Ok(())
-        }
-        Ok(false) => Err("Model health check failed: model not ready".to_string()),
-        Err(e) => Err(format!("Model health check failed: {}", e)),
-    }
+    let active_project = ActiveProject {
+        project_name: project_name.to_string(),
+        model_version,
+        env_fingerprint,
+        artifacts_fingerprint: None,
+        host_services: host_services_config,
+    };
+    Ok((python_process, active_project))
 }
 
 /// Build the appropriate Python command based on the environment configuration
-fn build_python_command(python_env: &Option<PythonEnvironment>, model_dir: &Path) -> Result<Command, String> {
+fn build_python_command(python_env: &Option<PythonEnvironment>, model_dir: &Path) -> Result<Command, ChanterelleError> {
     let command = match python_env {
         Some(PythonEnvironment::System) | None => {
             // Use system Python
@@ -271,7 +309,7 @@ fn build_python_command(python_env: &Option<PythonEnvironment>, model_dir: &Path
             };
             
             if !python_executable.exists() {
-                return Err(format!("Python executable not found in venv: {}", python_executable.display()));
+                return Err(ChanterelleError::env(format!("Python executable not found in venv: {}", python_executable.display())));
             }
             
             println!("Using venv Python at: {}", python_executable.display());
@@ -292,7 +330,7 @@ fn build_python_command(python_env: &Option<PythonEnvironment>, model_dir: &Path
             };
             
             if !python_executable.exists() {
-                return Err(format!("Python executable not found in virtualenv: {}", python_executable.display()));
+                return Err(ChanterelleError::env(format!("Python executable not found in virtualenv: {}", python_executable.display())));
             }
             
             println!("Using virtualenv Python at: {}", python_executable.display());
@@ -316,39 +354,209 @@ fn build_python_command(python_env: &Option<PythonEnvironment>, model_dir: &Path
     Ok(command)
 }
 
-fn ensure_base_handler_exists(model_dir: &Path) -> Result<(), String> {
+/// What a project's interactive.json, or else its model_meta.json, says about how to run it.
+struct ProjectConfig {
+    is_interactive: bool,
+    python_environment: Option<PythonEnvironment>,
+    host_services: Option<Vec<String>>,
+    model_version: Option<String>,
+}
+
+fn read_project_config(model_dir: &Path) -> Result<ProjectConfig, ChanterelleError> {
+    let interactive_meta_path = model_dir.join("interactive.json");
+    let is_interactive = interactive_meta_path.exists();
+
+    let mut python_environment = None;
+    let mut host_services_config: Option<Vec<String>> = None;
+    let mut model_version: Option<String> = None;
+
+    if is_interactive {
+        let content = std::fs::read_to_string(&interactive_meta_path)
+            .map_err(|e| ChanterelleError::config(format!("Failed to read interactive.json: {}", e)))?;
+        
+        let mut value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| ChanterelleError::config(format!("Failed to parse interactive.json: {}", e)))?;
+            
+        crate::projects::resolve_json_refs(&mut value, model_dir)?;
+        
+        if let Some(env_val) = value.get("python_environment") {
+             let env: crate::types::PythonEnvironment = serde_json::from_value(env_val.clone())
+                .map_err(|e| ChanterelleError::config(format!("Failed to parse python_environment from interactive.json: {}", e)))?;
+             python_environment = Some(env);
+        }
+        if let Some(services) = value.get("host_services") {
+            let services: Vec<String> = serde_json::from_value(services.clone())
+                .map_err(|e| ChanterelleError::config(format!("Failed to parse host_services from interactive.json: {}", e)))?;
+            host_services_config = Some(services);
+        }
+        model_version = value.get("version").and_then(|v| v.as_str()).map(|v| v.to_string());
+    }
+
+    if python_environment.is_none() {
+        let metadata_path = model_dir.join("model_meta.json");
+        if metadata_path.exists() {
+            let metadata_content = std::fs::read_to_string(&metadata_path)
+                .map_err(|e| ChanterelleError::config(format!("Failed to read model_meta.json: {}", e)))?;
+            
+            let mut metadata_value: serde_json::Value = serde_json::from_str(&metadata_content)
+                .map_err(|e| ChanterelleError::config(format!("Failed to parse model_meta.json: {}", e)))?;
+            
+            crate::projects::resolve_json_refs(&mut metadata_value, model_dir)?;
+            
+            let model_meta: ModelMeta = serde_json::from_value(metadata_value)
+                .map_err(|e| ChanterelleError::config(format!("Failed to parse model_meta.json: {}", e)))?;
+            python_environment = model_meta.python_environment;
+            if !is_interactive {
+                host_services_config = model_meta.host_services;
+                model_version = Some(model_meta.model_version);
+            }
+        }
+    }
+
+    Ok(ProjectConfig {
+        is_interactive,
+        python_environment,
+        host_services: host_services_config,
+        model_version,
+    })
+}
+
+/// `sys.prefix` of the project's Python environment, asked from its interpreter.
+pub fn python_prefix(model_dir: &Path) -> Result<PathBuf, ChanterelleError> {
+    let python_environment = read_project_config(model_dir)?.python_environment;
+    let output = build_python_command(&python_environment, model_dir)?
+        .args(["-c", "import sys; print(sys.prefix)"])
+        .output()
+        .map_err(|e| ChanterelleError::spawn(format!("Failed to start Python: {}", e)))?;
+    if !output.status.success() {
+        return Err(ChanterelleError::env(format!(
+            "Failed to read the Python prefix: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    // `conda run` may print more than the script does; the prefix is the last line
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .ok_or_else(|| ChanterelleError::env("Python printed no prefix"))
+}
+
+/// Writes the base handler files the project's type needs into its folder. Files
+/// already holding the bundled version are left untouched, so processes started
+/// after a call only read them.
+pub fn ensure_handler_files(model_dir: &Path) -> Result<(), ChanterelleError> {
+    ensure_host_helper_exists(model_dir)?;
+    if model_dir.join("interactive.json").exists() {
+        println!("Project is interactive. Ensuring interactive handler exists.");
+        ensure_interactive_handler_exists(model_dir)
+    } else {
+        println!("Project is standard. Ensuring base handler exists.");
+        ensure_base_handler_exists(model_dir)
+    }
+}
+
+/// Writes `content` to `path` unless the file already holds it.
+fn write_if_changed(path: &Path, content: &str) -> std::io::Result<bool> {
+    if std::fs::read(path).is_ok_and(|current| current == content.as_bytes()) {
+        return Ok(false);
+    }
+    std::fs::write(path, content).map(|_| true)
+}
+
+fn ensure_base_handler_exists(model_dir: &Path) -> Result<(), ChanterelleError> {
     let base_handler_path = model_dir.join("python_handler_base.py");
     
     // Always update the base handler to ensure we have the latest version
     let base_handler_content = include_str!("python_handler_base.py");
-    std::fs::write(&base_handler_path, base_handler_content)
-        .map_err(|e| format!("Failed to create/update base handler file: {}", e))?;
-    println!("Updated python_handler_base.py in project directory");
+    let updated = write_if_changed(&base_handler_path, base_handler_content)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create/update base handler file: {}", e)))?;
+    if updated {
+        println!("Updated python_handler_base.py in project directory");
+    }
     
     Ok(())
 }
 
-fn ensure_interactive_handler_exists(model_dir: &Path) -> Result<(), String> {
+fn ensure_host_helper_exists(model_dir: &Path) -> Result<(), ChanterelleError> {
+    let helper_path = model_dir.join("chanterelle_host.py");
+    let content = include_str!("chanterelle_host.py");
+    write_if_changed(&helper_path, content)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create/update chanterelle_host.py: {}", e)))?;
+    Ok(())
+}
+
+fn ensure_interactive_handler_exists(model_dir: &Path) -> Result<(), ChanterelleError> {
     let handler_path = model_dir.join("python_interactive_handler_base.py");
     let content = include_str!("python_interactive_handler_base.py");
-    std::fs::write(&handler_path, content)
-        .map_err(|e| format!("Failed to create/update interactive handler file: {}", e))?;
+    write_if_changed(&handler_path, content)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create/update interactive handler file: {}", e)))?;
     Ok(())
 }
 
+/// A handler's mid-turn request, answered through `respond_interactive`.
+/// Sending `None` (or dropping the sender) cancels the turn.
+pub struct PendingInteraction {
+    pub interaction_id: String,
+    pub responder: mpsc::Sender<Option<serde_json::Value>>,
+}
+
+/// One `invoke_interactive` call. Empty `inputs` means `initialize`.
+pub struct InteractiveRequest {
+    pub inputs: HashMap<String, serde_json::Value>,
+    pub request_id: Option<String>,
+    /// Conversation to run in; each session has its own state on the Python side
+    pub session_id: Option<String>,
+    /// Previous turns to rebuild the conversation from on `initialize`
+    pub session_turns: Option<Vec<serde_json::Value>>,
+}
+
+/// Runs one interactive turn on a blocking thread: reading the handler's output and
+/// waiting for answers to its requests can take minutes, and must not hold up the
+/// async runtime that `respond_interactive` and `stop_interactive` run on.
 pub async fn run_interactive(
     _projects_dir: &str,
-    _project_name: &str,
-    inputs: HashMap<String, serde_json::Value>,
-    request_id: Option<String>,
-    session_turns: Option<Vec<serde_json::Value>>,
+    project_name: &str,
+    request: InteractiveRequest,
     window: tauri::Window,
-    state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), ChanterelleError> {
+    let project_name = project_name.to_string();
+    tauri::async_runtime::spawn_blocking(move || {
+        let app_handle = window.app_handle().clone();
+        let state = app_handle.state::<AppState>();
+        run_interactive_turn(&project_name, request, &window, &state)
+    })
+    .await
+    .map_err(|e| ChanterelleError::protocol(format!("Interactive turn failed: {}", e)))?
+}
+
+fn run_interactive_turn(
+    project_name: &str,
+    request: InteractiveRequest,
+    window: &tauri::Window,
+    state: &AppState,
+) -> Result<(), ChanterelleError> {
+    let InteractiveRequest { inputs, request_id, session_id, session_turns } = request;
     println!("Running interactive session with inputs: {:?}", inputs);
+
+    let session = match session_id {
+        Some(session_id) => select_interactive_session(project_name, session_id, inputs.is_empty(), state)?,
+        None if inputs.is_empty() => begin_interactive_session(project_name, state),
+        None => state.interactive_session.lock().unwrap().clone(),
+    };
+    if let Some(session) = &session {
+        if !inputs.is_empty() {
+            let recorded = serde_json::to_value(&inputs).unwrap_or_default();
+            if let Err(e) = sessions::record_event(session, sessions::SessionEventKind::Input, recorded) {
+                println!("Failed to record session input: {}", e);
+            }
+        }
+    }
     
     let mut guard = state.python_process.lock().unwrap();
-    let process = guard.as_mut().ok_or("Python process not started")?;
+    let process = guard.as_mut().ok_or_else(|| ChanterelleError::spawn("Python process not started"))?;
     validate_process_alive(process)?;
     
     let request_payload = if inputs.is_empty() {
@@ -356,6 +564,9 @@ pub async fn run_interactive(
             "command": "initialize",
             "request_id": request_id
         });
+        if let Some(session) = &session {
+            payload["session_id"] = serde_json::json!(session.session_id);
+        }
         if let Some(turns) = session_turns {
             payload["session_turns"] = serde_json::Value::Array(turns);
         }
@@ -364,10 +575,11 @@ pub async fn run_interactive(
         serde_json::json!({
             "command": "on_input",
             "inputs": inputs,
-            "request_id": request_id
+            "request_id": request_id,
+            "session_id": session.as_ref().map(|s| s.session_id.clone())
         })
     };
-    let inputs_json = serde_json::to_string(&request_payload).map_err(|e| e.to_string())?;
+    let inputs_json = serde_json::to_string(&request_payload).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
     send_request_to_python(process, &inputs_json)?;
     
     // Loop to read streaming responses
@@ -382,6 +594,11 @@ pub async fn run_interactive(
                 // Try parsing to ensure validity before emitting
                 match serde_json::from_str::<serde_json::Value>(trimmed) {
                     Ok(json_val) => {
+                        let host = HostContext { window, state };
+                        if reply_to_host_call(process, &json_val, Some(&host))? {
+                            continue;
+                        }
+
                         // Drain one full request/response turn using explicit Python boundary marker.
                         if json_val
                             .get("_chanterelle_turn_end")
@@ -391,23 +608,206 @@ pub async fn run_interactive(
                             break;
                         }
 
-                        window.emit("interactive:output", &json_val).map_err(|e| e.to_string())?;
+                        if json_val.get("event_type").and_then(|v| v.as_str()) == Some("request") {
+                            await_interaction(process, &json_val, window, state)?;
+                            continue;
+                        }
+
+                        if let Some(session) = &session {
+                            if let Err(e) = sessions::record_event(session, sessions::SessionEventKind::Output, json_val.clone()) {
+                                println!("Failed to record session output: {}", e);
+                            }
+                        }
+                        window.emit("interactive:output", &json_val).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
                     },
                     Err(e) => {
                         println!("Error parsing interactive output: {}", e);
                     }
                 }
             }
-            Err(e) => return Err(format!("Error reading from Python: {}", e)),
+            Err(e) => return Err(ChanterelleError::protocol(format!("Error reading from Python: {}", e))),
+        }
+    }
+    if let Some(session) = &session {
+        if let Err(e) = sessions::end_turn(session) {
+            println!("Failed to record session turn end: {}", e);
         }
     }
     Ok(())
 }
 
+/// Uses the session the frontend asked for, creating its transcript on first initialize.
+fn select_interactive_session(
+    project_name: &str,
+    session_id: String,
+    initialize: bool,
+    state: &AppState,
+) -> Result<Option<sessions::SessionRef>, ChanterelleError> {
+    storage::validate_id(&session_id)?;
+    let session = sessions::SessionRef {
+        project_name: project_name.to_string(),
+        session_id,
+    };
+    if initialize {
+        if let Err(e) = sessions::ensure_session(&session) {
+            println!("Failed to start session transcript: {}", e);
+        }
+        let mut pending = state.pending_session_resume.lock().unwrap();
+        if pending.as_ref().is_some_and(|p| p.session_id == session.session_id) {
+            *pending = None;
+        }
+    }
+    *state.interactive_session.lock().unwrap() = Some(session.clone());
+    Ok(Some(session))
+}
+
+/// Picks the transcript for a new `initialize`: a session queued by
+/// `resume_interactive_session` for this project, otherwise a fresh one.
+fn begin_interactive_session(project_name: &str, state: &AppState) -> Option<sessions::SessionRef> {
+    let pending = state.pending_session_resume.lock().unwrap().take();
+    let session = match pending {
+        Some(session) if session.project_name == project_name => Some(session),
+        _ => match sessions::start_session(project_name) {
+            Ok(meta) => Some(sessions::SessionRef {
+                project_name: meta.project_name,
+                session_id: meta.session_id,
+            }),
+            Err(e) => {
+                println!("Failed to start session transcript: {}", e);
+                None
+            }
+        },
+    };
+    *state.interactive_session.lock().unwrap() = session.clone();
+    session
+}
+
+/// Shows a handler's mid-turn request to the user and blocks until they answer,
+/// then writes the answer back so the same generator can continue. The request is
+/// cancelled when the window goes away or nobody answers within `INTERACTION_TIMEOUT`,
+/// since the wait holds the process lock. Runs on the blocking thread of the turn.
+fn await_interaction(
+    process: &mut PythonProcess,
+    request: &serde_json::Value,
+    window: &tauri::Window,
+    state: &AppState,
+) -> Result<(), ChanterelleError> {
+    let interaction_id = request
+        .get("interaction_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| ChanterelleError::protocol("Interactive request is missing interaction_id"))?
+        .to_string();
+
+    // Register before emitting so an immediate answer finds the channel.
+    let (tx, rx) = mpsc::channel();
+    *state.pending_interaction.lock().unwrap() = Some(PendingInteraction {
+        interaction_id: interaction_id.clone(),
+        responder: tx,
+    });
+    if let Err(e) = window.emit("interactive:request", request) {
+        state.pending_interaction.lock().unwrap().take();
+        return Err(ChanterelleError::protocol(e.to_string()));
+    }
+
+    let started = std::time::Instant::now();
+    let response = loop {
+        match rx.recv_timeout(INTERACTION_POLL) {
+            Ok(response) => break response,
+            Err(mpsc::RecvTimeoutError::Disconnected) => break None,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if window.app_handle().get_webview_window(window.label()).is_none() {
+                    println!("Window closed; cancelling interactive request {}", interaction_id);
+                    break None;
+                }
+                if started.elapsed() >= INTERACTION_TIMEOUT {
+                    println!("No answer to interactive request {}; cancelling it", interaction_id);
+                    break None;
+                }
+            }
+        }
+    };
+    state.pending_interaction.lock().unwrap().take();
+
+    let reply = match response {
+        Some(response) => serde_json::json!({
+            "command": "respond",
+            "interaction_id": interaction_id,
+            "response": response
+        }),
+        None => serde_json::json!({
+            "command": "respond",
+            "interaction_id": interaction_id,
+            "cancelled": true
+        }),
+    };
+    let reply_json = serde_json::to_string(&reply).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
+    send_request_to_python(process, &reply_json)
+}
+
+pub async fn respond_interactive(
+    interaction_id: String,
+    response: serde_json::Value,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    let mut pending = state.pending_interaction.lock().unwrap();
+    match pending.take() {
+        Some(interaction) if interaction.interaction_id == interaction_id => {
+            interaction
+                .responder
+                .send(Some(response))
+                .map_err(|_| ChanterelleError::protocol("Interactive request is no longer waiting"))
+        }
+        other => {
+            *pending = other;
+            Err(ChanterelleError::protocol(format!("No pending interactive request '{}'", interaction_id)))
+        }
+    }
+}
+
+/// Drops the Python-side state of one session. The transcript is kept.
+pub async fn close_interactive_session(
+    session_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<bool, ChanterelleError> {
+    let mut guard = state.python_process.lock().unwrap();
+    let process = guard.as_mut().ok_or_else(|| ChanterelleError::spawn("Python process not started"))?;
+    validate_process_alive(process)?;
+
+    let request = serde_json::json!({
+        "command": "close_session",
+        "session_id": session_id
+    });
+    let request_json = serde_json::to_string(&request).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
+    send_request_to_python(process, &request_json)?;
+
+    let mut closed = false;
+    loop {
+        let message = read_protocol_message(process)?;
+        if message.get("_chanterelle_turn_end").and_then(|v| v.as_bool()) == Some(true) {
+            break;
+        }
+        if let Some(value) = message.get("closed").and_then(|v| v.as_bool()) {
+            closed = value;
+        }
+    }
+
+    let mut current = state.interactive_session.lock().unwrap();
+    if current.as_ref().is_some_and(|s| s.session_id == session_id) {
+        *current = None;
+    }
+    Ok(closed)
+}
+
 pub async fn stop_interactive(
     request_id: Option<String>,
     state: tauri::State<'_, AppState>,
-) -> Result<bool, String> {
+) -> Result<bool, ChanterelleError> {
+    // A turn waiting on the user holds the process lock; cancel through its channel instead.
+    if let Some(interaction) = state.pending_interaction.lock().unwrap().take() {
+        let _ = interaction.responder.send(None);
+        return Ok(true);
+    }
+
     // Try cooperative cancel first if we can lock immediately.
     if let Ok(mut guard) = state.python_process.try_lock() {
         if let Some(process) = guard.as_mut() {
@@ -415,7 +815,7 @@ pub async fn stop_interactive(
                 "command": "cancel",
                 "request_id": request_id
             });
-            let request_json = serde_json::to_string(&request).map_err(|e| e.to_string())?;
+            let request_json = serde_json::to_string(&request).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
             if send_request_to_python(process, &request_json).is_ok() {
                 return Ok(true);
             }
@@ -433,27 +833,78 @@ pub async fn stop_interactive(
         .arg("-15")
         .arg(pid.to_string())
         .status()
-        .map_err(|e| format!("Failed to run soft kill for PID {}: {}", pid, e))?;
+        .map_err(|e| ChanterelleError::spawn(format!("Failed to run soft kill for PID {}: {}", pid, e)))?;
 
     #[cfg(windows)]
     let status = Command::new("taskkill")
         .args(["/PID", &pid.to_string(), "/T"])
         .status()
-        .map_err(|e| format!("Failed to run soft taskkill for PID {}: {}", pid, e))?;
+        .map_err(|e| ChanterelleError::spawn(format!("Failed to run soft taskkill for PID {}: {}", pid, e)))?;
 
     Ok(status.success())
 }
 
+/// Loads the project unless its Python process is already running.
+pub async fn ensure_model_loaded(
+    projects_dir: &str,
+    project_name: &str,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), ChanterelleError> {
+    let is_active = state
+        .active_project
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|p| p.project_name == project_name);
+    let is_alive = state
+        .python_process
+        .lock()
+        .unwrap()
+        .as_mut()
+        .is_some_and(|process| validate_process_alive(process).is_ok());
+
+    if is_active && is_alive {
+        return Ok(());
+    }
+    load_model(projects_dir, project_name, state).await
+}
+
+/// Runs one prediction on a process owned by the caller (see `spawn_project_process`).
+/// Host calls are refused and partial events dropped. Like `run_model`, it expects
+/// checked inputs.
+pub fn run_detached_model(
+    process: &mut PythonProcess,
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<serde_json::Value, ChanterelleError> {
+    validate_process_alive(process)?;
+    let inputs_json = serde_json::to_string(inputs).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
+    send_request_to_python(process, &inputs_json)?;
+    read_response_from_python(process)
+}
+
+/// Checks that a process owned by the caller answers a ping with its model loaded.
+pub fn check_detached_model_ready(process: &mut PythonProcess) -> Result<(), ChanterelleError> {
+    validate_process_alive(process)?;
+    match ping_process(process) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(ChanterelleError::protocol("Model health check failed: model not ready")),
+        Err(e) => Err(e.context("Model health check failed")),
+    }
+}
+
+/// Runs one prediction on the loaded process. Expects inputs checked by the caller
+/// with `validation::check_model_inputs`, so they are coerced once.
 pub async fn run_model(
     _projects_dir: &str,
-    _project_name: &str,
+    project_name: &str,
     inputs: HashMap<String, serde_json::Value>,
+    window: tauri::Window,
     state: tauri::State<'_, AppState>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, ChanterelleError> {
     println!("Running model with inputs: {:?}", inputs);
-    
+
     let mut guard = state.python_process.lock().unwrap();
-    let process = guard.as_mut().ok_or("Python process not started")?;
+    let process = guard.as_mut().ok_or_else(|| ChanterelleError::spawn("Python process not started"))?;
 
     // Validate process health first
     validate_process_alive(process)?;
@@ -462,18 +913,72 @@ pub async fn run_model(
     println!("Using Python process PID: {}", pid);
 
     // Send request to the Python process
-    let inputs_json = serde_json::to_string(&inputs).map_err(|e| e.to_string())?;
+    let inputs_json = serde_json::to_string(&inputs).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
     println!("Sending to Python: {}", inputs_json);
 
     send_request_to_python(process, &inputs_json)?;
-    read_response_from_python(process)
+
+    // Generator handlers stream partial sections before the final merged response
+    let host = HostContext { window: &window, state: &state };
+    read_streaming_response_from_python(process, Some(&host), |mut partial| {
+        partial["project_name"] = serde_json::Value::String(project_name.to_string());
+        window.emit("model:partial", &partial).map_err(|e| ChanterelleError::protocol(e.to_string()))
+    })
 }
 
-fn send_request_to_python(process: &mut PythonProcess, request: &str) -> Result<(), String> {
-    writeln!(process.stdin, "{}", request).map_err(|e| format!("Write failed: {}", e))
+fn send_request_to_python(process: &mut PythonProcess, request: &str) -> Result<(), ChanterelleError> {
+    writeln!(process.stdin, "{}", request).map_err(|e| ChanterelleError::protocol(format!("Write failed: {}", e)))
 }
 
-fn read_response_from_python(process: &mut PythonProcess) -> Result<serde_json::Value, String> {
+fn read_response_from_python(process: &mut PythonProcess) -> Result<serde_json::Value, ChanterelleError> {
+    read_streaming_response_from_python(process, None, |_| Ok(()))
+}
+
+/// Reads lines until the final response, handing every partial event (marked with
+/// `_chanterelle_partial`) to `on_partial` with the marker stripped. Host calls
+/// made by the handler meanwhile are answered through `host`.
+fn read_streaming_response_from_python<F>(
+    process: &mut PythonProcess,
+    host: Option<&HostContext>,
+    mut on_partial: F,
+) -> Result<serde_json::Value, ChanterelleError>
+where
+    F: FnMut(serde_json::Value) -> Result<(), ChanterelleError>,
+{
+    loop {
+        let mut message = read_protocol_message(process)?;
+        if reply_to_host_call(process, &message, host)? {
+            continue;
+        }
+        let is_partial = message
+            .as_object_mut()
+            .and_then(|obj| obj.remove(PARTIAL_KEY))
+            .and_then(|v| v.as_bool())
+            == Some(true);
+        if !is_partial {
+            return interpret_response(message);
+        }
+        on_partial(message)?;
+    }
+}
+
+/// Answers a `chanterelle_host` call from the handler. Returns false for any other message.
+fn reply_to_host_call(
+    process: &mut PythonProcess,
+    message: &serde_json::Value,
+    host: Option<&HostContext>,
+) -> Result<bool, ChanterelleError> {
+    let call = match message.get(host_services::HOST_CALL_KEY) {
+        Some(call) => call,
+        None => return Ok(false),
+    };
+    let reply = host_services::handle_host_call(call, host);
+    let reply_json = serde_json::to_string(&reply).map_err(|e| ChanterelleError::protocol(e.to_string()))?;
+    send_request_to_python(process, &reply_json)?;
+    Ok(true)
+}
+
+fn read_protocol_message(process: &mut PythonProcess) -> Result<serde_json::Value, ChanterelleError> {
     let mut response_line = String::new();
     match process.stdout.read_line(&mut response_line) {
         Ok(0) => {
@@ -484,16 +989,16 @@ fn read_response_from_python(process: &mut PythonProcess) -> Result<serde_json::
                     let mut buf = String::new();
                     let _ = process.stderr.read_to_string(&mut buf);
                     if buf.trim().is_empty() {
-                        return Err("Python process ended unexpectedly (EOF)".to_string());
+                        return Err(ChanterelleError::spawn("Python process ended unexpectedly (EOF)"));
                     } else {
-                        return Err(format!("Python process ended unexpectedly. Stderr: {}", buf.trim()));
+                        return Err(ChanterelleError::spawn(format!("Python process ended unexpectedly. Stderr: {}", buf.trim())));
                     }
                 }
                 Ok(None) => {
-                    return Err("Python stdout closed (EOF) while process still running".to_string());
+                    return Err(ChanterelleError::protocol("Python stdout closed (EOF) while process still running"));
                 }
                 Err(e) => {
-                    return Err(format!("Failed to check Python process status after EOF: {}", e));
+                    return Err(ChanterelleError::spawn(format!("Failed to check Python process status after EOF: {}", e)));
                 }
             }
         }
@@ -501,75 +1006,32 @@ fn read_response_from_python(process: &mut PythonProcess) -> Result<serde_json::
             println!("Received from Python: {}", response_line.trim());
         }
         Err(e) => {
-            return Err(format!("Read failed: {}", e));
+            return Err(ChanterelleError::protocol(format!("Read failed: {}", e)));
         }
     }
 
-    let parsed_response: serde_json::Value = serde_json::from_str(&response_line.trim()).map_err(|e| {
-        format!(
+    serde_json::from_str(&response_line.trim()).map_err(|e| {
+        ChanterelleError::protocol(format!(
             "Parse failed: {}. Raw response: '{}'",
             e,
             response_line.trim()
-        )
-    })?;
+        ))
+    })
+}
 
-    // Check if the response contains error information and enhance it if it's detailed format
+fn interpret_response(parsed_response: serde_json::Value) -> Result<serde_json::Value, ChanterelleError> {
     if let Some(error_obj) = parsed_response.as_object() {
-        // Check for detailed error format first
+        // Detailed error format from the base handlers keeps stage and traceback
         if error_obj.contains_key("error") && error_obj.contains_key("error_type") {
-            // This is a detailed error response from our enhanced Python handler
-            let error_msg = error_obj.get("error")
-                .and_then(|v| v.as_str())
-                .unwrap_or("Unknown error");
-            
-            // let error_type = error_obj.get("error_type")
-            //     .and_then(|v| v.as_str())
-            //     .unwrap_or("Error");
-            
-            // let context = error_obj.get("context")
-            //     .and_then(|v| v.as_str())
-            //     .unwrap_or("");
-            
-            // Only interested in summary
-            let summary = error_obj.get("summary")
-                .and_then(|v| v.as_str())
-                .unwrap_or(error_msg);
-            
-            // // Format traceback information if available
-            // let mut traceback_info = String::new();
-            // if let Some(traceback) = error_obj.get("traceback").and_then(|v| v.as_array()) {
-            //     traceback_info.push_str("\nTraceback:\n");
-            //     for frame in traceback {
-            //         if let Some(frame_obj) = frame.as_object() {
-            //             let file = frame_obj.get("file").and_then(|v| v.as_str()).unwrap_or("unknown");
-            //             let line = frame_obj.get("line").and_then(|v| v.as_u64()).unwrap_or(0);
-            //             let function = frame_obj.get("function").and_then(|v| v.as_str()).unwrap_or("unknown");
-            //             let code = frame_obj.get("code").and_then(|v| v.as_str()).unwrap_or("");
-                        
-            //             traceback_info.push_str(&format!(
-            //                 "  File {}, line {}, in {}:\n    {}\n",
-            //                 file, line, function, code
-            //             ));
-            //         }
-            //     }
-            // }
-            
-            // // Create enhanced error message with all the details
-            // let detailed_msg = if !context.is_empty() {
-            //     format!("{}: {}{}", context, summary, traceback_info)
-            // } else {
-            //     format!("{}{}", summary, traceback_info)
-            // };
-            let detailed_msg = summary.to_string();
-            // Return a new JSON object with the enhanced error message
-            let enhanced_response = serde_json::json!({
-                "error": detailed_msg
-            });
-            return Ok(enhanced_response);
+            return Err(ChanterelleError::Handler(HandlerError::from_detailed(&parsed_response)));
+        }
+        // Plain {"error": "..."} responses, e.g. a handler missing predict_fn()
+        if error_obj.len() == 1 && error_obj.get("error").is_some_and(|v| v.is_string()) {
+            return Err(ChanterelleError::Handler(HandlerError::from_detailed(&parsed_response)));
         }
     }
 
-    // For simple errors or successful responses, return as-is
+    // Successful responses are returned as-is
     Ok(parsed_response)
 }
 
@@ -577,7 +1039,7 @@ fn read_response_from_python(process: &mut PythonProcess) -> Result<serde_json::
 
 // Enhanced validation function with more detailed error reporting
 // This checks if the process did not exit unexpectedly or gave an error
-fn validate_process_alive(process: &mut PythonProcess) -> Result<(), String> {
+fn validate_process_alive(process: &mut PythonProcess) -> Result<(), ChanterelleError> {
     let pid = get_process_id(process);
 
     match process.child.try_wait() {
@@ -590,16 +1052,16 @@ fn validate_process_alive(process: &mut PythonProcess) -> Result<(), String> {
                 "Process was terminated by signal".to_string()
             };
             
-            Err(format!(
+            Err(ChanterelleError::spawn(format!(
                 "Python process (PID: {}) has exited. Reason: {}",
                 pid, exit_reason
-            ))
+            )))
         }
         Ok(None) => Ok(()), // Process is still running
-        Err(e) => Err(format!(
+        Err(e) => Err(ChanterelleError::spawn(format!(
             "Failed to check Python process (PID: {}) status: {}. This might indicate the process crashed or system resource issues.",
             pid, e
-        )),
+        ))),
     }
 }
 
@@ -609,9 +1071,9 @@ fn get_process_id(process: &PythonProcess) -> u32 {
 
 /// Checks if the model is ready by sending a ping request to the Python process and verifying the response.
 // It also uses validate_process_alive to ensure the process is still running.
-pub async fn check_model_ready(state: tauri::State<'_, AppState>) -> Result<bool, String> {
+pub async fn check_model_ready(state: tauri::State<'_, AppState>) -> Result<bool, ChanterelleError> {
     let mut guard = state.python_process.lock().unwrap();
-    let process = guard.as_mut().ok_or("Python process not started")?;
+    let process = guard.as_mut().ok_or_else(|| ChanterelleError::spawn("Python process not started"))?;
 
     let pid = get_process_id(process);
     println!("Checking if model is ready for PID: {}", pid);
@@ -619,6 +1081,11 @@ pub async fn check_model_ready(state: tauri::State<'_, AppState>) -> Result<bool
     // Check if process is still alive first
     validate_process_alive(process)?;
 
+    ping_process(process)
+}
+
+/// Sends a ping and checks the handler answers that its model is loaded.
+fn ping_process(process: &mut PythonProcess) -> Result<bool, ChanterelleError> {
     // Send ping to check if model is loaded
     let ping_request = r#"{"ping": true}"#;
     println!("Sending ping to Python: {}", ping_request);
@@ -635,54 +1102,27 @@ pub async fn check_model_ready(state: tauri::State<'_, AppState>) -> Result<bool
                 .get("error")
                 .and_then(|v| v.as_str())
                 .unwrap_or("Model not ready");
-            Err(format!("Model health check failed: {}", error_msg))
+            Err(ChanterelleError::protocol(format!("Model health check failed: {}", error_msg)))
         }
     } else {
-        Err("Invalid ping response format".to_string())
+        Err(ChanterelleError::protocol("Invalid ping response format"))
     }
 }
 
-pub async fn submit_feedback(
-    feedback: serde_json::Value,
-    state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
-    println!("Submitting feedback to Python");
-    
-    let mut guard = state.python_process.lock().unwrap();
-    // If process is not running, we just ignore the Python part (it's optional custom action)
-    if let Some(process) = guard.as_mut() {
-         // Check if alive but don't fail hard if it's just for feedback? 
-         // Actually if it's there but dead, we should probably know.
-         // But validate_process_alive checks that.
-         if let Err(e) = validate_process_alive(process) {
-             println!("Python process validation failed during feedback: {}", e);
-             return Ok(()); // Don't fail the whole feedback submission if python is dead
-         }
-         
-         let request = serde_json::json!({
-             "command": "feedback",
-             "data": feedback
-         });
-         
-         let request_json = serde_json::to_string(&request).map_err(|e| e.to_string())?;
-         
-         if let Err(e) = send_request_to_python(process, &request_json) {
-              println!("Failed to send feedback to Python: {}", e);
-              return Ok(());
-         }
-         
-         // We expect a response
-         match read_response_from_python(process) {
-             Ok(response) => println!("Python feedback response: {:?}", response),
-             Err(e) => println!("Failed to read Python feedback response: {}", e),
-         }
-    }
-    
-    Ok(())
+/// Hands one feedback entry to the handler's `feedback_fn` and returns its answer:
+/// a `status` of "success" or "ignored", or an `error`.
+pub fn send_feedback(process: &mut PythonProcess, feedback: &serde_json::Value) -> Result<serde_json::Value, ChanterelleError> {
+    validate_process_alive(process)?;
+    let request = serde_json::json!({
+        "command": "feedback",
+        "data": feedback
+    });
+    send_request_to_python(process, &request.to_string())?;
+    read_response_from_python(process)
 }
 
 /// Manually cleanup the Python process. This will be called on app shutdown.
-pub async fn cleanup_python_process(state: tauri::State<'_, AppState>) -> Result<(), String> {
+pub async fn cleanup_python_process(state: tauri::State<'_, AppState>) -> Result<(), ChanterelleError> {
     let mut guard = state.python_process.lock().unwrap();
     
     if let Some(process) = guard.take() {
@@ -702,7 +1142,7 @@ pub async fn cleanup_python_process(state: tauri::State<'_, AppState>) -> Result
     }
 }
 
-pub async fn force_kill_python_process(state: tauri::State<'_, AppState>) -> Result<bool, String> {
+pub async fn force_kill_python_process(state: tauri::State<'_, AppState>) -> Result<bool, ChanterelleError> {
     let pid = state.python_pid.load(Ordering::SeqCst);
     if pid == 0 {
         return Ok(false);
@@ -715,13 +1155,13 @@ pub async fn force_kill_python_process(state: tauri::State<'_, AppState>) -> Res
         .arg("-9")
         .arg(pid.to_string())
         .status()
-        .map_err(|e| format!("Failed to run kill for PID {}: {}", pid, e))?;
+        .map_err(|e| ChanterelleError::spawn(format!("Failed to run kill for PID {}: {}", pid, e)))?;
 
     #[cfg(windows)]
     let status = Command::new("taskkill")
         .args(["/PID", &pid.to_string(), "/T", "/F"])
         .status()
-        .map_err(|e| format!("Failed to run taskkill for PID {}: {}", pid, e))?;
+        .map_err(|e| ChanterelleError::spawn(format!("Failed to run taskkill for PID {}: {}", pid, e)))?;
 
     let killed = status.success();
     if killed {
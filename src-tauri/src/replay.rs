@@ -0,0 +1,398 @@
+// src-tauri/src/replay.rs
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::Emitter;
+
+use crate::error::ChanterelleError;
+use crate::history::HistoryQuery;
+use crate::output_diff::{self, DiffOptions, FieldDiff};
+use crate::python_runner_io;
+use crate::report::{cell, table};
+use crate::state::AppState;
+use crate::storage;
+use crate::validation;
+
+/// Rows shown in the "Changed fields" table; the diff file always has all of them
+const MAX_REPORT_ROWS: usize = 200;
+
+/// Where the inputs to replay come from.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReplaySource {
+    /// Runs recorded by `invoke_model`; picked by id, or by a history filter
+    History {
+        ids: Option<Vec<String>>,
+        query: Option<HistoryQuery>,
+    },
+    /// Feedback entries (their recorded inputs and outputs), picked by id or timestamp;
+    /// all when both are omitted
+    Feedback {
+        ids: Option<Vec<String>>,
+        timestamps: Option<Vec<u64>>,
+    },
+    /// A .json array or .jsonl file of `{"inputs": {...}, "outputs": ...}` records, or of
+    /// bare input objects. Relative paths are resolved against the project folder.
+    File { path: String },
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReplayRequest {
+    pub source: ReplaySource,
+    /// Absolute tolerance per `ModelOutput` name
+    #[serde(default)]
+    pub tolerances: HashMap<String, f64>,
+    pub default_tolerance: Option<f64>,
+    /// Where to write the diff file; defaults to the project's data directory
+    pub diff_path: Option<String>,
+}
+
+/// One set of inputs to re-run, with the outputs it produced before (if known).
+#[derive(Debug, Clone)]
+struct ReplayCase {
+    case_id: String,
+    inputs: HashMap<String, serde_json::Value>,
+    baseline: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplayStatus {
+    Unchanged,
+    Changed,
+    Failed,
+    NoBaseline,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplayCaseResult {
+    pub case_id: String,
+    pub status: ReplayStatus,
+    pub inputs: HashMap<String, serde_json::Value>,
+    pub baseline: Option<serde_json::Value>,
+    pub current: Option<serde_json::Value>,
+    pub error: Option<ChanterelleError>,
+    pub diffs: Vec<FieldDiff>,
+    pub latency_ms: u64,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ReplaySummary {
+    pub total: usize,
+    pub unchanged: usize,
+    pub changed: usize,
+    pub failed: usize,
+    pub no_baseline: usize,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplayReport {
+    pub project_name: String,
+    pub model_version: Option<String>,
+    pub timestamp: u64,
+    pub summary: ReplaySummary,
+    pub cases: Vec<ReplayCaseResult>,
+    /// Insight sections summarizing the replay
+    pub sections: Vec<serde_json::Value>,
+    pub diff_file: String,
+}
+
+fn case_from_record(case_id: String, record: serde_json::Value) -> Result<ReplayCase, ChanterelleError> {
+    let (inputs, baseline) = match record.get("inputs") {
+        Some(inputs) => (
+            inputs.clone(),
+            record.get("outputs").or_else(|| record.get("expected")).cloned(),
+        ),
+        None => (record, None),
+    };
+    let inputs = serde_json::from_value(inputs)
+        .map_err(|e| ChanterelleError::config(format!("Case '{}' has invalid inputs: {}", case_id, e)))?;
+    Ok(ReplayCase { case_id, inputs, baseline })
+}
+
+/// Reads a .json array or a .jsonl file of records.
+pub fn read_records(path: &Path) -> Result<Vec<serde_json::Value>, ChanterelleError> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to read '{}': {}", path.display(), e)))?;
+    if path.extension().and_then(|e| e.to_str()) == Some("jsonl") {
+        return content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .map_err(|e| ChanterelleError::config(format!("Invalid line in '{}': {}", path.display(), e)))
+            })
+            .collect();
+    }
+    match serde_json::from_str(&content)
+        .map_err(|e| ChanterelleError::config(format!("Failed to parse '{}': {}", path.display(), e)))?
+    {
+        serde_json::Value::Array(records) => Ok(records),
+        record => Ok(vec![record]),
+    }
+}
+
+fn feedback_cases(
+    model_dir: &Path,
+    ids: Option<&[String]>,
+    timestamps: Option<&[u64]>,
+    state: &AppState,
+) -> Result<Vec<ReplayCase>, ChanterelleError> {
+    let mut cases = Vec::new();
+    for entry in state.feedback.list(model_dir)? {
+        if ids.is_some_and(|wanted| !wanted.contains(&entry.id)) {
+            continue;
+        }
+        if timestamps.is_some_and(|wanted| !wanted.contains(&entry.timestamp)) {
+            continue;
+        }
+        let case_id = format!("feedback-{}", entry.id);
+        // Feedback linked to a run holds the validated inputs the handler received and what it returned
+        if let Some(run) = entry.run {
+            cases.push(ReplayCase { case_id, inputs: run.inputs, baseline: run.outputs });
+            continue;
+        }
+        let context = &entry.feedback["context"];
+        let Some(inputs) = context.get("inputs").and_then(|i| serde_json::from_value(i.clone()).ok()) else {
+            continue;
+        };
+        cases.push(ReplayCase {
+            case_id,
+            inputs,
+            baseline: context.get("outputs").cloned(),
+        });
+    }
+    Ok(cases)
+}
+
+fn collect_cases(
+    model_dir: &Path,
+    project_name: &str,
+    source: &ReplaySource,
+    state: &AppState,
+) -> Result<Vec<ReplayCase>, ChanterelleError> {
+    match source {
+        ReplaySource::History { ids, query } => {
+            let entries = state.history.matching(project_name, &query.clone().unwrap_or_default())?;
+            Ok(entries
+                .into_iter()
+                .filter(|e| ids.as_ref().is_none_or(|ids| ids.contains(&e.id)))
+                .map(|e| ReplayCase {
+                    // Failed runs are compared in the same shape the handler uses for errors
+                    baseline: e
+                        .outputs
+                        .or_else(|| e.error.map(|err| serde_json::json!({ "error": err.message() }))),
+                    case_id: e.id,
+                    inputs: e.inputs,
+                })
+                .collect())
+        }
+        ReplaySource::Feedback { ids, timestamps } => {
+            feedback_cases(model_dir, ids.as_deref(), timestamps.as_deref(), state)
+        }
+        ReplaySource::File { path } => {
+            let path = model_dir.join(path);
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("case").to_string();
+            read_records(&path)?
+                .into_iter()
+                .enumerate()
+                .map(|(index, record)| {
+                    let case_id = record
+                        .get("id")
+                        .and_then(|id| id.as_str())
+                        .map(|id| id.to_string())
+                        .unwrap_or_else(|| format!("{}-{}", stem, index + 1));
+                    case_from_record(case_id, record)
+                })
+                .collect()
+        }
+    }
+}
+
+fn report_sections(summary: &ReplaySummary, cases: &[ReplayCaseResult], diff_file: &str) -> Vec<serde_json::Value> {
+    let regressions = summary.changed + summary.failed;
+    let summary_rows = vec![
+        serde_json::json!({ "metric": "Cases replayed", "value": summary.total }),
+        serde_json::json!({ "metric": "Unchanged", "value": summary.unchanged }),
+        serde_json::json!({ "metric": "Changed", "value": summary.changed }),
+        serde_json::json!({ "metric": "Failed", "value": summary.failed }),
+        serde_json::json!({ "metric": "No baseline", "value": summary.no_baseline }),
+    ];
+    let mut summary_section = serde_json::json!({
+        "type": "section",
+        "id": "replay_summary",
+        "title": "Replay Summary",
+        "color": if regressions == 0 { "green" } else { "red" },
+        "items": [table("Results", &[("Metric", "metric"), ("Value", "value")], summary_rows)],
+    });
+    if !diff_file.is_empty() {
+        summary_section["description"] = serde_json::json!(format!("Full diff written to {}", diff_file));
+    }
+    let mut sections = vec![summary_section];
+
+    let changed_rows: Vec<serde_json::Value> = cases
+        .iter()
+        .flat_map(|case| {
+            case.diffs.iter().map(move |diff| {
+                serde_json::json!({
+                    "case": case.case_id,
+                    "field": diff.output.clone().unwrap_or_else(|| diff.path.clone()),
+                    "path": diff.path,
+                    "baseline": cell(diff.baseline.as_ref()),
+                    "current": cell(diff.current.as_ref()),
+                    "delta": diff.delta.map(|d| format!("{:+.6}", d)).unwrap_or_default(),
+                })
+            })
+        })
+        .take(MAX_REPORT_ROWS)
+        .collect();
+    if !changed_rows.is_empty() {
+        sections.push(serde_json::json!({
+            "type": "section",
+            "id": "replay_changes",
+            "title": "Changed Fields",
+            "items": [table(
+                "Fields that differ from the recorded outputs",
+                &[("Case", "case"), ("Field", "field"), ("Path", "path"), ("Before", "baseline"), ("Now", "current"), ("Delta", "delta")],
+                changed_rows,
+            )],
+        }));
+    }
+
+    let failure_rows: Vec<serde_json::Value> = cases
+        .iter()
+        .filter(|case| case.status == ReplayStatus::Failed)
+        .map(|case| serde_json::json!({
+            "case": case.case_id,
+            "error": case.error.as_ref().map(|e| e.message()).unwrap_or_default(),
+        }))
+        .collect();
+    if !failure_rows.is_empty() {
+        sections.push(serde_json::json!({
+            "type": "section",
+            "id": "replay_failures",
+            "title": "Failures",
+            "color": "red",
+            "items": [table("Cases that raised an error", &[("Case", "case"), ("Error", "error")], failure_rows)],
+        }));
+    }
+    sections
+}
+
+fn default_diff_path(project_name: &str, timestamp: u64) -> Result<PathBuf, ChanterelleError> {
+    let dir = storage::project_data_dir(project_name)?.join("replays");
+    fs::create_dir_all(&dir)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create replays directory: {}", e)))?;
+    Ok(dir.join(format!("replay-{}.json", timestamp)))
+}
+
+// --- Commands ---
+
+/// Re-runs recorded inputs through the project's current handler and diffs the
+/// outputs against what was recorded. Replays are not added to the run history.
+#[tauri::command]
+pub async fn replay_history(
+    project_name: String,
+    request: ReplayRequest,
+    window: tauri::Window,
+    state: tauri::State<'_, AppState>,
+) -> Result<ReplayReport, ChanterelleError> {
+    let projects_dir = {
+        let settings = state.settings.lock().unwrap();
+        settings.projects_directory.clone()
+    };
+    let model_dir = Path::new(&projects_dir).join(&project_name);
+
+    let cases = collect_cases(&model_dir, &project_name, &request.source, &state)?;
+    if cases.is_empty() {
+        return Err(ChanterelleError::config("No recorded inputs to replay"));
+    }
+
+    python_runner_io::ensure_model_loaded(&projects_dir, &project_name, state.clone()).await?;
+    let model_version = state
+        .active_project
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|p| p.model_version.clone());
+
+    let options = DiffOptions {
+        outputs: output_diff::read_model_outputs(&model_dir),
+        tolerances: request.tolerances,
+        default_tolerance: request.default_tolerance,
+    };
+
+    let declared = validation::read_model_inputs(&model_dir)?;
+    let total = cases.len();
+    let mut summary = ReplaySummary { total, ..Default::default() };
+    let mut results = Vec::with_capacity(total);
+    for (index, case) in cases.into_iter().enumerate() {
+        let started = std::time::Instant::now();
+        let result = match validation::validate_inputs(&declared, case.inputs.clone()) {
+            Ok(inputs) => {
+                python_runner_io::run_model(&projects_dir, &project_name, inputs, window.clone(), state.clone()).await
+            }
+            Err(e) => Err(e),
+        };
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        let (status, current, error, diffs) = match result {
+            Err(e) => (ReplayStatus::Failed, None, Some(e), Vec::new()),
+            Ok(current) => match &case.baseline {
+                None => (ReplayStatus::NoBaseline, Some(current), None, Vec::new()),
+                Some(baseline) => {
+                    let diffs = output_diff::diff_outputs(baseline, &current, &options);
+                    let status = if diffs.is_empty() { ReplayStatus::Unchanged } else { ReplayStatus::Changed };
+                    (status, Some(current), None, diffs)
+                }
+            },
+        };
+        match status {
+            ReplayStatus::Unchanged => summary.unchanged += 1,
+            ReplayStatus::Changed => summary.changed += 1,
+            ReplayStatus::Failed => summary.failed += 1,
+            ReplayStatus::NoBaseline => summary.no_baseline += 1,
+        }
+        results.push(ReplayCaseResult {
+            case_id: case.case_id,
+            status,
+            inputs: case.inputs,
+            baseline: case.baseline,
+            current,
+            error,
+            diffs,
+            latency_ms,
+        });
+
+        let progress = serde_json::json!({ "project_name": project_name, "done": index + 1, "total": total });
+        let _ = window.emit("replay:progress", &progress);
+    }
+
+    let timestamp = storage::now_millis();
+    let diff_path = match &request.diff_path {
+        Some(path) => PathBuf::from(path),
+        None => default_diff_path(&project_name, timestamp)?,
+    };
+
+    let mut report = ReplayReport {
+        project_name,
+        model_version,
+        timestamp,
+        summary,
+        cases: results,
+        sections: Vec::new(),
+        diff_file: diff_path.to_string_lossy().to_string(),
+    };
+    // A failed write should not throw away the replay itself
+    if let Err(e) = fs::write(&diff_path, serde_json::to_vec_pretty(&report).unwrap()) {
+        println!("Failed to write replay diff file: {}", e);
+        report.diff_file = String::new();
+    }
+    report.sections = report_sections(&report.summary, &report.cases, &report.diff_file);
+    println!(
+        "Replay finished for {}: {} changed, {} failed of {}",
+        report.project_name, report.summary.changed, report.summary.failed, report.summary.total
+    );
+    Ok(report)
+}
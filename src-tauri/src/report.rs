@@ -0,0 +1,40 @@
+// src-tauri/src/report.rs
+// Helpers for building insight sections (see visualization-types.md) from Rust.
+
+/// Compact text for a table cell.
+pub fn cell(value: Option<&serde_json::Value>) -> String {
+    match value {
+        None => "—".to_string(),
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+pub fn table(title: &str, columns: &[(&str, &str)], rows: Vec<serde_json::Value>) -> serde_json::Value {
+    serde_json::json!({
+        "type": "table",
+        "title": title,
+        "data": {
+            "columns": columns
+                .iter()
+                .map(|(header, field)| serde_json::json!({ "header": header, "field": field }))
+                .collect::<Vec<_>>(),
+            "rows": rows,
+        }
+    })
+}
+
+/// `YYYY-MM-DD` of a Unix timestamp in seconds, in UTC.
+pub fn utc_date(secs: u64) -> String {
+    // Days to civil date, after Howard Hinnant's `civil_from_days`
+    let z = (secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
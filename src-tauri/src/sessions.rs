@@ -0,0 +1,493 @@
+// src-tauri/src/sessions.rs
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use crate::error::ChanterelleError;
+use crate::state::AppState;
+use crate::storage;
+
+/// Identifies the transcript an interactive turn is recorded into.
+#[derive(Debug, Clone)]
+pub struct SessionRef {
+    pub project_name: String,
+    pub session_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SessionMeta {
+    pub session_id: String,
+    pub project_name: String,
+    pub title: Option<String>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    #[serde(default)]
+    pub turn_count: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionEventKind {
+    /// Inputs the user submitted
+    Input,
+    /// An `interactive:output` event emitted by the handler
+    Output,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SessionEvent {
+    pub timestamp: u64,
+    pub kind: SessionEventKind,
+    pub data: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SessionTranscript {
+    #[serde(flatten)]
+    pub meta: SessionMeta,
+    pub events: Vec<SessionEvent>,
+}
+
+// Each session is a small meta file plus an append-only JSONL of events.
+fn sessions_dir(project_name: &str) -> Result<PathBuf, ChanterelleError> {
+    let dir = storage::project_data_dir(project_name)?.join("sessions");
+    fs::create_dir_all(&dir)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create sessions directory: {}", e)))?;
+    Ok(dir)
+}
+
+fn meta_path(project_name: &str, session_id: &str) -> Result<PathBuf, ChanterelleError> {
+    storage::validate_id(session_id)?;
+    Ok(sessions_dir(project_name)?.join(format!("{}.json", session_id)))
+}
+
+fn events_path(project_name: &str, session_id: &str) -> Result<PathBuf, ChanterelleError> {
+    storage::validate_id(session_id)?;
+    Ok(sessions_dir(project_name)?.join(format!("{}.jsonl", session_id)))
+}
+
+fn read_meta(project_name: &str, session_id: &str) -> Result<SessionMeta, ChanterelleError> {
+    let path = meta_path(project_name, session_id)?;
+    let content = fs::read_to_string(&path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to read session '{}': {}", session_id, e)))?;
+    serde_json::from_str(&content)
+        .map_err(|e| ChanterelleError::io(format!("Failed to parse session '{}': {}", session_id, e)))
+}
+
+fn write_meta(meta: &SessionMeta) -> Result<(), ChanterelleError> {
+    let path = meta_path(&meta.project_name, &meta.session_id)?;
+    let content = serde_json::to_vec_pretty(meta).unwrap();
+    storage::write_atomic(&path, &content)
+}
+
+fn read_events(project_name: &str, session_id: &str) -> Result<Vec<SessionEvent>, ChanterelleError> {
+    let path = events_path(project_name, session_id)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let file = fs::File::open(&path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to open session events: {}", e)))?;
+    let mut events = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| ChanterelleError::io(format!("Failed to read line: {}", e)))?;
+        if line.trim().is_empty() { continue; }
+        match serde_json::from_str::<SessionEvent>(&line) {
+            Ok(event) => events.push(event),
+            Err(e) => println!("Failed to parse session event: {}", e),
+        }
+    }
+    Ok(events)
+}
+
+pub fn start_session(project_name: &str) -> Result<SessionMeta, ChanterelleError> {
+    let now = storage::now_millis();
+    let meta = SessionMeta {
+        session_id: uuid::Uuid::new_v4().to_string(),
+        project_name: project_name.to_string(),
+        title: None,
+        created_at: now,
+        updated_at: now,
+        turn_count: 0,
+    };
+    write_meta(&meta)?;
+    Ok(meta)
+}
+
+/// Creates the transcript for a frontend-chosen session id unless it already exists.
+pub fn ensure_session(session: &SessionRef) -> Result<(), ChanterelleError> {
+    if meta_path(&session.project_name, &session.session_id)?.exists() {
+        return Ok(());
+    }
+    let now = storage::now_millis();
+    write_meta(&SessionMeta {
+        session_id: session.session_id.clone(),
+        project_name: session.project_name.clone(),
+        title: None,
+        created_at: now,
+        updated_at: now,
+        turn_count: 0,
+    })
+}
+
+/// Appends one event to the transcript. Only inputs update the meta file, where the
+/// first text the user sends becomes the title; outputs just append.
+pub fn record_event(
+    session: &SessionRef,
+    kind: SessionEventKind,
+    data: serde_json::Value,
+) -> Result<(), ChanterelleError> {
+    let event = SessionEvent {
+        timestamp: storage::now_millis(),
+        kind,
+        data,
+    };
+
+    let path = events_path(&session.project_name, &session.session_id)?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| ChanterelleError::io(format!("Failed to open session events: {}", e)))?;
+    writeln!(file, "{}", serde_json::to_string(&event).unwrap())
+        .map_err(|e| ChanterelleError::io(format!("Failed to write session event: {}", e)))?;
+
+    if event.kind != SessionEventKind::Input {
+        return Ok(());
+    }
+    let mut meta = read_meta(&session.project_name, &session.session_id)?;
+    meta.updated_at = event.timestamp;
+    meta.turn_count += 1;
+    if meta.title.is_none() {
+        meta.title = input_text(&event.data).map(|text| text.chars().take(60).collect());
+    }
+    write_meta(&meta)
+}
+
+/// Marks the end of a turn, so `updated_at` covers the outputs recorded during it.
+pub fn end_turn(session: &SessionRef) -> Result<(), ChanterelleError> {
+    let mut meta = read_meta(&session.project_name, &session.session_id)?;
+    meta.updated_at = storage::now_millis();
+    write_meta(&meta)
+}
+
+/// Modification time of the events file in milliseconds; it changes with every event.
+fn events_modified_at(project_name: &str, session_id: &str) -> Option<u64> {
+    let modified = fs::metadata(events_path(project_name, session_id).ok()?).ok()?.modified().ok()?;
+    Some(modified.duration_since(std::time::UNIX_EPOCH).ok()?.as_millis() as u64)
+}
+
+/// Text of the first non-empty string input, used for titles and exports.
+fn input_text(inputs: &serde_json::Value) -> Option<String> {
+    inputs
+        .as_object()?
+        .values()
+        .filter_map(|v| v.as_str())
+        .map(|s| s.trim())
+        .find(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
+
+/// Rebuilds the UI-level `session_turns` from recorded events. Output events that
+/// share a `response_id` are merged into one agent turn using their append flag.
+pub fn build_session_turns(events: &[SessionEvent]) -> Vec<serde_json::Value> {
+    let mut turns: Vec<serde_json::Value> = Vec::new();
+    let mut agent_turn_index: HashMap<String, usize> = HashMap::new();
+
+    for (i, event) in events.iter().enumerate() {
+        match event.kind {
+            SessionEventKind::Input => {
+                turns.push(serde_json::json!({
+                    "id": format!("user-{}-{}", event.timestamp, i),
+                    "type": "user",
+                    "content": user_display(&event.data),
+                    "createdAt": event.timestamp,
+                }));
+            }
+            SessionEventKind::Output => {
+                let sections = event
+                    .data
+                    .get("outputs")
+                    .and_then(|v| v.as_array())
+                    .cloned()
+                    .unwrap_or_default();
+                if sections.is_empty() { continue; }
+
+                let response_id = event.data.get("response_id").and_then(|v| v.as_str());
+                let existing = response_id.and_then(|id| agent_turn_index.get(id).copied());
+                match existing {
+                    Some(index) => {
+                        let append = event.data.get("append").and_then(|v| v.as_bool()) != Some(false);
+                        let turn = &mut turns[index];
+                        if append {
+                            if let Some(content) = turn["content"].as_array_mut() {
+                                content.extend(sections);
+                            }
+                        } else {
+                            turn["content"] = serde_json::Value::Array(sections);
+                        }
+                        turn["completedAt"] = serde_json::json!(event.timestamp);
+                    }
+                    None => {
+                        if let Some(id) = response_id {
+                            agent_turn_index.insert(id.to_string(), turns.len());
+                        }
+                        turns.push(serde_json::json!({
+                            "id": format!("agent-{}-{}", event.timestamp, i),
+                            "type": "agent",
+                            "content": sections,
+                            "createdAt": event.timestamp,
+                            "responseId": response_id,
+                            "completedAt": event.timestamp,
+                        }));
+                    }
+                }
+            }
+        }
+    }
+    turns
+}
+
+/// Same shape as the UserDisplay the interactive page renders.
+fn user_display(inputs: &serde_json::Value) -> serde_json::Value {
+    let fields: Vec<(String, String)> = inputs
+        .as_object()
+        .map(|obj| {
+            obj.iter()
+                .map(|(name, value)| {
+                    let text = match value {
+                        serde_json::Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    (name.clone(), text)
+                })
+                .filter(|(_, text)| !text.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if fields.len() == 1 {
+        return serde_json::json!({ "kind": "text", "text": fields[0].1 });
+    }
+    serde_json::json!({
+        "kind": "fields",
+        "fields": fields
+            .into_iter()
+            .map(|(label, value)| serde_json::json!({ "label": label, "value": value }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Renders a transcript as Markdown, keeping text and markdown items and naming the rest.
+fn export_markdown(transcript: &SessionTranscript) -> String {
+    let title = transcript.meta.title.as_deref().unwrap_or("Interactive session");
+    let mut out = format!("# {}\n\n", title);
+
+    for turn in build_session_turns(&transcript.events) {
+        if turn["type"] == "user" {
+            out.push_str("## User\n\n");
+            let content = &turn["content"];
+            if content["kind"] == "text" {
+                out.push_str(content["text"].as_str().unwrap_or(""));
+                out.push_str("\n\n");
+            } else {
+                for field in content["fields"].as_array().into_iter().flatten() {
+                    out.push_str(&format!(
+                        "- **{}**: {}\n",
+                        field["label"].as_str().unwrap_or(""),
+                        field["value"].as_str().unwrap_or("")
+                    ));
+                }
+                out.push('\n');
+            }
+        } else {
+            out.push_str("## Assistant\n\n");
+            push_sections_markdown(&mut out, &turn["content"]);
+        }
+    }
+    out
+}
+
+fn push_sections_markdown(out: &mut String, sections: &serde_json::Value) {
+    for section in sections.as_array().into_iter().flatten() {
+        if let Some(section_title) = section["title"].as_str() {
+            out.push_str(&format!("### {}\n\n", section_title));
+        }
+        for item in section["items"].as_array().into_iter().flatten() {
+            push_item_markdown(out, item);
+        }
+    }
+}
+
+fn push_item_markdown(out: &mut String, item: &serde_json::Value) {
+    match item["type"].as_str() {
+        Some("markdown") => {
+            out.push_str(item["content"].as_str().unwrap_or(""));
+            out.push_str("\n\n");
+        }
+        Some("text") => {
+            for block in item["content"].as_array().into_iter().flatten() {
+                if let Some(text) = block["text"].as_str() {
+                    out.push_str(text);
+                    out.push_str("\n\n");
+                }
+            }
+        }
+        Some("error") => {
+            out.push_str(&format!("> Error: {}\n\n", item["error"].as_str().unwrap_or("")));
+        }
+        Some(other) => {
+            let label = item["title"].as_str().unwrap_or(other);
+            out.push_str(&format!("_[{}: {}]_\n\n", other, label));
+        }
+        None => {}
+    }
+}
+
+/// Turns of a stored session, in the shape of `build_session_turns`.
+pub fn read_session_turns(project_name: &str, session_id: &str) -> Result<Vec<serde_json::Value>, ChanterelleError> {
+    Ok(build_session_turns(&read_events(project_name, session_id)?))
+}
+
+/// Converts UI-level turns into `{role, content}` chat messages, with agent
+/// sections rendered as markdown.
+pub fn chat_messages(turns: &[serde_json::Value]) -> Vec<serde_json::Value> {
+    turns
+        .iter()
+        .map(|turn| {
+            let content = &turn["content"];
+            let (role, text) = if turn["type"] == "user" {
+                let text = if content["kind"] == "fields" {
+                    content["fields"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .map(|f| format!("{}: {}", f["label"].as_str().unwrap_or(""), f["value"].as_str().unwrap_or("")))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                } else {
+                    content["text"].as_str().unwrap_or("").to_string()
+                };
+                ("user", text)
+            } else {
+                let mut text = String::new();
+                push_sections_markdown(&mut text, content);
+                ("assistant", text)
+            };
+            serde_json::json!({ "role": role, "content": text.trim() })
+        })
+        .collect()
+}
+
+// --- Commands ---
+
+#[tauri::command]
+pub async fn list_interactive_sessions(project_name: String) -> Result<Vec<SessionMeta>, ChanterelleError> {
+    let dir = sessions_dir(&project_name)?;
+    let mut sessions = Vec::new();
+
+    for entry in fs::read_dir(&dir).map_err(|e| ChanterelleError::io(format!("Failed to read sessions directory: {}", e)))? {
+        let entry = entry.map_err(|e| ChanterelleError::io(format!("Failed to read directory entry: {}", e)))?;
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") { continue; }
+
+        match fs::read_to_string(&path).map(|c| serde_json::from_str::<SessionMeta>(&c)) {
+            Ok(Ok(mut meta)) => {
+                // Covers a turn still running, or one that ended without `end_turn`
+                if let Some(modified_at) = events_modified_at(&meta.project_name, &meta.session_id) {
+                    meta.updated_at = meta.updated_at.max(modified_at);
+                }
+                sessions.push(meta);
+            }
+            _ => println!("Skipping unreadable session file: {}", path.display()),
+        }
+    }
+
+    // Most recently active first
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
+    Ok(sessions)
+}
+
+#[tauri::command]
+pub async fn load_interactive_session(
+    project_name: String,
+    session_id: String,
+) -> Result<SessionTranscript, ChanterelleError> {
+    Ok(SessionTranscript {
+        meta: read_meta(&project_name, &session_id)?,
+        events: read_events(&project_name, &session_id)?,
+    })
+}
+
+/// Returns `session_turns` for the frontend to pass to the next `initialize`, and
+/// makes that `initialize` continue this transcript instead of starting a new one.
+#[tauri::command]
+pub async fn resume_interactive_session(
+    project_name: String,
+    session_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<serde_json::Value>, ChanterelleError> {
+    read_meta(&project_name, &session_id)?;
+    let events = read_events(&project_name, &session_id)?;
+
+    *state.pending_session_resume.lock().unwrap() = Some(SessionRef {
+        project_name,
+        session_id,
+    });
+    Ok(build_session_turns(&events))
+}
+
+#[tauri::command]
+pub async fn rename_interactive_session(
+    project_name: String,
+    session_id: String,
+    title: String,
+) -> Result<SessionMeta, ChanterelleError> {
+    let mut meta = read_meta(&project_name, &session_id)?;
+    meta.title = Some(title);
+    write_meta(&meta)?;
+    Ok(meta)
+}
+
+#[tauri::command]
+pub async fn delete_interactive_session(
+    project_name: String,
+    session_id: String,
+) -> Result<(), ChanterelleError> {
+    for path in [meta_path(&project_name, &session_id)?, events_path(&project_name, &session_id)?] {
+        if path.exists() {
+            fs::remove_file(&path)
+                .map_err(|e| ChanterelleError::io(format!("Failed to delete '{}': {}", path.display(), e)))?;
+        }
+    }
+    Ok(())
+}
+
+/// Renders a session as "markdown" or "json". The content is returned and, when
+/// `path` is given, also written there.
+#[tauri::command]
+pub async fn export_interactive_session(
+    project_name: String,
+    session_id: String,
+    format: String,
+    path: Option<String>,
+) -> Result<String, ChanterelleError> {
+    let transcript = SessionTranscript {
+        meta: read_meta(&project_name, &session_id)?,
+        events: read_events(&project_name, &session_id)?,
+    };
+
+    let content = match format.as_str() {
+        "markdown" | "md" => export_markdown(&transcript),
+        "json" => serde_json::to_string_pretty(&transcript).unwrap(),
+        other => return Err(ChanterelleError::config(format!("Unsupported export format: {}", other))),
+    };
+
+    if let Some(path) = path {
+        fs::write(&path, &content)
+            .map_err(|e| ChanterelleError::io(format!("Failed to write export to '{}': {}", path, e)))?;
+    }
+    Ok(content)
+}
@@ -4,15 +4,57 @@ use std::env;
 use std::fs;
 use std::path::PathBuf;
 
+/// Editor used to open handler files at a traceback line.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum EditorSetting {
+    /// `code -g {file}:{line}`
+    #[default]
+    Vscode,
+    /// `$VISUAL` or `$EDITOR` with `+{line} {file}`
+    Env,
+    /// Any command line with `{file}` and `{line}` placeholders
+    Custom { template: String },
+}
+
+/// How the projects directory is searched for projects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectDiscovery {
+    /// Folder levels searched below the projects directory; 1 only looks at its
+    /// direct children
+    pub max_depth: usize,
+    /// Names of folders that are never searched; `*` matches any characters.
+    /// Python virtual environments are skipped whatever their name.
+    pub ignore: Vec<String>,
+}
+
+impl Default for ProjectDiscovery {
+    fn default() -> Self {
+        Self {
+            max_depth: 4,
+            ignore: [".*", "node_modules", "__pycache__", "venv", "env", "*.egg-info"]
+                .map(String::from)
+                .to_vec(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
     pub projects_directory: String,
+    #[serde(default)]
+    pub editor: EditorSetting,
+    #[serde(default)]
+    pub project_discovery: ProjectDiscovery,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
             projects_directory: String::new(),
+            editor: EditorSetting::default(),
+            project_discovery: ProjectDiscovery::default(),
         }
     }
 }
@@ -42,7 +84,7 @@ impl Settings {
         Ok(())
     }
 
-    fn get_config_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    pub fn get_config_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
         let config_dir = if cfg!(target_os = "windows") {
             env::var("APPDATA").map(PathBuf::from).or_else(|_| {
                 env::var("USERPROFILE").map(|p| PathBuf::from(p).join("AppData").join("Roaming"))
@@ -1,12 +1,51 @@
 use std::sync::Mutex;
 use std::sync::atomic::AtomicU32;
-use crate::{settings, python_runner_io};
+use crate::{settings, python_runner_io, sessions, kv_store, history, prediction_cache, feedback};
+
+/// Project the Python process was started for
+#[derive(Debug, Clone)]
+pub struct ActiveProject {
+    pub project_name: String,
+    pub model_version: Option<String>,
+    /// Short hash of the Python environment config and interpreter path
+    pub env_fingerprint: String,
+    /// Short hash of the project files when the process was started. Only taken for
+    /// the loaded project, whose runs are recorded and cached.
+    pub artifacts_fingerprint: Option<String>,
+    /// `host_services` from the project metadata; `None` allows every service
+    pub host_services: Option<Vec<String>>,
+}
+
+impl ActiveProject {
+    pub fn allows_host_service(&self, service: &str) -> bool {
+        match &self.host_services {
+            Some(allowed) => allowed.iter().any(|s| s == service),
+            None => true,
+        }
+    }
+}
 
 // App state to store settings
 pub struct AppState {
     pub settings: Mutex<settings::Settings>,
     pub python_process: Mutex<Option<python_runner_io::PythonProcess>>,
     pub python_pid: AtomicU32,
+    /// Transcript the current interactive session is recorded into
+    pub interactive_session: Mutex<Option<sessions::SessionRef>>,
+    /// Session queued by `resume_interactive_session` for the next initialize
+    pub pending_session_resume: Mutex<Option<sessions::SessionRef>>,
+    /// Mid-turn request from an interactive handler that is waiting for the user
+    pub pending_interaction: Mutex<Option<python_runner_io::PendingInteraction>>,
+    /// Project the Python process was started for, used to authorize host calls
+    pub active_project: Mutex<Option<ActiveProject>>,
+    /// Persistent per-project key-value store for handler state
+    pub kv_store: kv_store::KvStore,
+    /// Per-project log of model runs
+    pub history: history::History,
+    /// Opt-in per-project cache of model responses
+    pub prediction_cache: prediction_cache::PredictionCache,
+    /// Feedback files of every project
+    pub feedback: feedback::FeedbackStore,
 }
 
 impl Default for AppState {
@@ -15,6 +54,14 @@ impl Default for AppState {
             settings: Mutex::new(settings::Settings::default()),
             python_process: Mutex::new(None),
             python_pid: AtomicU32::new(0),
+            interactive_session: Mutex::new(None),
+            pending_session_resume: Mutex::new(None),
+            pending_interaction: Mutex::new(None),
+            active_project: Mutex::new(None),
+            kv_store: kv_store::KvStore::default(),
+            history: history::History::default(),
+            prediction_cache: prediction_cache::PredictionCache::default(),
+            feedback: feedback::FeedbackStore::default(),
         }
     }
 }
@@ -0,0 +1,105 @@
+// src-tauri/src/storage.rs
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::ChanterelleError;
+use crate::settings::Settings;
+
+/// Feedback submissions, kept in the project folder so they travel with it
+pub const FEEDBACK_FILE: &str = "feedback.jsonl";
+/// Written to the project folder when a benchmark is saved, next to model_findings.json
+pub const BENCHMARK_FILE: &str = "model_benchmark.json";
+/// Every file the app writes into a project folder; the rest of its data lives in
+/// `project_data_dir`.
+pub const PROJECT_APP_FILES: [&str; 2] = [FEEDBACK_FILE, BENCHMARK_FILE];
+
+/// Directory for data the app keeps about a project (transcripts, history, ...).
+/// It lives next to settings.json rather than inside the user's project folder.
+pub fn project_data_dir(project_name: &str) -> Result<PathBuf, ChanterelleError> {
+    let config_dir = Settings::get_config_dir().map_err(|e| ChanterelleError::io(e.to_string()))?;
+    let dir = config_dir.join("projects").join(project_key(project_name));
+    fs::create_dir_all(&dir)
+        .map_err(|e| ChanterelleError::io(format!("Failed to create data directory '{}': {}", dir.display(), e)))?;
+    Ok(dir)
+}
+
+/// Encodes a project id as a single path component. Bytes outside `[A-Za-z0-9_-]`
+/// become `%XX` escapes, so distinct ids such as `team/a` and `team_a` never share
+/// a directory.
+fn project_key(project_name: &str) -> String {
+    let mut key = String::new();
+    for byte in project_name.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_') {
+            key.push(byte as char);
+        } else {
+            key.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    key
+}
+
+/// Key of earlier versions, which replaced every other character with `_`.
+fn legacy_project_key(project_name: &str) -> String {
+    project_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_') { c } else { '_' })
+        .collect()
+}
+
+/// Moves the data of listed projects from their legacy directories, once the project
+/// list is known. A legacy directory named like an existing project folder belongs to
+/// that project and is left alone.
+pub fn migrate_legacy_dirs<'a>(projects_dir: &str, project_names: impl IntoIterator<Item = &'a str>) {
+    let Ok(config_dir) = Settings::get_config_dir() else { return };
+    for project_name in project_names {
+        let legacy_key = legacy_project_key(project_name);
+        let dir = config_dir.join("projects").join(project_key(project_name));
+        let legacy_dir = dir.with_file_name(&legacy_key);
+        if legacy_dir == dir || dir.exists() || !legacy_dir.is_dir() {
+            continue;
+        }
+        if Path::new(projects_dir).join(&legacy_key).is_dir() {
+            continue;
+        }
+        match fs::rename(&legacy_dir, &dir) {
+            Ok(()) => println!("Moved data of '{}' to {}", project_name, dir.display()),
+            Err(e) => println!("Failed to move data of '{}' from {}: {}", project_name, legacy_dir.display(), e),
+        }
+    }
+}
+
+/// Rejects ids that could escape their directory when used as file names.
+pub fn validate_id(id: &str) -> Result<(), ChanterelleError> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(ChanterelleError::config(format!("Invalid id: '{}'", id)));
+    }
+    Ok(())
+}
+
+/// Writes a file via a temporary sibling and a rename, so readers never see a partial file.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), ChanterelleError> {
+    write_atomic_via(&path.with_extension("tmp"), path, contents)
+}
+
+/// Like `write_atomic`, with the temporary file at `tmp_path`. When `tmp_path` is on
+/// another file system the rename can't work and the file is copied over instead.
+pub fn write_atomic_via(tmp_path: &Path, path: &Path, contents: &[u8]) -> Result<(), ChanterelleError> {
+    fs::write(tmp_path, contents)
+        .map_err(|e| ChanterelleError::io(format!("Failed to write '{}': {}", tmp_path.display(), e)))?;
+    let replaced = match fs::rename(tmp_path, path) {
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+            let copied = fs::copy(tmp_path, path).map(|_| ());
+            let _ = fs::remove_file(tmp_path);
+            copied
+        }
+        result => result,
+    };
+    replaced.map_err(|e| ChanterelleError::io(format!("Failed to replace '{}': {}", path.display(), e)))
+}
+
+pub fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
@@ -0,0 +1,417 @@
+// src-tauri/src/sweep.rs
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tauri::Emitter;
+
+use crate::error::{ChanterelleError, ContractViolation};
+use crate::output_diff;
+use crate::python_runner_io;
+use crate::report::{cell, table};
+use crate::state::AppState;
+use crate::types::{ModelInput, ModelInputConstraint, ModelInputConstraintOptions, ModelInputType, ModelOutputType};
+use crate::validation;
+
+/// Grid size for a numeric input without a step (or when `points` is not given)
+const DEFAULT_AXIS_POINTS: usize = 21;
+/// Upper bound on points along one input
+const MAX_AXIS_POINTS: usize = 200;
+/// Upper bound on handler calls for a whole sweep
+const MAX_SWEEP_POINTS: usize = 2500;
+
+/// One swept input. The grid comes from the input's constraints unless overridden here.
+#[derive(Deserialize, Debug, Clone)]
+pub struct SweepAxis {
+    pub input: String,
+    /// Explicit values to try, in order
+    pub values: Option<Vec<serde_json::Value>>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub step: Option<f64>,
+    /// Evenly spaced points between min and max, used when there is no step
+    pub points: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SweepRequest {
+    /// Values for every input that is not swept
+    #[serde(default)]
+    pub base_inputs: HashMap<String, serde_json::Value>,
+    /// One input for line or bar charts, two for heatmaps
+    pub axes: Vec<SweepAxis>,
+    /// `ModelOutput` names to chart; defaults to every float and int output
+    #[serde(default)]
+    pub outputs: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SweepAxisValues {
+    pub input: String,
+    pub label: String,
+    pub values: Vec<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SweepPoint {
+    /// Value of each swept input, in axis order
+    pub values: Vec<serde_json::Value>,
+    /// Charted outputs found in the response; empty when the handler failed. Outputs
+    /// the response has no number for are reported in `error`.
+    pub outputs: HashMap<String, serde_json::Value>,
+    pub error: Option<ChanterelleError>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SweepReport {
+    pub project_name: String,
+    pub model_version: Option<String>,
+    pub axes: Vec<SweepAxisValues>,
+    pub outputs: Vec<String>,
+    pub points: Vec<SweepPoint>,
+    pub errors: usize,
+    pub sections: Vec<serde_json::Value>,
+}
+
+fn read_meta(model_dir: &Path) -> Result<serde_json::Value, ChanterelleError> {
+    let content = fs::read_to_string(model_dir.join("model_meta.json"))
+        .map_err(|e| ChanterelleError::config(format!("Failed to read model_meta.json: {}", e)))?;
+    let mut meta: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| ChanterelleError::config(format!("Failed to parse model_meta.json: {}", e)))?;
+    crate::projects::resolve_json_refs(&mut meta, model_dir)?;
+    Ok(meta)
+}
+
+fn find_input(meta: &serde_json::Value, name: &str) -> Result<ModelInput, ChanterelleError> {
+    let input = meta["inputs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|input| input["name"].as_str() == Some(name))
+        .ok_or_else(|| ChanterelleError::config(format!("Input '{}' not found in model_meta.json", name)))?;
+    serde_json::from_value(input.clone())
+        .map_err(|e| ChanterelleError::config(format!("Invalid definition for input '{}': {}", name, e)))
+}
+
+fn numeric_grid(axis: &SweepAxis, constraints: Option<&ModelInputConstraint>, is_int: bool) -> Result<Vec<f64>, ChanterelleError> {
+    let min = axis.min.or(constraints.and_then(|c| c.min));
+    let max = axis.max.or(constraints.and_then(|c| c.max));
+    let (Some(min), Some(max)) = (min, max) else {
+        return Err(ChanterelleError::config(format!(
+            "Input '{}' needs a min and max to sweep; set them in its constraints or on the axis",
+            axis.input
+        )));
+    };
+    if max < min {
+        return Err(ChanterelleError::config(format!("Input '{}' has max below min", axis.input)));
+    }
+
+    let step = match axis.points {
+        Some(_) => axis.step,
+        None => axis.step.or(constraints.and_then(|c| c.step)),
+    };
+    let mut values: Vec<f64> = match step.filter(|step| *step > 0.0) {
+        Some(step) => {
+            let count = ((max - min) / step + 1e-9).floor() as usize + 1;
+            if count > MAX_AXIS_POINTS {
+                return Err(ChanterelleError::config(format!(
+                    "Sweeping '{}' by {} gives {} points (at most {}); use a larger step or `points`",
+                    axis.input, step, count, MAX_AXIS_POINTS
+                )));
+            }
+            (0..count).map(|i| min + step * i as f64).collect()
+        }
+        None => {
+            let count = axis.points.unwrap_or(DEFAULT_AXIS_POINTS).clamp(2, MAX_AXIS_POINTS);
+            (0..count).map(|i| min + (max - min) * i as f64 / (count - 1) as f64).collect()
+        }
+    };
+    if is_int {
+        values = values.into_iter().map(f64::round).collect();
+        values.dedup();
+    }
+    Ok(values)
+}
+
+/// Values to try for one axis.
+fn axis_values(
+    input: &ModelInput,
+    axis: &SweepAxis,
+    base_inputs: &HashMap<String, serde_json::Value>,
+) -> Result<Vec<serde_json::Value>, ChanterelleError> {
+    if let Some(values) = axis.values.as_ref().filter(|values| !values.is_empty()) {
+        return Ok(values.clone());
+    }
+    let constraints = validation::effective_constraints(input, base_inputs);
+    let constraints = constraints.as_ref();
+    match input.input_type {
+        ModelInputType::Float => Ok(numeric_grid(axis, constraints, false)?
+            .into_iter()
+            .map(|v| serde_json::json!(v))
+            .collect()),
+        ModelInputType::Int => Ok(numeric_grid(axis, constraints, true)?
+            .into_iter()
+            .map(|v| serde_json::json!(v as i64))
+            .collect()),
+        ModelInputType::Category => match constraints.and_then(|c| c.options.as_ref()) {
+            Some(ModelInputConstraintOptions::Strings(options)) => {
+                Ok(options.iter().map(|o| serde_json::json!(o)).collect())
+            }
+            Some(ModelInputConstraintOptions::Objects(options)) => {
+                Ok(options.iter().map(|o| serde_json::json!(o.value)).collect())
+            }
+            None => Err(ChanterelleError::config(format!("Input '{}' has no options to sweep", input.name))),
+        },
+        ModelInputType::Boolean => Ok(vec![serde_json::json!(false), serde_json::json!(true)]),
+        _ => Err(ChanterelleError::config(format!(
+            "Input '{}' cannot be swept without explicit `values`",
+            input.name
+        ))),
+    }
+}
+
+/// Outputs to chart: the requested names, or every numeric declared output.
+fn chart_outputs(model_dir: &Path, requested: &[String]) -> Result<Vec<String>, ChanterelleError> {
+    if !requested.is_empty() {
+        return Ok(requested.to_vec());
+    }
+    let outputs: Vec<String> = output_diff::read_model_outputs(model_dir)
+        .into_iter()
+        .filter(|o| matches!(o.output_type, ModelOutputType::Float | ModelOutputType::Int))
+        .map(|o| o.name)
+        .collect();
+    if outputs.is_empty() {
+        return Err(ChanterelleError::config("No numeric outputs declared; pass the outputs to chart"));
+    }
+    Ok(outputs)
+}
+
+fn is_numeric_axis(values: &[serde_json::Value]) -> bool {
+    values.iter().all(|v| v.is_number())
+}
+
+fn chart_1d(axis: &SweepAxisValues, output: &str, points: &[SweepPoint]) -> serde_json::Value {
+    let title = format!("{} by {}", output, axis.label);
+    let y = |point: &SweepPoint| point.outputs.get(output).and_then(|v| v.as_f64());
+    if is_numeric_axis(&axis.values) {
+        let line: Vec<serde_json::Value> = points
+            .iter()
+            .filter_map(|point| Some(serde_json::json!({ "x": point.values[0], "y": y(point)? })))
+            .collect();
+        serde_json::json!({
+            "type": "line_chart",
+            "id": format!("sweep_{}", output),
+            "title": title,
+            "data": {
+                "lines": [{ "id": output, "points": line }],
+                "axis": { "x": { "label": axis.label }, "y": { "label": output } }
+            }
+        })
+    } else {
+        let bars: Vec<serde_json::Value> = points
+            .iter()
+            .filter_map(|point| Some(serde_json::json!({ "label": cell(Some(&point.values[0])), "value": y(point)? })))
+            .collect();
+        serde_json::json!({
+            "type": "bar_chart",
+            "id": format!("sweep_{}", output),
+            "title": title,
+            "data": {
+                "bars": bars,
+                "axis": { "x": { "label": axis.label }, "y": { "label": output } }
+            }
+        })
+    }
+}
+
+/// Heatmap over two inputs; points are in row-major order (first axis outermost).
+fn chart_2d(x_axis: &SweepAxisValues, y_axis: &SweepAxisValues, output: &str, points: &[SweepPoint]) -> serde_json::Value {
+    let labels = |axis: &SweepAxisValues| -> Vec<serde_json::Value> {
+        if is_numeric_axis(&axis.values) {
+            axis.values.clone()
+        } else {
+            axis.values.iter().map(|v| serde_json::json!(cell(Some(v)))).collect()
+        }
+    };
+    // Plotly wants one row per y value
+    let z: Vec<Vec<serde_json::Value>> = (0..y_axis.values.len())
+        .map(|row| {
+            (0..x_axis.values.len())
+                .map(|col| {
+                    points[col * y_axis.values.len() + row]
+                        .outputs
+                        .get(output)
+                        .cloned()
+                        .unwrap_or(serde_json::Value::Null)
+                })
+                .collect()
+        })
+        .collect();
+    serde_json::json!({
+        "type": "plotly",
+        "id": format!("sweep_{}", output),
+        "title": format!("{} by {} and {}", output, x_axis.label, y_axis.label),
+        "data": [{ "type": "heatmap", "x": labels(x_axis), "y": labels(y_axis), "z": z, "colorbar": { "title": output } }],
+        "layout": {
+            "xaxis": { "title": x_axis.label },
+            "yaxis": { "title": y_axis.label },
+            "height": 400
+        }
+    })
+}
+
+fn report_sections(axes: &[SweepAxisValues], outputs: &[String], points: &[SweepPoint]) -> Vec<serde_json::Value> {
+    let charts: Vec<serde_json::Value> = outputs
+        .iter()
+        .map(|output| match axes {
+            [axis] => chart_1d(axis, output, points),
+            [x_axis, y_axis] => chart_2d(x_axis, y_axis, output, points),
+            _ => unreachable!("sweeps have one or two axes"),
+        })
+        .collect();
+    let mut sections = vec![serde_json::json!({
+        "type": "section",
+        "id": "sweep",
+        "title": "Sensitivity Sweep",
+        "description": format!(
+            "{} over {} points",
+            axes.iter().map(|a| a.label.as_str()).collect::<Vec<_>>().join(" × "),
+            points.len()
+        ),
+        "items_per_row": if axes.len() == 1 { 2 } else { 1 },
+        "items": charts,
+    })];
+
+    let failure_rows: Vec<serde_json::Value> = points
+        .iter()
+        .filter_map(|point| {
+            let error = point.error.as_ref()?;
+            let at = axes
+                .iter()
+                .zip(&point.values)
+                .map(|(axis, value)| format!("{} = {}", axis.label, cell(Some(value))))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Some(serde_json::json!({ "point": at, "error": error.message() }))
+        })
+        .collect();
+    if !failure_rows.is_empty() {
+        sections.push(serde_json::json!({
+            "type": "section",
+            "id": "sweep_failures",
+            "title": "Failures",
+            "color": "red",
+            "items": [table("Points that raised an error", &[("Point", "point"), ("Error", "error")], failure_rows)],
+        }));
+    }
+    sections
+}
+
+// --- Commands ---
+
+/// Runs the base inputs through the loaded handler once per grid point, varying one or
+/// two inputs, and charts how the chosen outputs respond. Sweeps are not added to the
+/// run history. Constraints that depend on another input use its base value.
+#[tauri::command]
+pub async fn sweep_input(
+    project_name: String,
+    request: SweepRequest,
+    window: tauri::Window,
+    state: tauri::State<'_, AppState>,
+) -> Result<SweepReport, ChanterelleError> {
+    let projects_dir = {
+        let settings = state.settings.lock().unwrap();
+        settings.projects_directory.clone()
+    };
+    let model_dir = Path::new(&projects_dir).join(&project_name);
+
+    if request.axes.is_empty() || request.axes.len() > 2 {
+        return Err(ChanterelleError::config("A sweep needs one or two inputs"));
+    }
+    let meta = read_meta(&model_dir)?;
+    let mut axes = Vec::with_capacity(request.axes.len());
+    for axis in &request.axes {
+        let input = find_input(&meta, &axis.input)?;
+        let values = axis_values(&input, axis, &request.base_inputs)?;
+        axes.push(SweepAxisValues { input: input.name, label: input.label, values });
+    }
+    if axes.len() == 2 && axes[0].input == axes[1].input {
+        return Err(ChanterelleError::config("The two swept inputs must differ"));
+    }
+    let outputs = chart_outputs(&model_dir, &request.outputs)?;
+
+    let grid: Vec<Vec<serde_json::Value>> = match axes.as_slice() {
+        [axis] => axis.values.iter().map(|v| vec![v.clone()]).collect(),
+        [x_axis, y_axis] => x_axis
+            .values
+            .iter()
+            .flat_map(|x| y_axis.values.iter().map(move |y| vec![x.clone(), y.clone()]))
+            .collect(),
+        _ => unreachable!(),
+    };
+    if grid.len() > MAX_SWEEP_POINTS {
+        return Err(ChanterelleError::config(format!(
+            "The sweep has {} points (at most {}); use fewer points per input",
+            grid.len(),
+            MAX_SWEEP_POINTS
+        )));
+    }
+
+    python_runner_io::ensure_model_loaded(&projects_dir, &project_name, state.clone()).await?;
+    let model_version = state
+        .active_project
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|p| p.model_version.clone());
+
+    let declared = validation::read_model_inputs(&model_dir)?;
+    let total = grid.len();
+    let mut points = Vec::with_capacity(total);
+    for (index, values) in grid.into_iter().enumerate() {
+        let mut inputs = request.base_inputs.clone();
+        for (axis, value) in axes.iter().zip(&values) {
+            inputs.insert(axis.input.clone(), value.clone());
+        }
+        let result = match validation::validate_inputs(&declared, inputs) {
+            Ok(inputs) => {
+                python_runner_io::run_model(&projects_dir, &project_name, inputs, window.clone(), state.clone()).await
+            }
+            Err(e) => Err(e),
+        };
+        let point = match result {
+            Ok(response) => {
+                let mut found = HashMap::new();
+                let mut missing = Vec::new();
+                for name in &outputs {
+                    match output_diff::output_number(&response, name) {
+                        Some(value) => {
+                            found.insert(name.clone(), value);
+                        }
+                        None => missing.push(ContractViolation {
+                            output: name.clone(),
+                            path: name.clone(),
+                            message: format!("{}: the response has no numeric field named '{}'", name, name),
+                        }),
+                    }
+                }
+                let error = (!missing.is_empty()).then(|| ChanterelleError::contract(missing));
+                SweepPoint { values, outputs: found, error }
+            }
+            // A dead process fails every remaining point the same way
+            Err(e @ (ChanterelleError::Spawn { .. } | ChanterelleError::Protocol { .. })) => {
+                return Err(e.context(&format!("Sweep stopped at point {}", index + 1)));
+            }
+            Err(e) => SweepPoint { values, outputs: HashMap::new(), error: Some(e) },
+        };
+        points.push(point);
+
+        let progress = serde_json::json!({ "project_name": project_name, "done": index + 1, "total": total });
+        let _ = window.emit("sweep:progress", &progress);
+    }
+
+    let errors = points.iter().filter(|p| p.error.is_some()).count();
+    let sections = report_sections(&axes, &outputs, &points);
+    println!("Sweep finished for {}: {} points, {} failed", project_name, total, errors);
+    Ok(SweepReport { project_name, model_version, axes, outputs, points, errors, sections })
+}
@@ -22,6 +22,24 @@ pub struct ModelInputConstraint {
     pub step: Option<f64>,
     pub options: Option<ModelInputConstraintOptions>,
     pub regex: Option<String>,
+    /// Values sent by `yes_no` inputs (default true / false)
+    pub yes_value: Option<serde_json::Value>,
+    pub no_value: Option<serde_json::Value>,
+}
+
+impl ModelInputConstraint {
+    /// Field-wise merge: values set in `other` win, like `mergeConstraints` in the form.
+    pub fn merged_with(&self, other: &ModelInputConstraint) -> ModelInputConstraint {
+        ModelInputConstraint {
+            min: other.min.or(self.min),
+            max: other.max.or(self.max),
+            step: other.step.or(self.step),
+            options: other.options.clone().or_else(|| self.options.clone()),
+            regex: other.regex.clone().or_else(|| self.regex.clone()),
+            yes_value: other.yes_value.clone().or_else(|| self.yes_value.clone()),
+            no_value: other.no_value.clone().or_else(|| self.no_value.clone()),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -46,7 +64,10 @@ pub enum ModelInputType {
     Category,
     Boolean,
     Textarea,
-    File
+    File,
+    Button,
+    #[serde(rename = "yes_no")]
+    YesNo,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -150,6 +171,61 @@ pub enum PythonEnvironment {
     },
 }
 
+/// `prediction_cache` in model_meta.json; its presence turns the cache on.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct PredictionCacheConfig {
+    pub max_entries: Option<usize>,
+    pub max_bytes: Option<u64>,
+    pub ttl_seconds: Option<u64>,
+}
+
+/// `feedback_schema` in model_meta.json or interactive.json: the fields of the
+/// feedback form. Without one, feedback is a thumbs up or down and a comment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeedbackSchema {
+    pub fields: Vec<FeedbackField>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeedbackField {
+    pub name: String,
+    pub label: String,
+    #[serde(flatten)]
+    pub kind: FeedbackFieldKind,
+    pub required: Option<bool>,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum FeedbackFieldKind {
+    /// A whole number from `min` to `max`
+    Rating {
+        #[serde(default = "default_rating_min")]
+        min: i64,
+        #[serde(default = "default_rating_max")]
+        max: i64,
+    },
+    /// One of `options`, or any number of them when `multiple`
+    Label {
+        options: Vec<String>,
+        #[serde(default)]
+        multiple: bool,
+    },
+    /// The value an output should have had, checked like the declared output
+    Correction { output: ModelOutput },
+    Text { max_length: Option<usize> },
+}
+
+fn default_rating_min() -> i64 {
+    1
+}
+
+fn default_rating_max() -> i64 {
+    5
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ModelMeta {
     pub model_id: Option<String>,
@@ -165,11 +241,20 @@ pub struct ModelMeta {
     pub outputs: Vec<ModelOutput>,
     pub python_environment: Option<PythonEnvironment>,
     pub allow_feedback: Option<bool>,
+    /// Host calls handlers may make through `chanterelle_host`; all when omitted
+    pub host_services: Option<Vec<String>>,
+    /// Fail predictions whose response breaks the declared `outputs` instead of warning
+    pub strict_outputs: Option<bool>,
+    /// Reuse responses for inputs seen before with the same model version and files
+    pub prediction_cache: Option<PredictionCacheConfig>,
+    pub feedback_schema: Option<FeedbackSchema>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProjectMeta {
-    pub project_name: String, // folder name
+    pub project_name: String, // folder path relative to the projects directory, e.g. "team/domain/project"
+    /// Folders above the project, e.g. "team/domain"; `None` for top-level projects
+    pub category: Option<String>,
     pub project_title: String,
     pub description: String,
     pub description_short: String,
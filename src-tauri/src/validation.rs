@@ -0,0 +1,201 @@
+// src-tauri/src/validation.rs
+// Checks inputs against the typed ModelMeta before they reach the Python handler,
+// mirroring the checks the form does (see src/components/form/inputs/index.ts).
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::error::{ChanterelleError, FieldError};
+use crate::types::{ModelInput, ModelInputConstraint, ModelInputConstraintOptions, ModelInputType};
+
+/// Slack for float rounding when checking min and max
+const EPSILON: f64 = 1e-9;
+/// Slack, in steps, when checking that a value lies on the step grid
+const STEP_TOLERANCE: f64 = 1e-6;
+
+/// Reads the declared inputs of a project. Inputs whose definition does not parse
+/// are left out, so they reach the handler unchecked as before.
+pub fn read_model_inputs(model_dir: &Path) -> Result<Vec<ModelInput>, ChanterelleError> {
+    let path = model_dir.join("model_meta.json");
+    let content = fs::read_to_string(&path)
+        .map_err(|e| ChanterelleError::config(format!("Failed to read '{}': {}", path.display(), e)))?;
+    let mut meta: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| ChanterelleError::config(format!("Failed to parse '{}': {}", path.display(), e)))?;
+    crate::projects::resolve_json_refs(&mut meta, model_dir)?;
+
+    Ok(meta["inputs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|input| match serde_json::from_value::<ModelInput>(input.clone()) {
+            Ok(input) => Some(input),
+            Err(e) => {
+                println!("Skipping validation of input {}: {}", input["name"], e);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Reads the declared inputs of a project and checks `values` against them (see
+/// `validate_inputs`). Callers run this once, before the inputs reach the handler.
+pub fn check_model_inputs(
+    model_dir: &Path,
+    values: HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, serde_json::Value>, ChanterelleError> {
+    validate_inputs(&read_model_inputs(model_dir)?, values)
+}
+
+/// Constraints in effect for an input: its own, overridden by the `depends_on` mapping
+/// for the current value of the input it depends on.
+pub fn effective_constraints(
+    input: &ModelInput,
+    values: &HashMap<String, serde_json::Value>,
+) -> Option<ModelInputConstraint> {
+    let mapped = input.depends_on.as_ref().and_then(|dep| {
+        let parent = match values.get(&dep.input_name)? {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        dep.mapping.get(&parent)?.constraints.clone()
+    });
+    match (input.constraints.as_ref(), mapped) {
+        (Some(base), Some(mapped)) => Some(base.merged_with(&mapped)),
+        (base, mapped) => mapped.or_else(|| base.cloned()),
+    }
+}
+
+pub fn is_empty(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => true,
+        serde_json::Value::String(s) => s.trim().is_empty(),
+        serde_json::Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+fn to_f64(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn check_range(label: &str, value: f64, constraints: Option<&ModelInputConstraint>) -> Result<(), String> {
+    let Some(c) = constraints else { return Ok(()) };
+    if let Some(min) = c.min.filter(|min| value < min - EPSILON) {
+        return Err(format!("{} must be at least {}", label, min));
+    }
+    if let Some(max) = c.max.filter(|max| value > max + EPSILON) {
+        return Err(format!("{} must be at most {}", label, max));
+    }
+    if let Some(step) = c.step.filter(|step| *step > 0.0) {
+        let steps = (value - c.min.unwrap_or(0.0)) / step;
+        if (steps - steps.round()).abs() > STEP_TOLERANCE {
+            return Err(format!("{} must be a multiple of {} from {}", label, step, c.min.unwrap_or(0.0)));
+        }
+    }
+    Ok(())
+}
+
+fn option_values(options: &ModelInputConstraintOptions) -> Vec<&str> {
+    match options {
+        ModelInputConstraintOptions::Strings(options) => options.iter().map(|o| o.as_str()).collect(),
+        ModelInputConstraintOptions::Objects(options) => options.iter().map(|o| o.value.as_str()).collect(),
+    }
+}
+
+/// Checks and coerces one non-empty value.
+fn check_value(
+    input: &ModelInput,
+    value: &serde_json::Value,
+    constraints: Option<&ModelInputConstraint>,
+) -> Result<serde_json::Value, String> {
+    let label = &input.label;
+    match input.input_type {
+        ModelInputType::Float => {
+            let number = to_f64(value).filter(|n| n.is_finite()).ok_or_else(|| format!("{} must be a number", label))?;
+            check_range(label, number, constraints)?;
+            Ok(serde_json::json!(number))
+        }
+        ModelInputType::Int => {
+            let number = to_f64(value)
+                .filter(|n| n.is_finite() && n.fract() == 0.0)
+                .ok_or_else(|| format!("{} must be a whole number", label))?;
+            check_range(label, number, constraints)?;
+            Ok(serde_json::json!(number as i64))
+        }
+        ModelInputType::String | ModelInputType::Textarea => {
+            let text = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Number(_) | serde_json::Value::Bool(_) => value.to_string(),
+                _ => return Err(format!("{} must be text", label)),
+            };
+            if let Some(pattern) = constraints.and_then(|c| c.regex.as_deref()) {
+                let regex = regex::Regex::new(pattern).map_err(|e| format!("{} has an invalid pattern: {}", label, e))?;
+                if !regex.is_match(&text) {
+                    return Err(format!("{} does not match the required pattern", label));
+                }
+            }
+            Ok(serde_json::Value::String(text))
+        }
+        ModelInputType::Category => {
+            let choice = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            if let Some(options) = constraints.and_then(|c| c.options.as_ref()) {
+                if !option_values(options).contains(&choice.as_str()) {
+                    return Err(format!("{} must be one of the listed options", label));
+                }
+            }
+            Ok(value.clone())
+        }
+        ModelInputType::Boolean => match value {
+            serde_json::Value::Bool(_) => Ok(value.clone()),
+            serde_json::Value::String(s) if s == "true" || s == "false" => Ok(serde_json::json!(s == "true")),
+            _ => Err(format!("{} must be true or false", label)),
+        },
+        ModelInputType::YesNo => {
+            let yes = constraints.and_then(|c| c.yes_value.clone()).unwrap_or(serde_json::json!(true));
+            let no = constraints.and_then(|c| c.no_value.clone()).unwrap_or(serde_json::json!(false));
+            if *value == yes || *value == no {
+                Ok(value.clone())
+            } else {
+                Err(format!("{} must be {} or {}", label, yes, no))
+            }
+        }
+        // File references and button values are handled by the handler
+        ModelInputType::File | ModelInputType::Button => Ok(value.clone()),
+    }
+}
+
+/// Checks inputs against their declarations and returns them with numbers coerced
+/// to the declared type. Values without a declaration are passed through.
+pub fn validate_inputs(
+    declared: &[ModelInput],
+    mut values: HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, serde_json::Value>, ChanterelleError> {
+    let mut errors = Vec::new();
+    let mut coerced = Vec::new();
+    for input in declared {
+        let Some(value) = values.get(&input.name).filter(|value| !is_empty(value)) else {
+            if input.required.unwrap_or(false) {
+                errors.push(FieldError { field: input.name.clone(), message: format!("{} is required", input.label) });
+            }
+            continue;
+        };
+        // Constraints follow the raw values, as they do in the form
+        let constraints = effective_constraints(input, &values);
+        match check_value(input, value, constraints.as_ref()) {
+            Ok(value) => coerced.push((input.name.clone(), value)),
+            Err(message) => errors.push(FieldError { field: input.name.clone(), message }),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(ChanterelleError::validation(errors));
+    }
+    values.extend(coerced);
+    Ok(values)
+}
@@ -12,6 +12,7 @@ import { ProjectProvider } from './contexts/ProjectContext';
 import { ThemeProvider } from './contexts/ThemeContext';
 import { SettingsService } from './services/Settings';
 import TitleBar from './components/layout/TitleBar';
+import HostNotifications from './components/common/HostNotifications';
 
 const ScrollToTop: React.FC = () => {
   const location = useLocation();
@@ -94,6 +95,7 @@ const App: React.FC = () => {
         <ProjectProvider>
           <Router>
             <ScrollToTop />
+            <HostNotifications />
             <div className="flex flex-col h-full bg-sky-100 dark:bg-slate-900 text-slate-900 dark:text-slate-100 transition-colors overflow-hidden">
               <TitleBar />
               <main className="flex-1 min-h-0 overflow-auto" id="app-scroll-container">
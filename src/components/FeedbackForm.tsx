@@ -2,30 +2,38 @@ import React, { useState } from 'react';
 import { ThumbsUp, ThumbsDown, Send, Edit2 } from 'lucide-react';
 import { submitFeedback, FeedbackData } from '../services/apis/submitFeedback';
 import { deleteFeedback } from '../services/apis/deleteFeedback';
+import { FeedbackSchemaFields } from './FeedbackSchemaFields';
+import { isChanterelleError } from '../types/ModelError';
+import type { FeedbackSchema } from '../types/ModelMeta';
 
 interface FeedbackFormProps {
     projectName: string;
     context?: any;
+    // Run history entry the feedback is about
+    runId?: string;
+    // Fields declared by the project; replaces the thumbs up/down form
+    schema?: FeedbackSchema | null;
     onFeedbackSubmitted?: () => void;
 }
 
-export const FeedbackForm: React.FC<FeedbackFormProps> = ({ projectName, context, onFeedbackSubmitted }) => {
+export const FeedbackForm: React.FC<FeedbackFormProps> = ({ projectName, context, runId, schema, onFeedbackSubmitted }) => {
     const [rating, setRating] = useState<'up' | 'down' | null>(null);
     const [comment, setComment] = useState('');
     const [submitted, setSubmitted] = useState(false);
     const [loading, setLoading] = useState(false);
-    const [submissionId, setSubmissionId] = useState<number | null>(null);
+    const [submissionId, setSubmissionId] = useState<string | null>(null);
+    const [fields, setFields] = useState<Record<string, any>>({});
+    const [fieldErrors, setFieldErrors] = useState<Record<string, string>>({});
 
     const handleSubmit = async () => {
-        if (!rating) return;
+        if (!schema && !rating) return;
         setLoading(true);
-        const feedback: FeedbackData = {
-            rating,
-            comment,
-            context
-        };
+        setFieldErrors({});
+        const feedback: FeedbackData = schema
+            ? { ...fields, context }
+            : { rating: rating!, comment, context };
         try {
-            const id = await submitFeedback(projectName, feedback);
+            const id = await submitFeedback(projectName, feedback, runId);
             setSubmissionId(id);
             setSubmitted(true);
             if (onFeedbackSubmitted) {
@@ -33,7 +41,11 @@ export const FeedbackForm: React.FC<FeedbackFormProps> = ({ projectName, context
             }
         } catch (e) {
             console.error(e);
-            alert("Failed to submit feedback");
+            if (isChanterelleError(e) && e.code === 'validation' && e.fields) {
+                setFieldErrors(Object.fromEntries(e.fields.map(f => [f.field, f.message])));
+            } else {
+                alert("Failed to submit feedback");
+            }
         } finally {
             setLoading(false);
         }
@@ -77,6 +89,40 @@ export const FeedbackForm: React.FC<FeedbackFormProps> = ({ projectName, context
         );
     }
 
+    if (schema) {
+        return (
+            <div className="mt-3 p-3 border border-gray-200 dark:border-slate-700 rounded-lg bg-gray-50 dark:bg-slate-800/50 space-y-3">
+                <h3 className="text-xs font-medium text-gray-500 dark:text-gray-400">Rate this result</h3>
+                <FeedbackSchemaFields
+                    schema={schema}
+                    values={fields}
+                    errors={fieldErrors}
+                    onChange={(name, value) => {
+                        setFields(prev => ({ ...prev, [name]: value }));
+                        setFieldErrors(prev => {
+                            const { [name]: _, ...rest } = prev;
+                            return rest;
+                        });
+                    }}
+                />
+                <div className="flex justify-end">
+                    <button
+                        onClick={handleSubmit}
+                        disabled={loading}
+                        className="flex items-center gap-2 px-3 py-1.5 bg-blue-600 text-white text-sm rounded-md hover:bg-blue-700 disabled:opacity-50 transition-colors"
+                    >
+                        {loading ? 'Sending...' : (
+                            <>
+                                <Send className="w-3 h-3" />
+                                Submit Feedback
+                            </>
+                        )}
+                    </button>
+                </div>
+            </div>
+        );
+    }
+
     return (
         <div className="mt-3 p-3 border border-gray-200 dark:border-slate-700 rounded-lg bg-gray-50 dark:bg-slate-800/50">
             <div className="flex items-center gap-3">
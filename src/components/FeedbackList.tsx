@@ -29,22 +29,24 @@ export const FeedbackList: React.FC<FeedbackListProps> = ({ history, loading, on
 	return (
 		<div className="space-y-3">
 			{history
-				.slice()
-				.sort((a, b) => b.timestamp - a.timestamp)
 				.map((entry, idx) => {
 					const isUp = entry.feedback?.rating === 'up';
+					const hasThumb = isUp || entry.feedback?.rating === 'down';
+					// Fields declared by a feedback_schema
+					const schemaFields = Object.entries(entry.feedback ?? {})
+						.filter(([key, value]) => !['rating', 'comment', 'context'].includes(key) && value !== null && value !== undefined);
 					const comment = (entry.feedback?.comment || '').trim();
 					const timestampLabel = new Date(entry.timestamp * 1000).toLocaleString();
 
 					return (
 						<div
-							key={`${entry.timestamp}-${idx}`}
+							key={entry.id}
 							className={`rounded-lg border border-gray-200 dark:border-slate-700 bg-white dark:bg-slate-800/60 p-3 relative group transition-colors ${onSelect ? 'cursor-pointer hover:border-blue-300 dark:hover:border-blue-700' : ''}`}
                             onClick={() => onSelect && onSelect(entry)}
 						>
 							<div className="flex items-start justify-between gap-3">
 								<div className="flex items-center gap-2">
-									<span
+									{hasThumb && <span
 										className={`inline-flex items-center justify-center w-7 h-7 rounded-full ${
 											isUp
 												? 'bg-green-100 text-green-700 dark:bg-green-900/30 dark:text-green-300'
@@ -57,7 +59,7 @@ export const FeedbackList: React.FC<FeedbackListProps> = ({ history, loading, on
 										) : (
 											<ThumbsDown className="w-4 h-4" />
 										)}
-									</span>
+									</span>}
 									<div className="text-xs text-gray-500 dark:text-gray-400">
 										{timestampLabel}
 									</div>
@@ -76,6 +78,16 @@ export const FeedbackList: React.FC<FeedbackListProps> = ({ history, loading, on
                                 )}
 							</div>
 
+							{schemaFields.length > 0 && (
+								<div className="mt-2 flex flex-wrap gap-1">
+									{schemaFields.map(([key, value]) => (
+										<span key={key} className="px-2 py-0.5 text-xs rounded-md bg-gray-100 dark:bg-slate-700 text-gray-700 dark:text-gray-300">
+											{key}: {Array.isArray(value) ? value.join(', ') : String(value)}
+										</span>
+									))}
+								</div>
+							)}
+
 							{comment && (
 								<div className="mt-2 text-sm text-gray-800 dark:text-gray-200 whitespace-pre-wrap">
 									{comment}
@@ -0,0 +1,125 @@
+import React from 'react';
+import type { FeedbackField, FeedbackSchema } from '../types/ModelMeta';
+
+interface FeedbackSchemaFieldsProps {
+    schema: FeedbackSchema;
+    values: Record<string, any>;
+    // Messages from the backend validator, by field name
+    errors?: Record<string, string>;
+    onChange: (name: string, value: any) => void;
+}
+
+const chipClass = (selected: boolean) => `px-2 py-1 text-xs rounded-md border transition-colors ${
+    selected
+        ? 'bg-blue-600 border-blue-600 text-white'
+        : 'border-gray-300 dark:border-slate-600 text-gray-700 dark:text-gray-300 hover:bg-gray-200 dark:hover:bg-slate-700'
+}`;
+
+const inputClass = 'w-full text-sm p-2 rounded-md border border-gray-300 dark:border-slate-600 bg-white dark:bg-slate-700 focus:outline-none focus:ring-2 focus:ring-blue-500';
+
+const FieldControl: React.FC<{ field: FeedbackField; value: any; onChange: (value: any) => void }> = ({ field, value, onChange }) => {
+    switch (field.type) {
+        case 'rating': {
+            const min = field.min ?? 1;
+            const max = field.max ?? 5;
+            const steps = Array.from({ length: Math.max(0, max - min + 1) }, (_, i) => min + i);
+            return (
+                <div className="flex flex-wrap gap-1">
+                    {steps.map(step => (
+                        <button key={step} type="button" className={chipClass(value === step)} onClick={() => onChange(value === step ? undefined : step)}>
+                            {step}
+                        </button>
+                    ))}
+                </div>
+            );
+        }
+        case 'label': {
+            const chosen: string[] = field.multiple ? (Array.isArray(value) ? value : []) : (value ? [value] : []);
+            const toggle = (option: string) => {
+                if (!field.multiple) {
+                    onChange(value === option ? undefined : option);
+                } else {
+                    onChange(chosen.includes(option) ? chosen.filter(o => o !== option) : [...chosen, option]);
+                }
+            };
+            return (
+                <div className="flex flex-wrap gap-1">
+                    {field.options.map(option => (
+                        <button key={option} type="button" className={chipClass(chosen.includes(option))} onClick={() => toggle(option)}>
+                            {option}
+                        </button>
+                    ))}
+                </div>
+            );
+        }
+        case 'correction': {
+            const output = field.output;
+            if (output.type === 'boolean') {
+                return (
+                    <select className={inputClass} value={value === undefined ? '' : String(value)}
+                        onChange={e => onChange(e.target.value === '' ? undefined : e.target.value === 'true')}>
+                        <option value="">-</option>
+                        <option value="true">true</option>
+                        <option value="false">false</option>
+                    </select>
+                );
+            }
+            if (output.type === 'string' && output.options) {
+                return (
+                    <select className={inputClass} value={value ?? ''} onChange={e => onChange(e.target.value || undefined)}>
+                        <option value="">-</option>
+                        {output.options.map(option => {
+                            const optionValue = typeof option === 'string' ? option : option.value;
+                            const label = typeof option === 'string' ? option : option.label ?? option.value;
+                            return <option key={optionValue} value={optionValue}>{label}</option>;
+                        })}
+                    </select>
+                );
+            }
+            if (output.type === 'string') {
+                return <input type="text" className={inputClass} value={value ?? ''} onChange={e => onChange(e.target.value || undefined)} />;
+            }
+            return (
+                <input
+                    type="number"
+                    className={inputClass}
+                    min={output.min}
+                    max={output.max}
+                    step={output.type === 'int' ? 1 : 'any'}
+                    value={value ?? ''}
+                    onChange={e => onChange(e.target.value === '' ? undefined : Number(e.target.value))}
+                />
+            );
+        }
+        case 'text':
+            return (
+                <textarea
+                    className={inputClass}
+                    rows={2}
+                    maxLength={field.max_length}
+                    value={value ?? ''}
+                    onChange={e => onChange(e.target.value || undefined)}
+                />
+            );
+    }
+};
+
+// Form fields for a project's feedback_schema
+export const FeedbackSchemaFields: React.FC<FeedbackSchemaFieldsProps> = ({ schema, values, errors, onChange }) => (
+    <div className="space-y-3">
+        {schema.fields.map(field => (
+            <div key={field.name}>
+                <label className="block text-xs font-medium text-gray-600 dark:text-gray-300 mb-1">
+                    {field.label}
+                    {field.required && <span className="text-red-500 ml-1">*</span>}
+                    {field.type === 'correction' && field.output.unit && (
+                        <span className="ml-2 text-gray-400 dark:text-gray-500">({field.output.unit})</span>
+                    )}
+                </label>
+                <FieldControl field={field} value={values[field.name]} onChange={value => onChange(field.name, value)} />
+                {field.description && <p className="mt-1 text-xs text-gray-500 dark:text-gray-400">{field.description}</p>}
+                {errors?.[field.name] && <p className="mt-1 text-xs text-red-600 dark:text-red-400">{errors[field.name]}</p>}
+            </div>
+        ))}
+    </div>
+);
@@ -0,0 +1,76 @@
+import React, { useState } from 'react';
+import { HelpCircle } from 'lucide-react';
+import ModelInputField from './form/ModelInputField';
+import { getInputDefinition } from './form/inputs';
+import { resolveEffectiveConstraints } from '../utils/formUtils';
+import { ModelInputs } from '../types/ModelInputs';
+import { InteractiveRequest } from '../services/apis/respondInteractive';
+
+interface InteractiveRequestCardProps {
+    request: InteractiveRequest;
+    onRespond: (response: any) => void;
+}
+
+export const InteractiveRequestCard: React.FC<InteractiveRequestCardProps> = ({ request, onRespond }) => {
+    const { type, title, message, inputs = [], confirm_label, cancel_label } = request.request;
+    const [submitted, setSubmitted] = useState(false);
+    const [values, setValues] = useState<ModelInputs>(() => {
+        const defaults: ModelInputs = {};
+        inputs.forEach((input) => {
+            const def = getInputDefinition(input.type);
+            defaults[input.name] = def ? def.getDefaultValue(input) : "";
+        });
+        return defaults;
+    });
+
+    const respond = (response: any) => {
+        if (submitted) return;
+        setSubmitted(true);
+        onRespond(response);
+    };
+
+    return (
+        <div className="w-full max-w-4xl rounded-2xl border border-amber-200 dark:border-amber-800/60 bg-amber-50 dark:bg-amber-900/10 p-4">
+            <div className="flex items-start gap-2">
+                <HelpCircle size={18} className="mt-0.5 shrink-0 text-amber-600 dark:text-amber-400" />
+                <div className="min-w-0 flex-1">
+                    {title && <div className="font-medium text-slate-900 dark:text-slate-100">{title}</div>}
+                    {message && <div className="text-sm text-slate-700 dark:text-slate-300 whitespace-pre-wrap">{message}</div>}
+                </div>
+            </div>
+
+            {type === "input" && inputs.length > 0 && (
+                <div className="mt-4 space-y-4">
+                    {inputs.map((input) => (
+                        <ModelInputField
+                            key={input.name}
+                            input={input}
+                            value={values[input.name]}
+                            constraints={resolveEffectiveConstraints(input, values)}
+                            onChange={(name, value) => setValues((prev) => ({ ...prev, [name]: value }))}
+                        />
+                    ))}
+                </div>
+            )}
+
+            <div className="mt-4 flex items-center justify-end gap-2">
+                <button
+                    type="button"
+                    onClick={() => respond(type === "confirm" ? false : null)}
+                    disabled={submitted}
+                    className="px-3 py-1.5 rounded-lg border border-gray-200 dark:border-slate-600 text-sm text-slate-700 dark:text-slate-200 hover:bg-gray-100 dark:hover:bg-slate-700 disabled:opacity-50"
+                >
+                    {cancel_label || (type === "confirm" ? "Deny" : "Skip")}
+                </button>
+                <button
+                    type="button"
+                    onClick={() => respond(type === "confirm" ? true : values)}
+                    disabled={submitted}
+                    className="px-3 py-1.5 rounded-lg bg-slate-900 text-white text-sm hover:bg-slate-700 disabled:opacity-50 dark:bg-slate-100 dark:text-slate-900 dark:hover:bg-slate-200"
+                >
+                    {confirm_label || (type === "confirm" ? "Approve" : "Submit")}
+                </button>
+            </div>
+        </div>
+    );
+};
@@ -0,0 +1,83 @@
+import React from 'react';
+import { CheckCircle2, Trash2, XCircle } from 'lucide-react';
+import type { HistoryEntry } from '../services/apis/runHistory';
+
+interface RunHistoryListProps {
+    entries: HistoryEntry[];
+    loading?: boolean;
+    onDelete?: (entry: HistoryEntry) => void;
+    onSelect?: (entry: HistoryEntry) => void;
+}
+
+function summarizeInputs(inputs: Record<string, any>): string {
+    return Object.entries(inputs)
+        .map(([name, value]) => `${name}: ${typeof value === 'object' ? JSON.stringify(value) : String(value)}`)
+        .join(', ');
+}
+
+export const RunHistoryList: React.FC<RunHistoryListProps> = ({ entries, loading, onDelete, onSelect }) => {
+    if (loading) {
+        return (
+            <div className="text-sm text-gray-600 dark:text-gray-300">
+                Loading runs...
+            </div>
+        );
+    }
+
+    if (!entries || entries.length === 0) {
+        return (
+            <div className="text-sm text-gray-600 dark:text-gray-300">
+                No runs yet.
+            </div>
+        );
+    }
+
+    return (
+        <div className="space-y-3">
+            {entries.map(entry => {
+                const failed = entry.error !== null;
+                return (
+                    <div
+                        key={entry.id}
+                        className={`rounded-lg border border-gray-200 dark:border-slate-700 bg-white dark:bg-slate-800/60 p-3 relative group transition-colors ${onSelect ? 'cursor-pointer hover:border-blue-300 dark:hover:border-blue-700' : ''}`}
+                        onClick={() => onSelect && onSelect(entry)}
+                    >
+                        <div className="flex items-start justify-between gap-3">
+                            <div className="flex items-center gap-2">
+                                {failed ? (
+                                    <XCircle className="w-4 h-4 text-red-500" />
+                                ) : (
+                                    <CheckCircle2 className="w-4 h-4 text-green-600" />
+                                )}
+                                <div className="text-xs text-gray-500 dark:text-gray-400">
+                                    {new Date(entry.timestamp).toLocaleString()} · {entry.latency_ms} ms
+                                    {entry.model_version ? ` · v${entry.model_version}` : ''}
+                                </div>
+                            </div>
+                            {onDelete && (
+                                <button
+                                    onClick={(e) => {
+                                        e.stopPropagation();
+                                        onDelete(entry);
+                                    }}
+                                    className="text-gray-400 hover:text-red-500 dark:text-gray-500 dark:hover:text-red-400 opacity-0 group-hover:opacity-100 transition-opacity p-1"
+                                    title="Delete run"
+                                >
+                                    <Trash2 className="w-4 h-4" />
+                                </button>
+                            )}
+                        </div>
+                        <div className="mt-2 text-xs text-gray-700 dark:text-gray-300 truncate" title={summarizeInputs(entry.inputs)}>
+                            {summarizeInputs(entry.inputs)}
+                        </div>
+                        {failed && (
+                            <div className="mt-1 text-xs text-red-600 dark:text-red-400 truncate">
+                                {entry.error?.message}
+                            </div>
+                        )}
+                    </div>
+                );
+            })}
+        </div>
+    );
+};
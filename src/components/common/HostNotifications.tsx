@@ -0,0 +1,23 @@
+import React, { useEffect } from 'react';
+import { useNotificationContext } from '../../contexts/NotificationContext';
+import { listenHostNotifications } from '../../services/apis/hostServices';
+
+// Shows notifications that handlers raise through chanterelle_host.notify()
+const HostNotifications: React.FC = () => {
+  const { showNotification } = useNotificationContext();
+
+  useEffect(() => {
+    const unlistenPromise = listenHostNotifications(({ title, body, level }) => {
+      const message = body ? `${title}: ${body}` : title;
+      const type = ['success', 'error', 'warning', 'info'].includes(level) ? level : 'info';
+      showNotification(message, type, type !== 'error');
+    });
+    return () => {
+      void unlistenPromise.then((unlisten) => unlisten());
+    };
+  }, [showNotification]);
+
+  return null;
+};
+
+export default HostNotifications;
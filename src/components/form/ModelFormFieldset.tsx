@@ -11,6 +11,7 @@ interface ModelFormFieldsetProps {
     };
     values: ModelInputs;
     presetSelections: { [presetName: string]: string };
+    fieldErrors?: { [inputName: string]: string };
     handleChange: (name: string, value: any) => void;
     handlePresetChange: (preset: ModelInputPreset, selectedName: string) => void;
 }
@@ -19,6 +20,7 @@ const ModelFormFieldset: React.FC<ModelFormFieldsetProps> = ({
     group,
     values,
     presetSelections,
+    fieldErrors,
     handleChange,
     handlePresetChange
 }) => {
@@ -35,6 +37,7 @@ const ModelFormFieldset: React.FC<ModelFormFieldsetProps> = ({
                             input={entry.item}
                             value={values[entry.item.name]}
                             constraints={resolveEffectiveConstraints(entry.item, values)}
+                            error={fieldErrors?.[entry.item.name]}
                             onChange={handleChange}
                         />
                     ) : (
@@ -6,10 +6,12 @@ interface ModelInputFieldProps {
     input: ModelInput;
     value: any;
     constraints?: ModelInputConstraint;
+    // Message from the backend validator for this input
+    error?: string;
     onChange: (name: string, value: any) => void;
 }
 
-const ModelInputField: React.FC<ModelInputFieldProps> = ({ input, value, constraints, onChange }) => {
+const ModelInputField: React.FC<ModelInputFieldProps> = ({ input, value, constraints, error, onChange }) => {
     const Component = getInputComponent(input.type);
 
     if (input.type === "boolean") {
@@ -36,6 +38,9 @@ const ModelInputField: React.FC<ModelInputFieldProps> = ({ input, value, constra
                 {input.description && (
                     <span className="text-xs text-gray-500 dark:text-gray-400 mt-1">{input.description}</span>
                 )}
+                {error && (
+                    <span className="text-xs text-red-500 mt-1">{error}</span>
+                )}
             </div>
         );
     }
@@ -62,6 +67,9 @@ const ModelInputField: React.FC<ModelInputFieldProps> = ({ input, value, constra
             {input.description && (
                 <span className="text-xs text-gray-500 dark:text-gray-400 mt-1">{input.description}</span>
             )}
+            {error && (
+                <span className="text-xs text-red-500 mt-1">{error}</span>
+            )}
         </div>
     );
 };
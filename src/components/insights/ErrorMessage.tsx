@@ -1,17 +1,50 @@
 import { AlertTriangle } from "lucide-react";
+import { useParams } from "react-router";
 import { BaseItem } from "./BaseItem";
+import type { TracebackFrame } from "../../types/ModelError";
+import { openInEditor } from "../../services/apis/openInEditor";
 
 // Item type
 export interface ErrorMessageItem extends BaseItem {
     // type: 'error';
     error: string;
+    error_type?: string;
+    stage?: string;
+    traceback?: TracebackFrame[];
 }
 
 // Error message component
-export const ErrorMessageComponent: React.FC<ErrorMessageItem> = ({ error }) => {
+export const ErrorMessageComponent: React.FC<ErrorMessageItem> = ({ error, stage, traceback }) => {
+    const { modelId } = useParams<{ modelId: string }>(); // project name on model/interactive pages
     return (
         <div className="bg-red-100 dark:bg-red-900/30 border border-red-300 dark:border-red-700 rounded-md p-3 mb-2">
             <p className="text-red-700 dark:text-red-200 font-medium leading-relaxed">{error}</p>
+            {traceback && traceback.length > 0 && (
+                <details className="mt-2 text-sm text-red-700 dark:text-red-200">
+                    <summary className="cursor-pointer select-none">
+                        Traceback{stage ? ` (${stage})` : ""}
+                    </summary>
+                    <ol className="mt-1 space-y-1 font-mono text-xs">
+                        {traceback.map((frame, i) => (
+                            <li key={i}>
+                                {modelId && frame.path ? (
+                                    <button
+                                        type="button"
+                                        onClick={() => openInEditor(modelId, frame.path!, frame.line).catch(() => {})}
+                                        className="underline hover:text-red-900 dark:hover:text-red-100"
+                                        title="Open in editor"
+                                    >
+                                        {frame.file}:{frame.line} in {frame.function}
+                                    </button>
+                                ) : (
+                                    <div>{frame.file}:{frame.line} in {frame.function}</div>
+                                )}
+                                <div className="pl-4 opacity-80">{frame.code}</div>
+                            </li>
+                        ))}
+                    </ol>
+                </details>
+            )}
           </div>
     );
 };
@@ -3,6 +3,7 @@ import { useParams, useNavigate } from "react-router";
 import { ArrowLeft, RotateCcw } from "lucide-react";
 import LoadSpinner from "../../components/common/LoadSpinner";
 import ErrorBoundary from "../../components/common/ErrorBoundary";
+import { errorMessage } from "../../types/ModelError";
 import AnalyticsInsights from "./AnalyticsInsights";
 import { getAnalyticsDetails, AnalyticsDetails } from "../../services/apis/getAnalyticsDetails";
 import { useProjectContext } from "../../contexts/ProjectContext";
@@ -26,7 +27,7 @@ const AnalyticsPage: React.FC = () => {
                     setDetails(data);
                     setProjectPath(data.project_path);
                 })
-                .catch((err) => setError(String(err)))
+                .catch((err) => setError(errorMessage(err)))
                 .finally(() => setLoading(false));
         }
     };
@@ -1,20 +1,24 @@
 import React, { useState, useEffect, useRef, useCallback } from "react";
 import { useParams, useNavigate } from "react-router";
 import { invokeInteractive, InteractiveOutput } from "../../services/apis/invokeInteractive";
-import { ModelInput } from "../../types/ModelMeta";
+import { ModelInput, FeedbackSchema } from "../../types/ModelMeta";
 import { ModelInputs } from "../../types/ModelInputs";
 import { ProjectMeta } from "../../types/Project";
 import { SectionComponent as Section } from "../../components/insights";
 import ModelInputField from "../../components/form/ModelInputField";
 import { getInputDefinition } from "../../components/form/inputs";
-import { Send, RefreshCw, AlertCircle, ArrowLeft, RotateCcw, History, ChevronRight } from "lucide-react";
+import { Send, RefreshCw, AlertCircle, ArrowLeft, RotateCcw, History, ChevronRight, Plus, X } from "lucide-react";
 import { warmModel } from "../../services/apis/warmModel";
 import { resolveEffectiveConstraints } from "../../utils/formUtils";
 import { forceKillPython } from "../../services/apis/forceKillPython";
 import { stopInteractive } from "../../services/apis/stopInteractive";
+import { closeInteractiveSession } from "../../services/apis/closeInteractiveSession";
 import { FeedbackForm } from "../../components/FeedbackForm";
+import { InteractiveRequestCard } from "../../components/InteractiveRequestCard";
+import { listenInteractiveRequests, respondInteractive, InteractiveRequest } from "../../services/apis/respondInteractive";
 import { FeedbackList } from "../../components/FeedbackList";
-import { getFeedbackHistory, FeedbackEntry } from "../../services/apis/getFeedbackHistory";
+import type { FeedbackEntry } from "../../services/apis/getFeedbackHistory";
+import { queryFeedback } from "../../services/apis/queryFeedback";
 import { deleteFeedback } from "../../services/apis/deleteFeedback";
 import { updateFeedback } from "../../services/apis/updateFeedback";
 // import { getModelMeta } from "../../services/apis/getModelMeta";
@@ -34,6 +38,17 @@ type ConversationTurn = {
     completedAt?: number;
 };
 
+// What we keep of a chat while another one is shown
+type SessionSnapshot = {
+    history: ConversationTurn[];
+    currentFormInputs: ModelInput[] | null;
+    inputValues: ModelInputs;
+    wasStopped: boolean;
+    feedbackId: string | null;
+};
+
+type ChatTab = { id: string; label: string };
+
 type UserDisplay =
     | { kind: "text"; text: string }
     | { kind: "fields"; fields: Array<{ label: string; value: string }> };
@@ -57,6 +72,7 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
     const [initializing, setInitializing] = useState(true);
     const [error, setError] = useState<string | null>(null);
     const [composerDockHeight, setComposerDockHeight] = useState(220);
+    const [pendingRequest, setPendingRequest] = useState<InteractiveRequest | null>(null);
     const unlistenFnRef = useRef<Function | null>(null);
     const currentRequestIdRef = useRef<string | null>(null);
     const feedbackRestoreRequestRef = useRef<string | null>(null);
@@ -67,22 +83,42 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
     const composerTextareaRef = useRef<HTMLTextAreaElement>(null);
     const composerDockRef = useRef<HTMLDivElement>(null);
 
+    // Chats share one handler process; each has its own session state on the Python side
+    const sessionIdRef = useRef<string>(crypto.randomUUID());
+    const chatCounterRef = useRef(1);
+    const sessionSnapshotsRef = useRef(new Map<string, SessionSnapshot>());
+    const [chats, setChats] = useState<ChatTab[]>(() => [{ id: sessionIdRef.current, label: "Chat 1" }]);
+    const [activeSessionId, setActiveSessionId] = useState(sessionIdRef.current);
+
     // Feedback State
     const [allowFeedback, setAllowFeedback] = useState(false);
+    const [feedbackSchema, setFeedbackSchema] = useState<FeedbackSchema | null>(null);
+    const FEEDBACK_PAGE_SIZE = 20;
     const [feedbackHistory, setFeedbackHistory] = useState<FeedbackEntry[]>([]);
+    const [feedbackCursor, setFeedbackCursor] = useState<string | null>(null);
+    const [feedbackFilter, setFeedbackFilter] = useState("");
     const [showFeedbackHistory, setShowFeedbackHistory] = useState(false);
     const [loadingHistory, setLoadingHistory] = useState(false);
-    const activeFeedbackTimestampRef = useRef<number | null>(null);
-
-    const refreshFeedbackHistory = useCallback(() => {
-        if (allowFeedback && modelId) {
-            setLoadingHistory(true);
-            getFeedbackHistory(modelId).then(h => {
-                setFeedbackHistory(h);
-                setLoadingHistory(false);
+    const activeFeedbackIdRef = useRef<string | null>(null);
+
+    // Without a cursor, reloads the first page
+    const refreshFeedbackHistory = useCallback(async (cursor?: string) => {
+        if (!allowFeedback || !modelId) return;
+        setLoadingHistory(true);
+        try {
+            const page = await queryFeedback(modelId, {
+                text: feedbackFilter || undefined,
+                cursor,
+                limit: FEEDBACK_PAGE_SIZE,
             });
+            setFeedbackHistory(prev => cursor ? [...prev, ...page.entries] : page.entries);
+            setFeedbackCursor(page.next_cursor);
+        } catch (e) {
+            console.error('Error loading feedback history:', e);
+        } finally {
+            setLoadingHistory(false);
         }
-    }, [allowFeedback, modelId]);
+    }, [allowFeedback, modelId, feedbackFilter]);
 
     useEffect(() => {
         refreshFeedbackHistory();
@@ -90,16 +126,15 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
 
     const [highlightedTurnId, setHighlightedTurnId] = useState<string | null>(null);
 
+    // Removes the entry in place so the pages already loaded stay
     const handleDeleteFeedback = async (entry: FeedbackEntry) => {
         if (!modelId) return;
-        setLoadingHistory(true);
         try {
-            await deleteFeedback(modelId, entry.timestamp);
-            refreshFeedbackHistory();
+            await deleteFeedback(modelId, entry.id);
+            setFeedbackHistory(prev => prev.filter(f => f.id !== entry.id));
         } catch (e) {
             console.error(e);
             alert("Failed to delete feedback");
-            setLoadingHistory(false);
         }
     };
 
@@ -132,7 +167,7 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
         const focusTurn = [...turns].reverse().find((t) => t.type === "agent") || turns[turns.length - 1];
 
         // Track which feedback entry is active so new turns get saved back to it
-        activeFeedbackTimestampRef.current = entry.timestamp;
+        activeFeedbackIdRef.current = entry.id;
 
         stickToBottomRef.current = true;
         setHistory(turns);
@@ -150,7 +185,7 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
 
         // Pass the raw session turns so the base handler can extract conversation_history
         const rawTurns = Array.isArray(ctx.sessionTurns) ? ctx.sessionTurns : turns;
-        invokeInteractive(modelId, {}, handleOutput, requestId, rawTurns)
+        invokeInteractive(modelId, {}, handleOutput, requestId, rawTurns, sessionIdRef.current)
             .then((unlisten) => { unlistenFnRef.current = unlisten; })
             .catch((e: any) => {
                 setError(e.toString());
@@ -200,8 +235,9 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
                 if (warmRes.allow_feedback) {
                     setAllowFeedback(true);
                 }
+                setFeedbackSchema(warmRes.feedback_schema ?? null);
                 if (!warmRes.warmup) {
-                    throw new Error(warmRes.error || "Failed to start agent process");
+                    throw new Error(warmRes.error?.message || "Failed to start agent process");
                 }
 
                 // 2. Start Session automatically
@@ -223,6 +259,26 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
         };
     }, [modelId]);
 
+    // Mid-turn confirmations/input requests from the handler for the running turn
+    useEffect(() => {
+        const unlistenPromise = listenInteractiveRequests((request) => {
+            if (request.request_id && request.request_id !== currentRequestIdRef.current) return;
+            setPendingRequest(request);
+        });
+        return () => {
+            void unlistenPromise.then((unlisten) => unlisten());
+        };
+    }, []);
+
+    const handleRespondToRequest = (response: any) => {
+        const request = pendingRequest;
+        if (!request) return;
+        setPendingRequest(null);
+        respondInteractive(request.interaction_id, response).catch((e: any) => {
+            setError(e?.message || String(e));
+        });
+    };
+
     const isNearBottom = (): boolean => {
         const container = historyContainerRef.current;
         if (!container) return true;
@@ -275,13 +331,14 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
 
     // Auto-save conversation back to the active feedback entry when a turn completes
     useEffect(() => {
-        const ts = activeFeedbackTimestampRef.current;
-        if (!ts || !modelId || processing || history.length === 0) return;
+        const feedbackId = activeFeedbackIdRef.current;
+        if (!feedbackId || !modelId || processing || history.length === 0) return;
         // Only save when the last agent turn is completed
         const lastAgent = [...history].reverse().find((t) => t.type === "agent");
         if (!lastAgent?.completedAt) return;
 
         const context = {
+            sessionId: sessionIdRef.current,
             sessionTurns: history,
             inputs: (() => {
                 const userTurns = history.filter((t: ConversationTurn) => t.type === "user");
@@ -289,7 +346,7 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
             })(),
             outputs: lastAgent.content,
         };
-        updateFeedback(modelId, ts, context).then(() => {
+        updateFeedback(modelId, feedbackId, context).then(() => {
             refreshFeedbackHistory();
         }).catch((e) => {
             console.error("Failed to auto-save feedback:", e);
@@ -320,6 +377,7 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
         }
 
         if (data.stopped) {
+            setPendingRequest(null);
             setProcessing(false);
             setStopping(false);
             setCurrentFormInputs(null);
@@ -490,7 +548,7 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
         if (unlistenFnRef.current) unlistenFnRef.current();
 
         try {
-            const unlisten = await invokeInteractive(modelId, inputs, handleOutput, requestId);
+            const unlisten = await invokeInteractive(modelId, inputs, handleOutput, requestId, undefined, sessionIdRef.current);
             unlistenFnRef.current = unlisten;
         } catch (e: any) {
             setError(e.toString());
@@ -520,16 +578,25 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
 
         // Reset UI state
         setError(null);
+        setPendingRequest(null);
         setHistory([]);
         setCurrentFormInputs(null);
         setInputValues({});
         setProcessing(false);
         setWasStopped(false);
         currentRequestIdRef.current = null;
-        activeFeedbackTimestampRef.current = null;
+        activeFeedbackIdRef.current = null;
         stickToBottomRef.current = true;
         requestAnimationFrame(() => scrollHistoryToBottom("instant"));
 
+        // Restarting the process drops every chat's state, so start over with a single chat
+        const sessionId = crypto.randomUUID();
+        sessionIdRef.current = sessionId;
+        chatCounterRef.current = 1;
+        sessionSnapshotsRef.current.clear();
+        setChats([{ id: sessionId, label: "Chat 1" }]);
+        setActiveSessionId(sessionId);
+
         setInitializing(true);
         try {
             await forceKillPython();
@@ -537,8 +604,9 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
             if (warmRes.allow_feedback) {
                 setAllowFeedback(true);
             }
+            setFeedbackSchema(warmRes.feedback_schema ?? null);
             if (!warmRes.warmup) {
-                throw new Error(warmRes.error || "Failed to start agent process");
+                throw new Error(warmRes.error?.message || "Failed to start agent process");
             }
 
             await startTurn({});
@@ -549,6 +617,66 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
         }
     };
 
+    const snapshotActiveChat = () => {
+        sessionSnapshotsRef.current.set(sessionIdRef.current, {
+            history,
+            currentFormInputs,
+            inputValues,
+            wasStopped,
+            feedbackId: activeFeedbackIdRef.current,
+        });
+    };
+
+    const switchChat = (sessionId: string) => {
+        if (processing || initializing || sessionId === sessionIdRef.current) return;
+        const snapshot = sessionSnapshotsRef.current.get(sessionId);
+        if (!snapshot) return;
+
+        snapshotActiveChat();
+        sessionSnapshotsRef.current.delete(sessionId);
+        sessionIdRef.current = sessionId;
+        setActiveSessionId(sessionId);
+        setHistory(snapshot.history);
+        setCurrentFormInputs(snapshot.currentFormInputs);
+        setInputValues(snapshot.inputValues);
+        setWasStopped(snapshot.wasStopped);
+        activeFeedbackIdRef.current = snapshot.feedbackId;
+        setError(null);
+        stickToBottomRef.current = true;
+        requestAnimationFrame(() => scrollHistoryToBottom("instant"));
+    };
+
+    const startNewChat = async () => {
+        if (processing || initializing) return;
+
+        snapshotActiveChat();
+        const sessionId = crypto.randomUUID();
+        chatCounterRef.current += 1;
+        sessionIdRef.current = sessionId;
+        setChats((prev) => [...prev, { id: sessionId, label: `Chat ${chatCounterRef.current}` }]);
+        setActiveSessionId(sessionId);
+        setHistory([]);
+        setCurrentFormInputs(null);
+        setInputValues({});
+        setWasStopped(false);
+        setError(null);
+        activeFeedbackIdRef.current = null;
+
+        await startTurn({});
+    };
+
+    const closeChat = async (sessionId: string) => {
+        if (processing || initializing || chats.length <= 1) return;
+
+        const remaining = chats.filter((chat) => chat.id !== sessionId);
+        if (sessionId === sessionIdRef.current) {
+            switchChat(remaining[remaining.length - 1].id);
+        }
+        sessionSnapshotsRef.current.delete(sessionId);
+        setChats(remaining);
+        await closeInteractiveSession(sessionId);
+    };
+
     const handleBackToCatalog = async () => {
         if (unlistenFnRef.current) {
             try {
@@ -584,6 +712,7 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
             }
         }
         currentRequestIdRef.current = null;
+        setPendingRequest(null);
         setProcessing(false);
         setStopping(false);
         setCurrentFormInputs(null);
@@ -760,6 +889,50 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
                 </div>
             </div>
             
+            {(chats.length > 1 || history.length > 0) && (
+                <div className="px-6 pb-2 flex items-center gap-2 overflow-x-auto">
+                    {chats.map((chat) => (
+                        <div
+                            key={chat.id}
+                            className={`inline-flex items-center gap-1 rounded-lg border px-3 py-1 text-sm ${
+                                chat.id === activeSessionId
+                                    ? 'border-blue-300 dark:border-blue-700 bg-blue-50 dark:bg-blue-900/20 text-blue-700 dark:text-blue-300'
+                                    : 'border-gray-200 dark:border-slate-700 text-slate-600 dark:text-slate-300'
+                            }`}
+                        >
+                            <button
+                                type="button"
+                                onClick={() => switchChat(chat.id)}
+                                disabled={processing || initializing}
+                                className="disabled:cursor-not-allowed"
+                            >
+                                {chat.label}
+                            </button>
+                            {chats.length > 1 && (
+                                <button
+                                    type="button"
+                                    onClick={() => closeChat(chat.id)}
+                                    disabled={processing || initializing}
+                                    className="text-gray-400 hover:text-gray-600 dark:hover:text-gray-200 disabled:cursor-not-allowed"
+                                    title="Close chat"
+                                >
+                                    <X size={14} />
+                                </button>
+                            )}
+                        </div>
+                    ))}
+                    <button
+                        type="button"
+                        onClick={startNewChat}
+                        disabled={processing || initializing}
+                        className="inline-flex items-center gap-1 rounded-lg px-2 py-1 text-sm text-gray-500 hover:text-gray-700 dark:hover:text-gray-200 disabled:cursor-not-allowed"
+                        title="New chat"
+                    >
+                        <Plus size={14} /> New chat
+                    </button>
+                </div>
+            )}
+
             <div className="flex-1 min-h-0 flex overflow-hidden">
             <main ref={historyContainerRef} className="flex-1 min-h-0 overflow-y-auto overflow-x-hidden">
                 <div
@@ -832,10 +1005,12 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
                                 {allowFeedback && modelId && msg.type === "agent" && msg.completedAt && idx === history.length - 1 && !processing && (
                                     <FeedbackForm
                                         projectName={modelId}
+                                        schema={feedbackSchema}
                                         context={{
                                             inputs: idx > 0 && history[idx - 1]?.type === "user" ? history[idx - 1].content : undefined,
                                             outputs: msg.content,
                                             sessionTurns: history,
+                                            sessionId: sessionIdRef.current,
                                         }}
                                         onFeedbackSubmitted={() => {
                                             refreshFeedbackHistory();
@@ -854,7 +1029,15 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
                         </div>
                     )}
 
-                    {processing && !currentFormInputs && (
+                    {pendingRequest && (
+                        <InteractiveRequestCard
+                            key={pendingRequest.interaction_id}
+                            request={pendingRequest}
+                            onRespond={handleRespondToRequest}
+                        />
+                    )}
+
+                    {processing && !currentFormInputs && !pendingRequest && (
                         <div className="w-full max-w-4xl">
                             <div className="flex items-center gap-2 text-gray-500 animate-pulse px-1">
                                 <RefreshCw className="animate-spin" size={16} />
@@ -889,13 +1072,32 @@ const InteractivePage: React.FC<InteractivePageProps> = () => {
                             <ChevronRight className="w-5 h-5" />
                         </button>
                     </div>
+                    <div className="p-2 border-b border-gray-200 dark:border-slate-700">
+                        <input
+                            type="search"
+                            value={feedbackFilter}
+                            onChange={e => setFeedbackFilter(e.target.value)}
+                            placeholder="Search comments"
+                            className="w-full px-2 py-1 text-sm rounded border border-gray-300 dark:border-slate-600 bg-white dark:bg-slate-800 text-gray-900 dark:text-gray-100"
+                        />
+                    </div>
                     <div className="p-4 flex-1 min-h-0 overflow-y-auto overscroll-contain">
                         <FeedbackList
                             history={feedbackHistory}
-                            loading={loadingHistory}
+                            loading={loadingHistory && feedbackHistory.length === 0}
                             onDelete={handleDeleteFeedback}
                             onSelect={handleSelectFeedback}
                         />
+                        {feedbackCursor && (
+                            <button
+                                type="button"
+                                disabled={loadingHistory}
+                                onClick={() => refreshFeedbackHistory(feedbackCursor)}
+                                className="mt-3 w-full text-sm text-blue-600 dark:text-blue-400 hover:underline disabled:opacity-50"
+                            >
+                                {loadingHistory ? "Loading..." : "Load more"}
+                            </button>
+                        )}
                     </div>
                 </div>
             )}
@@ -1,13 +1,19 @@
 import React, { useState } from "react";
-import { invokeModel } from "../../services/apis/invokeModel";
+import { invokeModel, handlerErrorSections, contractWarningSection } from "../../services/apis/invokeModel";
 import { ModelMeta, ModelInput, ModelInputPreset, ModelInputGrouping } from "../../types/ModelMeta";
 import { ModelInputs } from "../../types/ModelInputs";
 import { SectionType, SectionComponent } from "../../components/insights";
 import { FeedbackForm } from "../../components/FeedbackForm";
 import { FeedbackList } from "../../components/FeedbackList";
-import { getFeedbackHistory, FeedbackEntry } from "../../services/apis/getFeedbackHistory";
+import type { FeedbackEntry } from "../../services/apis/getFeedbackHistory";
+import { queryFeedback } from "../../services/apis/queryFeedback";
 import { deleteFeedback } from "../../services/apis/deleteFeedback";
-import { Bot, ChevronRight, History, Square } from "lucide-react";
+import { getFeedbackSummary } from "../../services/apis/getFeedbackSummary";
+import { RunHistoryList } from "../../components/RunHistoryList";
+import { queryHistory, deleteHistoryEntries, HistoryEntry } from "../../services/apis/runHistory";
+import { replayHistory } from "../../services/apis/replayHistory";
+import { errorMessage, isChanterelleError } from "../../types/ModelError";
+import { Bot, ChartColumn, ChevronRight, Clock, History, RotateCcw, Square } from "lucide-react";
 import { useParams } from "react-router";
 import { ModelFormFieldset } from "../../components/form";
 import { getInputDefinition } from "../../components/form/inputs";
@@ -31,39 +37,147 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
     );
     const [presetSelections, setPresetSelections] = useState<{ [presetName: string]: string }>({});
     const [result, setResult] = useState<SectionType[] | null>(null);
+    // Per-input messages from the backend validator, cleared as inputs change
+    const [fieldErrors, setFieldErrors] = useState<{ [inputName: string]: string }>({});
     const [predictLoading, setPredictLoading] = useState(false);
     const [predictionKey, setPredictionKey] = useState(0);
+    // Run history entry of the shown result, which feedback is linked to
+    const [runId, setRunId] = useState<string | undefined>(undefined);
     const requestSeqRef = React.useRef(0);
     
     // Feedback state
+    const FEEDBACK_PAGE_SIZE = 20;
     const [feedbackHistory, setFeedbackHistory] = useState<FeedbackEntry[]>([]);
+    const [feedbackTotal, setFeedbackTotal] = useState(0);
+    const [feedbackCursor, setFeedbackCursor] = useState<string | null>(null);
+    const [feedbackFilter, setFeedbackFilter] = useState("");
     const [showFeedbackHistory, setShowFeedbackHistory] = useState(false);
     const [loadingHistory, setLoadingHistory] = useState(false);
 
-    const refreshHistory = React.useCallback(() => {
-        if (model.allow_feedback && modelId) {
-            setLoadingHistory(true);
-            getFeedbackHistory(modelId).then(history => {
-                setFeedbackHistory(history);
-                setLoadingHistory(false);
+    // Without a cursor, reloads the first page
+    const refreshHistory = React.useCallback(async (cursor?: string) => {
+        if (!model.allow_feedback || !modelId) return;
+        setLoadingHistory(true);
+        try {
+            const page = await queryFeedback(modelId, {
+                text: feedbackFilter || undefined,
+                cursor,
+                limit: FEEDBACK_PAGE_SIZE,
             });
+            setFeedbackHistory(prev => cursor ? [...prev, ...page.entries] : page.entries);
+            setFeedbackTotal(page.total);
+            setFeedbackCursor(page.next_cursor);
+        } catch (e) {
+            console.error('Error loading feedback history:', e);
+        } finally {
+            setLoadingHistory(false);
         }
-    }, [model.allow_feedback, modelId]);
+    }, [model.allow_feedback, modelId, feedbackFilter]);
 
     React.useEffect(() => {
         refreshHistory();
     }, [refreshHistory]);
 
+    // Run history state (every prediction is recorded by the backend)
+    const RUNS_PAGE_SIZE = 20;
+    const [runs, setRuns] = useState<HistoryEntry[]>([]);
+    const [runsTotal, setRunsTotal] = useState(0);
+    const [runsFilter, setRunsFilter] = useState("");
+    const [showRunHistory, setShowRunHistory] = useState(false);
+    const [loadingRuns, setLoadingRuns] = useState(false);
+    const [replayProgress, setReplayProgress] = useState<string | null>(null);
+
+    const loadRuns = React.useCallback(async (offset: number) => {
+        if (!modelId) return;
+        setLoadingRuns(true);
+        try {
+            const page = await queryHistory(modelId, {
+                text: runsFilter || undefined,
+                offset,
+                limit: RUNS_PAGE_SIZE,
+            });
+            setRuns(prev => offset === 0 ? page.entries : [...prev, ...page.entries]);
+            setRunsTotal(page.total);
+        } catch (e) {
+            console.error('Error loading run history:', e);
+        } finally {
+            setLoadingRuns(false);
+        }
+    }, [modelId, runsFilter]);
+
+    React.useEffect(() => {
+        if (showRunHistory) loadRuns(0);
+    }, [showRunHistory, loadRuns]);
+
+    const handleDeleteRun = async (entry: HistoryEntry) => {
+        if (!modelId) return;
+        try {
+            await deleteHistoryEntries(modelId, [entry.id]);
+            setRuns(prev => prev.filter(r => r.id !== entry.id));
+            setRunsTotal(t => Math.max(0, t - 1));
+        } catch (e) {
+            console.error(e);
+            alert("Failed to delete run");
+        }
+    };
+
+    // Re-runs the listed runs through the current handler and shows what changed
+    const handleReplayRuns = async () => {
+        if (!modelId || runsTotal === 0) return;
+        setReplayProgress("Starting...");
+        try {
+            const report = await replayHistory(
+                modelId,
+                { source: { type: 'history', query: { text: runsFilter || undefined } } },
+                (done, total) => setReplayProgress(`${done}/${total}`),
+            );
+            setResult(report.sections);
+        } catch (e) {
+            console.error('Error replaying runs:', e);
+            alert(`Replay failed: ${errorMessage(e)}`);
+        } finally {
+            setReplayProgress(null);
+        }
+    };
+
+    const handleSelectRun = (entry: HistoryEntry) => {
+        setValues(entry.inputs);
+        setPresetSelections({});
+        setPredictionKey(k => k + 1);
+        setRunId(entry.id);
+        if (Array.isArray(entry.outputs)) {
+            setResult(entry.outputs);
+        } else if (entry.error) {
+            setResult(handlerErrorSections(entry.error.message, {
+                error_type: entry.error.error_type,
+                stage: entry.error.stage,
+                traceback: entry.error.traceback,
+            }));
+        } else if (entry.outputs?.error) {
+            setResult(handlerErrorSections(entry.outputs.error));
+        }
+    };
+
+    // Removes the entry in place so the pages already loaded stay
     const handleDeleteFeedback = async (entry: FeedbackEntry) => {
         if (!modelId) return;
-        setLoadingHistory(true);
         try {
-            await deleteFeedback(modelId, entry.timestamp);
-            refreshHistory();
+            await deleteFeedback(modelId, entry.id);
+            setFeedbackHistory(prev => prev.filter(f => f.id !== entry.id));
+            setFeedbackTotal(t => Math.max(0, t - 1));
         } catch (e) {
             console.error(e);
             alert("Failed to delete feedback");
-            setLoadingHistory(false);
+        }
+    };
+
+    const handleFeedbackSummary = async () => {
+        if (!modelId) return;
+        try {
+            setResult(await getFeedbackSummary(modelId));
+        } catch (e) {
+            console.error('Error summarizing feedback:', e);
+            alert(`Feedback summary failed: ${errorMessage(e)}`);
         }
     };
 
@@ -75,6 +189,7 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
         }
         if (entry.feedback.context?.outputs) {
              setResult(entry.feedback.context.outputs);
+             setRunId(undefined);
         }
     };
 
@@ -129,6 +244,12 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
     // Handle input change
     const handleChange = (name: string, value: any) => {
         setValues(prev => ({ ...prev, [name]: value }));
+        setFieldErrors(prev => {
+            if (!(name in prev)) return prev;
+            const next = { ...prev };
+            delete next[name];
+            return next;
+        });
     };
 
     // Handle preset selection
@@ -148,6 +269,8 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
         e.preventDefault();
         setPredictLoading(true);
         setPredictionKey(k => k + 1);
+        setRunId(undefined);
+        setFieldErrors({});
         const requestSeq = ++requestSeqRef.current;
         // Validate all required inputs
         const errors: string[] = [];
@@ -182,15 +305,25 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
             return;
         }
         // Submit the form data
-        invokeModel(modelId, parsedValues)
+        invokeModel(modelId, parsedValues, sections => {
+            if (requestSeq !== requestSeqRef.current) return;
+            setResult(sections);
+        })
             .then(response => {
                 if (requestSeq !== requestSeqRef.current) return;
                 console.log("Model invoked successfully:", response);
-                setResult(response);
+                setRunId(response.runId);
+                setResult(response.warnings.length > 0
+                    ? [contractWarningSection(response.warnings), ...response.outputs]
+                    : response.outputs);
+                if (showRunHistory) loadRuns(0);
             })
             .catch(error => {
                 if (requestSeq !== requestSeqRef.current) return;
                 console.error("Error invoking model:", error);
+                if (isChanterelleError(error) && error.code === 'validation' && error.fields) {
+                    setFieldErrors(Object.fromEntries(error.fields.map(f => [f.field, f.message])));
+                }
                 // Create an error section for caught exceptions
                 setResult([{
                     type: 'section',
@@ -225,18 +358,32 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
             <div className="flex flex-col md:flex-row gap-6 min-w-0 items-stretch">
                 <div className="flex-1 min-w-0">
                     <div ref={formCardRef} className="bg-white dark:bg-slate-800 rounded-lg shadow-md px-2 py-1 transition-colors border border-transparent dark:border-slate-700">
-                        {model.allow_feedback && (
-                            <div className="flex justify-end">
+                        <div className="flex justify-end">
+                            <button
+                                type="button"
+                                onClick={() => {
+                                    setShowRunHistory(!showRunHistory);
+                                    setShowFeedbackHistory(false);
+                                }}
+                                className={`p-2 rounded-full hover:bg-gray-100 dark:hover:bg-slate-700 transition-colors ${showRunHistory ? 'text-blue-500 bg-blue-50 dark:bg-blue-900/20' : 'text-gray-400'}`}
+                                title={showRunHistory ? "Hide past runs" : "Show past runs"}
+                            >
+                                {showRunHistory ? <ChevronRight className="w-5 h-5" /> : <Clock className="w-5 h-5" />}
+                            </button>
+                            {model.allow_feedback && (
                                 <button
                                     type="button"
-                                    onClick={() => setShowFeedbackHistory(!showFeedbackHistory)}
+                                    onClick={() => {
+                                        setShowFeedbackHistory(!showFeedbackHistory);
+                                        setShowRunHistory(false);
+                                    }}
                                     className={`p-2 rounded-full hover:bg-gray-100 dark:hover:bg-slate-700 transition-colors ${showFeedbackHistory ? 'text-blue-500 bg-blue-50 dark:bg-blue-900/20' : 'text-gray-400'}`}
                                     title={showFeedbackHistory ? "Hide feedback history" : "Show feedback history"}
                                 >
                                     {showFeedbackHistory ? <ChevronRight className="w-5 h-5" /> : <History className="w-5 h-5" />}
                                 </button>
-                            </div>
-                        )}
+                            )}
+                        </div>
 
                         <form onSubmit={handleSubmit} className="space-y-6">
                         {/* Grouped sections */}
@@ -246,6 +393,7 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
                                 group={group}
                                 values={values}
                                 presetSelections={presetSelections}
+                                fieldErrors={fieldErrors}
                                 handleChange={handleChange}
                                 handlePresetChange={handlePresetChange}
                             />
@@ -262,6 +410,7 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
                                 }}
                                 values={values}
                                 presetSelections={presetSelections}
+                                fieldErrors={fieldErrors}
                                 handleChange={handleChange}
                                 handlePresetChange={handlePresetChange}
                             />
@@ -278,6 +427,7 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
                                 }}
                                 values={values}
                                 presetSelections={presetSelections}
+                                fieldErrors={fieldErrors}
                                 handleChange={handleChange}
                                 handlePresetChange={handlePresetChange}
                             />
@@ -321,7 +471,17 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
                             style={formCardHeight ? { height: formCardHeight } : undefined}
                         >
                             <div className="p-2 flex justify-between items-center border-b border-gray-200 dark:border-slate-700">
-                                <h3 className="font-medium text-gray-900 dark:text-gray-100">History</h3>
+                                <h3 className="font-medium text-gray-900 dark:text-gray-100">History ({feedbackTotal})</h3>
+                                <button
+                                    type="button"
+                                    onClick={handleFeedbackSummary}
+                                    disabled={loadingHistory || feedbackHistory.length === 0}
+                                    className="ml-auto mr-1 flex items-center gap-1 px-2 py-1 text-xs rounded text-blue-600 dark:text-blue-400 hover:bg-blue-50 dark:hover:bg-blue-900/20 disabled:opacity-50"
+                                    title="Chart feedback per model version and rating"
+                                >
+                                    <ChartColumn className="w-3.5 h-3.5" />
+                                    Summary
+                                </button>
                                 <button
                                     type="button"
                                     onClick={() => setShowFeedbackHistory(false)}
@@ -331,13 +491,91 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
                                     <ChevronRight className="w-5 h-5" />
                                 </button>
                             </div>
+                            <div className="p-2 border-b border-gray-200 dark:border-slate-700">
+                                <input
+                                    type="search"
+                                    value={feedbackFilter}
+                                    onChange={e => setFeedbackFilter(e.target.value)}
+                                    placeholder="Search comments"
+                                    className="w-full px-2 py-1 text-sm rounded border border-gray-300 dark:border-slate-600 bg-white dark:bg-slate-800 text-gray-900 dark:text-gray-100"
+                                />
+                            </div>
                             <div className="p-4 flex-1 overflow-y-auto min-h-0">
                                 <FeedbackList 
                                     history={feedbackHistory} 
-                                    loading={loadingHistory} 
+                                    loading={loadingHistory && feedbackHistory.length === 0} 
                                     onDelete={handleDeleteFeedback}
                                     onSelect={handleSelectFeedback}
                                 />
+                                {feedbackCursor && (
+                                    <button
+                                        type="button"
+                                        disabled={loadingHistory}
+                                        onClick={() => refreshHistory(feedbackCursor)}
+                                        className="mt-3 w-full text-sm text-blue-600 dark:text-blue-400 hover:underline disabled:opacity-50"
+                                    >
+                                        {loadingHistory ? "Loading..." : "Load more"}
+                                    </button>
+                                )}
+                            </div>
+                        </div>
+                    </div>
+                )}
+
+                {/* Runs Sidebar */}
+                {showRunHistory && (
+                    <div className="w-full md:w-[300px] shrink-0 self-stretch">
+                        <div
+                            className="bg-gray-50 dark:bg-slate-900/50 border border-gray-200 dark:border-slate-700 rounded-lg flex flex-col"
+                            style={formCardHeight ? { height: formCardHeight } : undefined}
+                        >
+                            <div className="p-2 flex justify-between items-center border-b border-gray-200 dark:border-slate-700">
+                                <h3 className="font-medium text-gray-900 dark:text-gray-100">Runs ({runsTotal})</h3>
+                                <button
+                                    type="button"
+                                    onClick={handleReplayRuns}
+                                    disabled={replayProgress !== null || predictLoading || runsTotal === 0}
+                                    className="ml-auto mr-1 flex items-center gap-1 px-2 py-1 text-xs rounded text-blue-600 dark:text-blue-400 hover:bg-blue-50 dark:hover:bg-blue-900/20 disabled:opacity-50"
+                                    title="Re-run these inputs with the current model and compare the outputs"
+                                >
+                                    <RotateCcw className="w-3.5 h-3.5" />
+                                    {replayProgress ?? "Replay"}
+                                </button>
+                                <button
+                                    type="button"
+                                    onClick={() => setShowRunHistory(false)}
+                                    className="p-1 text-gray-600 hover:text-gray-900 dark:text-gray-300 dark:hover:text-white"
+                                    title="Hide past runs"
+                                >
+                                    <ChevronRight className="w-5 h-5" />
+                                </button>
+                            </div>
+                            <div className="p-2 border-b border-gray-200 dark:border-slate-700">
+                                <input
+                                    type="search"
+                                    value={runsFilter}
+                                    onChange={e => setRunsFilter(e.target.value)}
+                                    placeholder="Search inputs and outputs"
+                                    className="w-full px-2 py-1 text-sm rounded border border-gray-300 dark:border-slate-600 bg-white dark:bg-slate-800 text-gray-900 dark:text-gray-100"
+                                />
+                            </div>
+                            <div className="p-4 flex-1 overflow-y-auto min-h-0">
+                                <RunHistoryList
+                                    entries={runs}
+                                    loading={loadingRuns && runs.length === 0}
+                                    onDelete={handleDeleteRun}
+                                    onSelect={handleSelectRun}
+                                />
+                                {runs.length < runsTotal && (
+                                    <button
+                                        type="button"
+                                        disabled={loadingRuns}
+                                        onClick={() => loadRuns(runs.length)}
+                                        className="mt-3 w-full text-sm text-blue-600 dark:text-blue-400 hover:underline disabled:opacity-50"
+                                    >
+                                        {loadingRuns ? "Loading..." : "Load more"}
+                                    </button>
+                                )}
                             </div>
                         </div>
                     </div>
@@ -360,6 +598,8 @@ const ModelForm: React.FC<ModelFormProps> = ({ model }) => {
                                 key={predictionKey}
                                 projectName={modelId}
                                 context={{ inputs: values, outputs: result }}
+                                runId={runId}
+                                schema={model.feedback_schema}
                                 onFeedbackSubmitted={() => {
                                     refreshHistory();
                                     if (!showFeedbackHistory) setShowFeedbackHistory(true);
@@ -59,7 +59,7 @@ const ModelPage: React.FC = () => {
                 setWarmStatus('ready');
             } else {
                 setWarmStatus('error');
-                setWarmError(response.error || 'Failed to warm up model');
+                setWarmError(response.error?.message || 'Failed to warm up model');
             }
         } catch (error) {
             setWarmStatus('error');
@@ -36,9 +36,31 @@ const ModelsCatalog: React.FC = () => {
     const filteredModels = models.filter(model =>
         model.project_title.toLowerCase().includes(searchQuery.toLowerCase()) ||
         model.description.toLowerCase().includes(searchQuery.toLowerCase()) ||
+        (model.category ?? '').toLowerCase().includes(searchQuery.toLowerCase()) ||
         (model.tags && Object.values(model.tags).some(tagValue => tagValue.toLowerCase().includes(searchQuery.toLowerCase())))
     );
 
+    // Projects grouped by the folders above them; top-level projects come first, without a heading
+    const groups = Object.entries(
+        filteredModels.reduce<Record<string, ProjectMeta[]>>((acc, model) => {
+            const category = model.category ?? '';
+            acc[category] = [...(acc[category] ?? []), model];
+            return acc;
+        }, {})
+    ).sort(([a], [b]) => a.localeCompare(b));
+
+    // Project ids are folder paths, so they are encoded to fit in one route segment
+    const openProject = (model: ProjectMeta) => {
+        const id = encodeURIComponent(model.project_name);
+        if (model.kind === 'analytics') {
+            navigate(`/analytics/${id}`);
+        } else if (model.kind === 'interactive') {
+            navigate(`/interactive/${id}`);
+        } else {
+            navigate(`/model/${id}`);
+        }
+    };
+
     return (
         <div className="min-h-full bg-indigo-50 dark:bg-slate-900 transition-colors">
             {/* <Header /> */}
@@ -90,29 +112,26 @@ const ModelsCatalog: React.FC = () => {
                     </div>
                 )}
 
-                {!loading && !error && (
-                    <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-6">
-                        {filteredModels.map(model => (
-                            <ModelCard
-                                key={model.project_name}
-                                model={model}
-                                onClick={() => {
-                                    if (model.kind === 'analytics') {
-                                        navigate(`/analytics/${model.project_name}`);
-                                    } else if (model.kind === 'interactive') {
-                                        navigate(`/interactive/${model.project_name}`);
-                                    } else {
-                                        navigate(`/model/${model.project_name}`);
-                                    }
-                                }}
-                            />
-                        ))}
-
-                        {filteredModels.length === 0 && (
-                            <div className="col-span-3 text-center py-12">
-                                <p className="text-gray-500 dark:text-gray-400">No models found matching your search criteria.</p>
-                            </div>
+                {!loading && !error && groups.map(([category, projects]) => (
+                    <section key={category} className="mb-8">
+                        {category && (
+                            <h2 className="mb-3 text-sm font-mono font-semibold text-gray-600 dark:text-gray-300">{category}</h2>
                         )}
+                        <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-6">
+                            {projects.map(model => (
+                                <ModelCard
+                                    key={model.project_name}
+                                    model={model}
+                                    onClick={() => openProject(model)}
+                                />
+                            ))}
+                        </div>
+                    </section>
+                ))}
+
+                {!loading && !error && filteredModels.length === 0 && (
+                    <div className="text-center py-12">
+                        <p className="text-gray-500 dark:text-gray-400">No models found matching your search criteria.</p>
                     </div>
                 )}
             </main>
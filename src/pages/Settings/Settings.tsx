@@ -10,6 +10,8 @@ export const Settings: React.FC = () => {
   const { showSuccess, showError } = useNotificationContext();
   const [settings, setSettings] = useState<SettingsType>({ projects_directory: '' });
   const [loading, setLoading] = useState(false);
+  // Ignore patterns as typed, split into a list on save
+  const [ignoreText, setIgnoreText] = useState('');
   const { preference, setPreference, textSize, setTextSize } = useTheme();
 
   useEffect(() => {
@@ -20,6 +22,7 @@ export const Settings: React.FC = () => {
       try {
         const currentSettings = await SettingsService.getSettings();
         setSettings(currentSettings);
+        setIgnoreText(currentSettings.project_discovery?.ignore.join(', ') ?? '');
       } catch (err) {
         console.error('Failed to load settings', err);
       }
@@ -41,7 +44,11 @@ export const Settings: React.FC = () => {
   const saveSettings = async () => {
     setLoading(true);
     try {
-      await SettingsService.saveSettings(settings);
+      const project_discovery = settings.project_discovery && {
+        ...settings.project_discovery,
+        ignore: ignoreText.split(',').map(p => p.trim()).filter(Boolean),
+      };
+      await SettingsService.saveSettings({ ...settings, project_discovery });
       showSuccess('Settings saved successfully');
       setTimeout(() => navigate('/'), 120);
     } catch (err) {
@@ -116,6 +123,67 @@ export const Settings: React.FC = () => {
                 <button onClick={selectProjectsDirectory} className="px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 dark:hover:bg-blue-500 transition-colors duration-200">Browse...</button>
               </div>
             </div>
+            {/* How deep to look for projects */}
+            {settings.project_discovery && (
+              <div className="mb-6">
+                <label className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">Project Discovery:</label>
+                <div className="flex gap-2">
+                  <input
+                    type="number"
+                    min={1}
+                    value={settings.project_discovery.max_depth}
+                    onChange={e => {
+                      const max_depth = Math.max(1, Number(e.target.value) || 1);
+                      setSettings(prev => ({ ...prev, project_discovery: prev.project_discovery && { ...prev.project_discovery, max_depth } }));
+                    }}
+                    title="Folder levels searched below the projects directory"
+                    className="w-20 px-3 py-2 border border-gray-300 dark:border-slate-600 rounded-md bg-gray-50 dark:bg-slate-700 dark:text-gray-100 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  />
+                  <input
+                    type="text"
+                    value={ignoreText}
+                    onChange={e => setIgnoreText(e.target.value)}
+                    placeholder=".*, node_modules, venv"
+                    title="Folder names to skip, separated by commas"
+                    className="flex-1 px-3 py-2 border border-gray-300 dark:border-slate-600 rounded-md bg-gray-50 dark:bg-slate-700 dark:text-gray-100 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  />
+                </div>
+                <p className="mt-2 text-xs text-gray-500 dark:text-gray-400 max-w-md">Projects are searched this many folder levels deep, e.g. 3 for team/domain/project. Folders matching a pattern are skipped, and so are Python virtual environments.</p>
+              </div>
+            )}
+            {/* Editor used to open traceback frames */}
+            <div className="mb-6">
+              <label className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">Code Editor:</label>
+              <div className="flex gap-2">
+                <select
+                  value={settings.editor?.type ?? 'vscode'}
+                  onChange={e => {
+                    const type = e.target.value;
+                    setSettings(prev => ({
+                      ...prev,
+                      editor: type === 'custom'
+                        ? { type: 'custom', template: prev.editor?.type === 'custom' ? prev.editor.template : '' }
+                        : { type: type as 'vscode' | 'env' },
+                    }));
+                  }}
+                  className="px-3 py-2 border border-gray-300 dark:border-slate-600 rounded-md bg-gray-50 dark:bg-slate-700 dark:text-gray-100 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                >
+                  <option value="vscode">VS Code</option>
+                  <option value="env">$EDITOR</option>
+                  <option value="custom">Custom</option>
+                </select>
+                {settings.editor?.type === 'custom' && (
+                  <input
+                    type="text"
+                    value={settings.editor.template}
+                    onChange={e => setSettings(prev => ({ ...prev, editor: { type: 'custom', template: e.target.value } }))}
+                    placeholder="subl {file}:{line}"
+                    className="flex-1 px-3 py-2 border border-gray-300 dark:border-slate-600 rounded-md bg-gray-50 dark:bg-slate-700 dark:text-gray-100 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  />
+                )}
+              </div>
+              <p className="mt-2 text-xs text-gray-500 dark:text-gray-400 max-w-md">Used to open handler errors at the failing line. Custom commands can use {'{file}'} and {'{line}'}.</p>
+            </div>
             {/* Actions */}
             <div className="flex gap-3">
               <button
@@ -14,8 +14,23 @@ import { invoke } from '@tauri-apps/api/core';
 //     findings?: any;
 // }
 
+export type EditorSetting =
+    | { type: 'vscode' }
+    | { type: 'env' }
+    | { type: 'custom'; template: string };
+
+// How the projects directory is searched for projects
+export interface ProjectDiscovery {
+    // Folder levels searched; 1 only looks at direct children
+    max_depth: number;
+    // Folder names never searched; `*` matches any characters
+    ignore: string[];
+}
+
 export interface Settings {
     projects_directory: string;
+    editor?: EditorSetting;
+    project_discovery?: ProjectDiscovery;
 }
 
 // export class ModelService {
@@ -50,7 +65,21 @@ export class SettingsService {
 
     static async saveSettings(settings: Settings): Promise<void> {
         // return await invoke('save_settings', { settings });
-        return await invoke('set_projects_directory', { path: settings.projects_directory });
+        await invoke('set_projects_directory', { path: settings.projects_directory });
+        if (settings.editor) {
+            await invoke('set_editor', { editor: settings.editor });
+        }
+        if (settings.project_discovery) {
+            await invoke('set_project_discovery', { discovery: settings.project_discovery });
+        }
+    }
+
+    static async setEditor(editor: EditorSetting): Promise<void> {
+        return await invoke('set_editor', { editor });
+    }
+
+    static async setProjectDiscovery(discovery: ProjectDiscovery): Promise<void> {
+        return await invoke('set_project_discovery', { discovery });
     }
 
     static async setProjectsDirectory(path: string): Promise<void> {
@@ -0,0 +1,59 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import type { SectionType } from '../../components/insights';
+
+// Cold-start and inference latency of a project (see src-tauri/src/benchmark.rs)
+
+export interface BenchmarkRequest {
+    iterations?: number;
+    // Cycled through; defaults to the model's input defaults
+    inputs?: Record<string, any>[];
+    // Name of an input preset from model_meta.json
+    preset?: string;
+    // Also write model_benchmark.json in the project folder
+    save?: boolean;
+}
+
+export interface LatencyStats {
+    min_ms: number;
+    mean_ms: number;
+    p50_ms: number;
+    p95_ms: number;
+    p99_ms: number;
+    max_ms: number;
+}
+
+export interface BenchmarkReport {
+    project_name: string;
+    model_version: string | null;
+    env_fingerprint: string;
+    timestamp: number;
+    warmup_ms: number;
+    iterations: number;
+    errors: number;
+    contract_violations: number;
+    latency: LatencyStats;
+    throughput_per_sec: number;
+    // Not measured on Windows
+    peak_rss_bytes: number | null;
+    latencies_ms: number[];
+    sections: SectionType[];
+}
+
+export async function benchmarkModel(
+    projectName: string,
+    request: BenchmarkRequest = {},
+    onProgress?: (done: number, total: number) => void,
+): Promise<BenchmarkReport> {
+    const unlisten = onProgress
+        ? await listen<{ project_name: string; done: number; total: number }>('benchmark:progress', (event) => {
+            if (event.payload.project_name !== projectName) return;
+            onProgress(event.payload.done, event.payload.total);
+        })
+        : null;
+    try {
+        return await invoke<BenchmarkReport>('benchmark_model', { projectName, request });
+    } finally {
+        unlisten?.();
+    }
+}
@@ -0,0 +1,12 @@
+import { invoke } from "@tauri-apps/api/core";
+
+// Drops the handler-side state of one conversation; its transcript is kept
+export async function closeInteractiveSession(sessionId: string): Promise<boolean> {
+    try {
+        const closed = await invoke("close_interactive_session", { sessionId });
+        return Boolean(closed);
+    } catch (error) {
+        console.error("Failed to close interactive session:", error);
+        return false;
+    }
+}
@@ -0,0 +1,74 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import type { SectionType } from '../../components/insights';
+import type { ChanterelleError } from '../../types/ModelError';
+import type { PythonEnvironment } from '../../types/ModelMeta';
+import type { FieldDiff } from './replayHistory';
+
+// Runs the same inputs through several projects side by side (see src-tauri/src/compare.rs)
+
+export interface CompareTarget {
+    project_name: string;
+    label?: string;
+    // Overrides the project's own python_environment
+    python_environment?: PythonEnvironment;
+}
+
+export interface CompareRequest {
+    // The first target is the baseline
+    targets: CompareTarget[];
+    inputs?: Record<string, any>;
+    // .json array or .jsonl file, relative to the projects directory
+    inputs_file?: string;
+    tolerances?: Record<string, number>;
+    default_tolerance?: number;
+}
+
+export interface CompareTargetInfo {
+    label: string;
+    project_name: string;
+    model_version: string | null;
+    env_fingerprint: string | null;
+    warmup_ms: number;
+    error: ChanterelleError | null;
+    mean_latency_ms: number | null;
+    agreeing_cases: number;
+    failed_cases: number;
+}
+
+export interface CompareResult {
+    label: string;
+    outputs: any;
+    error: ChanterelleError | null;
+    latency_ms: number;
+    diffs: FieldDiff[];
+}
+
+export interface CompareCase {
+    case_id: string;
+    inputs: Record<string, any>;
+    results: CompareResult[];
+    disagreeing_fields: string[];
+}
+
+export interface CompareReport {
+    targets: CompareTargetInfo[];
+    cases: CompareCase[];
+    sections: SectionType[];
+}
+
+export async function compareModels(
+    request: CompareRequest,
+    onProgress?: (done: number, total: number) => void,
+): Promise<CompareReport> {
+    const unlisten = onProgress
+        ? await listen<{ done: number; total: number }>('compare:progress', (event) => {
+            onProgress(event.payload.done, event.payload.total);
+        })
+        : null;
+    try {
+        return await invoke<CompareReport>('compare_models', { request });
+    } finally {
+        unlisten?.();
+    }
+}
@@ -1,9 +1,9 @@
 import { invoke } from '@tauri-apps/api/core';
 
-export async function deleteFeedback(projectName: string, timestamp: number): Promise<void> {
-    console.log('Deleting feedback for project:', projectName, timestamp);
+export async function deleteFeedback(projectName: string, id: string): Promise<void> {
+    console.log('Deleting feedback for project:', projectName, id);
     try {
-        await invoke('delete_feedback', { projectName, timestamp });
+        await invoke('delete_feedback', { projectName, id });
         console.log('Feedback deleted successfully');
     } catch (error) {
         console.error('Error deleting feedback:', error);
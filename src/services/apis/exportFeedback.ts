@@ -0,0 +1,29 @@
+import { invoke } from '@tauri-apps/api/core';
+
+// `chat_jsonl` writes `{"messages": [...]}` lines and only works for interactive projects
+export type FeedbackExportFormat = 'csv' | 'jsonl' | 'parquet' | 'chat_jsonl';
+
+export interface FeedbackFilter {
+    // Seconds since the Unix epoch; `since` is inclusive, `until` exclusive
+    since?: number;
+    until?: number;
+    // Version of the linked run
+    model_version?: string;
+    rating?: any;
+}
+
+export interface FeedbackExport {
+    path: string;
+    rows: number;
+    // Flattened column names; empty for JSONL formats
+    columns: string[];
+}
+
+export async function exportFeedback(
+    projectName: string,
+    format: FeedbackExportFormat,
+    path: string,
+    filter: FeedbackFilter = {},
+): Promise<FeedbackExport> {
+    return invoke<FeedbackExport>('export_feedback', { projectName, request: { format, path, filter } });
+}
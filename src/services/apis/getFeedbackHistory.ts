@@ -2,8 +2,31 @@ import { invoke } from '@tauri-apps/api/core';
 import type { FeedbackData } from './submitFeedback';
 
 export interface FeedbackEntry {
+    id: string;
+    // Seconds since the Unix epoch
     timestamp: number;
     feedback: FeedbackData;
+    // The run the feedback is about, when it was linked to one
+    run?: FeedbackRun;
+    // Whether the handler's feedback_fn received it; absent for interactive projects
+    delivery?: FeedbackDelivery;
+}
+
+export interface FeedbackDelivery {
+    status: 'pending' | 'delivered' | 'ignored' | 'failed' | 'abandoned';
+    attempts: number;
+    attempted_at: number | null;
+    artifacts_fingerprint: string | null;
+    error: string | null;
+}
+
+export interface FeedbackRun {
+    run_id: string;
+    model_version: string | null;
+    inputs: Record<string, any>;
+    outputs: any;
+    env_fingerprint: string | null;
+    artifacts_fingerprint: string | null;
 }
 
 export async function getFeedbackHistory(project_name: string): Promise<FeedbackEntry[]> {
@@ -0,0 +1,8 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { SectionType } from '../../components/insights';
+import type { FeedbackFilter } from './exportFeedback';
+
+// Feedback aggregated per model version and per rating or label, as insight sections
+export async function getFeedbackSummary(projectName: string, filter: FeedbackFilter = {}): Promise<SectionType[]> {
+    return invoke<SectionType[]>('get_feedback_summary', { projectName, filter });
+}
@@ -1,5 +1,6 @@
 import type { ModelMeta } from '../../types/ModelMeta';
 import type { ModelInsightsType } from '../../types/Project';
+import { errorMessage, isChanterelleError } from '../../types/ModelError';
 import { invoke } from '@tauri-apps/api/core';
 
 
@@ -30,8 +31,8 @@ export async function getModelMeta(project_name: string): Promise<ModelData> {
     } catch (error) {
         console.error('Error fetching model meta:', error);
         // Handle specific error types from Tauri backend
-        if (typeof error === 'string') {
-            throw new Error(error);
+        if (typeof error === 'string' || isChanterelleError(error)) {
+            throw new Error(errorMessage(error));
         } else if (error instanceof Error) {
             throw error;
         } else {
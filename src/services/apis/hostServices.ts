@@ -0,0 +1,35 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import { NotificationType } from '../../components/common/NotificationBanner';
+
+export interface HostNotification {
+    project_name: string;
+    title: string;
+    body: string;
+    level: NotificationType;
+}
+
+export interface MetricRecord {
+    name: string;
+    value: number;
+    step?: number | null;
+    tags?: Record<string, any> | null;
+    timestamp: number;
+}
+
+// Notifications raised by handlers with chanterelle_host.notify()
+export async function listenHostNotifications(onNotification: (notification: HostNotification) => void): Promise<UnlistenFn> {
+    return listen<HostNotification>('host:notification', (event) => {
+        onNotification(event.payload);
+    });
+}
+
+// Metrics handlers logged with chanterelle_host.log_metric(), oldest first
+export async function getProjectMetrics(projectName: string): Promise<MetricRecord[]> {
+    try {
+        return await invoke<MetricRecord[]>('get_project_metrics', { projectName });
+    } catch (error) {
+        console.error('Error loading project metrics:', error);
+        return [];
+    }
+}
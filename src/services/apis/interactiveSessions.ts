@@ -0,0 +1,52 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export interface InteractiveSessionMeta {
+    session_id: string;
+    project_name: string;
+    title: string | null;
+    created_at: number;
+    updated_at: number;
+    turn_count: number;
+}
+
+export interface InteractiveSessionEvent {
+    timestamp: number;
+    kind: 'input' | 'output';
+    data: any;
+}
+
+export interface InteractiveSessionTranscript extends InteractiveSessionMeta {
+    events: InteractiveSessionEvent[];
+}
+
+export type SessionExportFormat = 'markdown' | 'json';
+
+export async function listInteractiveSessions(projectName: string): Promise<InteractiveSessionMeta[]> {
+    return invoke<InteractiveSessionMeta[]>('list_interactive_sessions', { projectName });
+}
+
+export async function loadInteractiveSession(projectName: string, sessionId: string): Promise<InteractiveSessionTranscript> {
+    return invoke<InteractiveSessionTranscript>('load_interactive_session', { projectName, sessionId });
+}
+
+// Returns session_turns to pass to the next initialize; that initialize then keeps recording into this session
+export async function resumeInteractiveSession(projectName: string, sessionId: string): Promise<any[]> {
+    return invoke<any[]>('resume_interactive_session', { projectName, sessionId });
+}
+
+export async function renameInteractiveSession(projectName: string, sessionId: string, title: string): Promise<InteractiveSessionMeta> {
+    return invoke<InteractiveSessionMeta>('rename_interactive_session', { projectName, sessionId, title });
+}
+
+export async function deleteInteractiveSession(projectName: string, sessionId: string): Promise<void> {
+    await invoke('delete_interactive_session', { projectName, sessionId });
+}
+
+export async function exportInteractiveSession(
+    projectName: string,
+    sessionId: string,
+    format: SessionExportFormat,
+    path?: string,
+): Promise<string> {
+    return invoke<string>('export_interactive_session', { projectName, sessionId, format, path: path ?? null });
+}
@@ -13,6 +13,7 @@ export interface InteractiveOutput {
     error?: string;
     response_id?: string;
     request_id?: string;
+    session_id?: string;
     event_type?: "partial" | "final" | "prompt";
     append?: boolean;
     stopped?: boolean;
@@ -26,6 +27,7 @@ export type InteractiveCallback = (data: InteractiveOutput) => void;
  * @param projectName The name of the project folder
  * @param inputs The inputs to send to the Python script
  * @param onData Callback for receiving real-time updates from Python
+ * @param sessionId Conversation to run in; events from other sessions are ignored
  * @returns A promise that resolves when the *request* is sent (not when it finishes)
  */
 export async function invokeInteractive(
@@ -34,19 +36,23 @@ export async function invokeInteractive(
     onData: InteractiveCallback,
     requestId?: string,
     sessionTurns?: any[],
+    sessionId?: string,
 ): Promise<UnlistenFn> {
     console.log('Starting interactive session for:', projectName);
 
     // Set up the listener BEFORE invoking the command to ensure we don't miss early events
     const unlisten = await listen<InteractiveOutput>('interactive:output', (event) => {
         console.log('Interactive event:', event.payload);
+        if (sessionId && event.payload.session_id && event.payload.session_id !== sessionId) {
+            return;
+        }
         onData(event.payload);
     });
 
     // Kick off the Rust command but do NOT await it here.
     // In dev, React StrictMode can mount/unmount/remount quickly; awaiting here delays
     // exposing `unlisten`, which can lead to multiple active listeners and duplicated outputs.
-    void invoke('invoke_interactive', { projectName: projectName, inputs: inputs, requestId, sessionTurns: sessionTurns || null })
+    void invoke('invoke_interactive', { projectName: projectName, inputs: inputs, requestId, sessionId: sessionId || null, sessionTurns: sessionTurns || null })
         .catch((e: any) => {
             console.error("Error invoking interactive:", e);
             onData({ error: typeof e === 'string' ? e : e.message || "Unknown error" });
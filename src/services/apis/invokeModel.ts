@@ -1,16 +1,98 @@
 import type { ModelInputs } from "../../types/ModelInputs";
 import type { SectionType } from "../../components/insights";
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { errorMessage, isChanterelleError, type ContractViolation } from '../../types/ModelError';
+
+// Partial event emitted while a generator predict_fn/output_fn is streaming
+export interface ModelPartialOutput {
+    project_name: string;
+    outputs: SectionType[];
+    event_type: "partial";
+    append: boolean;
+}
+
+export interface ModelRunResult {
+    // History entry of this run; missing when it could not be recorded
+    runId?: string;
+    outputs: SectionType[];
+    // Fields that break the declared outputs
+    warnings: ContractViolation[];
+    // Served from the prediction cache instead of the handler
+    cached: boolean;
+}
+
+export function contractWarningSection(warnings: ContractViolation[]): SectionType {
+    return {
+        type: 'section',
+        id: 'contract_warnings',
+        color: 'yellow',
+        title: 'Output contract',
+        description: `${warnings.length} field(s) do not match the outputs declared in model_meta.json`,
+        items: [{
+            type: 'table',
+            id: 'contract_violations',
+            title: 'Violations',
+            data: {
+                columns: [
+                    { header: 'Output', field: 'output' },
+                    { header: 'Path', field: 'path' },
+                    { header: 'Problem', field: 'message' },
+                ],
+                rows: warnings,
+            },
+        }],
+    } as SectionType;
+}
+
+export function handlerErrorSections(error: string, details: Partial<Record<'error_type' | 'stage' | 'traceback', any>> = {}): SectionType[] {
+    return [{
+        type: 'section',
+        id: 'error',
+        color: 'red',
+        title: 'Results',
+        items: [{
+            type: 'error',
+            id: 'handler_error',
+            title: 'Error from the python handler',
+            error,
+            ...details,
+        }]
+    }];
+}
 
 // Rmk: for extra secure flow, invokeModel should make sure the user has access to the model (again)
 // Note: File inputs are currently handled as metadata objects. The backend handles file uploads or just references.
-export async function invokeModel(project_name: string, inputs: ModelInputs): Promise<SectionType[]> {
+export async function invokeModel(
+    project_name: string,
+    inputs: ModelInputs,
+    onPartial?: (sections: SectionType[]) => void,
+): Promise<ModelRunResult> {
     console.log('invoking model for project:', project_name, 'with inputs:', inputs);
     const startTime = Date.now();
+    // Merge streamed sections locally so the caller always receives the full picture so far
+    let streamed: SectionType[] = [];
+    const unlisten = onPartial
+        ? await listen<ModelPartialOutput>('model:partial', (event) => {
+            if (event.payload.project_name !== project_name) return;
+            const outputs = event.payload.outputs || [];
+            streamed = event.payload.append ? [...streamed, ...outputs] : outputs;
+            onPartial(streamed);
+        })
+        : null;
     try {
         // let r_warmup = await invoke('warmup_model', { projectName: project_name }) as string;
         // console.log('Model warmup response:', r_warmup);
-        let r = await invoke('invoke_model', { projectName: project_name, inputs }) as SectionType[] | { error: string };
+        const response = await invoke('invoke_model', { projectName: project_name, inputs }) as {
+            run_id: string | null;
+            outputs: SectionType[] | { error: string };
+            warnings: ContractViolation[];
+            cached: boolean;
+        };
+        const runId = response.run_id ?? undefined;
+        const warnings = response.warnings ?? [];
+        const cached = response.cached ?? false;
+        const r = response.outputs;
         const endTime = Date.now();
         const timeWaited = endTime - startTime;
         console.log(`Model invocation completed in ${timeWaited}ms`);
@@ -18,27 +100,30 @@ export async function invokeModel(project_name: string, inputs: ModelInputs): Pr
         // Check if response is an error object
         if (r && typeof r === 'object' && 'error' in r && !Array.isArray(r)) {
             console.log('Model returned error:', r);
-            return [{
-                type: 'section',
-                id: 'error',
-                color: 'red',
-                title: 'Results',
-                items: [{
-                    type: 'error',
-                    id: 'handler_error',
-                    title: 'Error from the python handler',
-                    error: r.error,
-                }]
-            }];
+            return { runId, outputs: handlerErrorSections(r.error), warnings, cached };
         }
         
         console.log('Model meta fetched:', r);
-        return r as SectionType[];
+        return { runId, outputs: r as SectionType[], warnings, cached };
     } catch (error) {
         console.error('Error invoking model:', error);
-        if (typeof error === 'string' && error.includes('No projects directory set')) {
+        // Handler exceptions are shown inline, with their traceback
+        if (isChanterelleError(error) && error.code === 'handler') {
+            return {
+                outputs: handlerErrorSections(error.message, {
+                    error_type: error.error_type,
+                    stage: error.stage,
+                    traceback: error.traceback,
+                }),
+                warnings: [],
+                cached: false,
+            };
+        }
+        if (errorMessage(error).includes('No projects directory set')) {
             throw new Error('Please configure your projects directory in settings first.');
         }
         throw error;
+    } finally {
+        unlisten?.();
     }
 }
\ No newline at end of file
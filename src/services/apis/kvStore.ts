@@ -0,0 +1,33 @@
+import { invoke } from '@tauri-apps/api/core';
+
+// Persistent per-project key-value store that handlers write through chanterelle_host
+
+export interface KvNamespaceSummary {
+    namespace: string;
+    keys: number;
+    bytes: number;
+}
+
+export interface KvEntrySummary {
+    key: string;
+    value: any;
+    bytes: number;
+    updated_at: number;
+}
+
+export async function listKvNamespaces(projectName: string): Promise<KvNamespaceSummary[]> {
+    return invoke<KvNamespaceSummary[]>('list_kv_namespaces', { projectName });
+}
+
+export async function getKvEntries(projectName: string, namespace?: string): Promise<KvEntrySummary[]> {
+    return invoke<KvEntrySummary[]>('get_kv_entries', { projectName, namespace: namespace ?? null });
+}
+
+export async function deleteKvEntry(projectName: string, key: string, namespace?: string): Promise<boolean> {
+    return invoke<boolean>('delete_kv_entry', { projectName, namespace: namespace ?? null, key });
+}
+
+// Clears one namespace, or the whole store when namespace is omitted
+export async function clearKvStore(projectName: string, namespace?: string): Promise<void> {
+    await invoke('clear_kv_store', { projectName, namespace: namespace ?? null });
+}
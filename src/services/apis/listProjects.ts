@@ -1,4 +1,5 @@
 import type { ProjectMeta } from "../../types/Project";
+import { errorMessage } from "../../types/ModelError";
 
 import { invoke } from '@tauri-apps/api/core';
 
@@ -9,7 +10,7 @@ export async function listProjects(): Promise<ProjectMeta[]> {
         console.log('Projects fetched:', s);
         return s as ProjectMeta[];
     } catch (error) {
-        if (typeof error === 'string' && error.includes('No projects directory set')) {
+        if (errorMessage(error).includes('No projects directory set')) {
             throw new Error('Please configure your projects directory in settings first.');
         }
         throw error;
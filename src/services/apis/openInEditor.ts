@@ -0,0 +1,11 @@
+import { invoke } from '@tauri-apps/api/core';
+
+// Opens a handler file (e.g. a traceback frame) in the editor configured in Settings
+export async function openInEditor(project_name: string, file: string, line?: number): Promise<void> {
+    try {
+        await invoke('open_in_editor', { projectName: project_name, file, line: line ?? null });
+    } catch (error) {
+        console.error('Error opening file in editor:', error);
+        throw error;
+    }
+}
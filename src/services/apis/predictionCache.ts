@@ -0,0 +1,32 @@
+import { invoke } from '@tauri-apps/api/core';
+
+// Opt-in cache of model responses, turned on by `prediction_cache` in model_meta.json
+
+export interface CacheEntrySummary {
+    key: string;
+    created_at: number;
+    last_hit_at: number | null;
+    hits: number;
+    model_version: string | null;
+    inputs: Record<string, any>;
+    bytes: number;
+}
+
+export interface CacheSummary {
+    enabled: boolean;
+    max_entries: number;
+    max_bytes: number;
+    ttl_seconds: number;
+    total_bytes: number;
+    // Most recently used first
+    entries: CacheEntrySummary[];
+}
+
+export async function getPredictionCache(projectName: string): Promise<CacheSummary> {
+    return invoke<CacheSummary>('get_prediction_cache', { projectName });
+}
+
+// Returns how many entries were removed
+export async function clearPredictionCache(projectName: string): Promise<number> {
+    return invoke<number>('clear_prediction_cache', { projectName });
+}
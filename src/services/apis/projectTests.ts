@@ -0,0 +1,34 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { SectionType } from '../../components/insights';
+import type { ChanterelleError } from '../../types/ModelError';
+import type { FieldDiff } from './replayHistory';
+
+// Golden tests from the project's tests/ folder or model_meta.json test_cases (see src-tauri/src/project_tests.rs)
+
+export interface TestCaseResult {
+    name: string;
+    source: string;
+    status: 'passed' | 'failed' | 'error';
+    diffs: FieldDiff[];
+    error: ChanterelleError | null;
+    outputs: any;
+    duration_ms: number;
+}
+
+export interface TestRunReport {
+    project_name: string;
+    model_version: string | null;
+    passed: number;
+    failed: number;
+    errors: number;
+    warmup_ms: number;
+    duration_ms: number;
+    cases: TestCaseResult[];
+    sections: SectionType[];
+    junit_xml: string;
+}
+
+// junitPath: optional file to write the JUnit XML report to
+export async function runProjectTests(projectName: string, junitPath?: string): Promise<TestRunReport> {
+    return invoke<TestRunReport>('run_project_tests', { projectName, junitPath: junitPath ?? null });
+}
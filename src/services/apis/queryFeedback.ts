@@ -0,0 +1,27 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { FeedbackEntry } from './getFeedbackHistory';
+import type { FeedbackFilter } from './exportFeedback';
+
+export interface FeedbackQuery extends FeedbackFilter {
+    // Feedback fields that must equal these values; list fields match when they contain the value
+    fields?: Record<string, any>;
+    // Case-insensitive search over the comment and other text fields
+    text?: string;
+    // Feedback field to sort by; timestamp when unset
+    sort_by?: string;
+    order?: 'asc' | 'desc';
+    // next_cursor of the previous page
+    cursor?: string;
+    limit?: number;
+}
+
+export interface FeedbackPage {
+    entries: FeedbackEntry[];
+    total: number;
+    // null on the last page
+    next_cursor: string | null;
+}
+
+export async function queryFeedback(projectName: string, query: FeedbackQuery = {}): Promise<FeedbackPage> {
+    return invoke<FeedbackPage>('query_feedback', { projectName, query });
+}
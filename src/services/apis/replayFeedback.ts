@@ -0,0 +1,14 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { FeedbackFilter } from './exportFeedback';
+
+export interface DeliveryReport {
+    attempted: number;
+    delivered: number;
+    ignored: number;
+    failed: number;
+}
+
+// Re-sends the given entries, or those matching the filter, to the handler's feedback_fn
+export async function replayFeedback(projectName: string, ids?: string[], filter: FeedbackFilter = {}): Promise<DeliveryReport> {
+    return invoke<DeliveryReport>('replay_feedback', { projectName, ids: ids ?? null, filter });
+}
@@ -0,0 +1,70 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import type { SectionType } from '../../components/insights';
+import type { ChanterelleError } from '../../types/ModelError';
+import type { HistoryQuery } from './runHistory';
+
+// Re-runs recorded inputs through the current handler and diffs the outputs (see src-tauri/src/replay.rs)
+
+export type ReplaySource =
+    | { type: 'history'; ids?: string[]; query?: HistoryQuery }
+    | { type: 'feedback'; ids?: string[]; timestamps?: number[] }
+    // .json array or .jsonl file; relative to the project folder
+    | { type: 'file'; path: string };
+
+export interface ReplayRequest {
+    source: ReplaySource;
+    // Absolute tolerance per model_meta output name
+    tolerances?: Record<string, number>;
+    default_tolerance?: number;
+    diff_path?: string;
+}
+
+export interface FieldDiff {
+    path: string;
+    output: string | null;
+    kind: 'changed' | 'added' | 'removed';
+    baseline: any;
+    current: any;
+    delta: number | null;
+}
+
+export interface ReplayCaseResult {
+    case_id: string;
+    status: 'unchanged' | 'changed' | 'failed' | 'no_baseline';
+    inputs: Record<string, any>;
+    baseline: any;
+    current: any;
+    error: ChanterelleError | null;
+    diffs: FieldDiff[];
+    latency_ms: number;
+}
+
+export interface ReplayReport {
+    project_name: string;
+    model_version: string | null;
+    timestamp: number;
+    summary: { total: number; unchanged: number; changed: number; failed: number; no_baseline: number };
+    cases: ReplayCaseResult[];
+    sections: SectionType[];
+    // Empty when the file could not be written
+    diff_file: string;
+}
+
+export async function replayHistory(
+    projectName: string,
+    request: ReplayRequest,
+    onProgress?: (done: number, total: number) => void,
+): Promise<ReplayReport> {
+    const unlisten = onProgress
+        ? await listen<{ project_name: string; done: number; total: number }>('replay:progress', (event) => {
+            if (event.payload.project_name !== projectName) return;
+            onProgress(event.payload.done, event.payload.total);
+        })
+        : null;
+    try {
+        return await invoke<ReplayReport>('replay_history', { projectName, request });
+    } finally {
+        unlisten?.();
+    }
+}
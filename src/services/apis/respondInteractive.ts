@@ -0,0 +1,35 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import { ModelInput } from '../../types/ModelMeta';
+
+// A handler paused mid-turn with `answer = yield {"request": {...}}`
+export interface InteractiveRequest {
+    interaction_id: string;
+    request: {
+        type: "confirm" | "input";
+        title?: string;
+        message?: string;
+        inputs?: ModelInput[];
+        confirm_label?: string;
+        cancel_label?: string;
+    };
+    response_id?: string;
+    request_id?: string;
+    session_id?: string;
+}
+
+export async function listenInteractiveRequests(onRequest: (request: InteractiveRequest) => void): Promise<UnlistenFn> {
+    return listen<InteractiveRequest>('interactive:request', (event) => {
+        onRequest(event.payload);
+    });
+}
+
+// Sends the user's answer back to the waiting generator (a boolean for confirm, input values for input)
+export async function respondInteractive(interactionId: string, response: any): Promise<void> {
+    try {
+        await invoke('respond_interactive', { interactionId, response });
+    } catch (error) {
+        console.error('Error responding to interactive request:', error);
+        throw error;
+    }
+}
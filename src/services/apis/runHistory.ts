@@ -0,0 +1,56 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { ChanterelleError } from '../../types/ModelError';
+
+// Every invoke_model call is recorded per project (see src-tauri/src/history.rs)
+
+export interface HistoryEntry {
+    id: string;
+    // Milliseconds since the Unix epoch
+    timestamp: number;
+    project_name: string;
+    model_version: string | null;
+    inputs: Record<string, any>;
+    // Raw handler response; null when the run failed
+    outputs: any | null;
+    error: ChanterelleError | null;
+    latency_ms: number;
+    pid: number | null;
+    env_fingerprint: string | null;
+    // Response fields that broke the declared outputs
+    contract_violations?: number;
+    // Served from the prediction cache
+    cached?: boolean;
+}
+
+export interface HistoryQuery {
+    since?: number;
+    until?: number;
+    status?: 'ok' | 'error';
+    model_version?: string;
+    text?: string;
+    offset?: number;
+    limit?: number;
+}
+
+export interface HistoryPage {
+    // Newest first
+    entries: HistoryEntry[];
+    total: number;
+}
+
+export async function queryHistory(projectName: string, query: HistoryQuery = {}): Promise<HistoryPage> {
+    return invoke<HistoryPage>('query_history', { projectName, query });
+}
+
+export async function getHistoryEntry(projectName: string, id: string): Promise<HistoryEntry> {
+    return invoke<HistoryEntry>('get_history_entry', { projectName, id });
+}
+
+// Returns how many entries were removed
+export async function deleteHistoryEntries(projectName: string, ids: string[]): Promise<number> {
+    return invoke<number>('delete_history_entries', { projectName, ids });
+}
+
+export async function clearHistory(projectName: string): Promise<void> {
+    await invoke('clear_history', { projectName });
+}
@@ -1,17 +1,21 @@
 import { invoke } from '@tauri-apps/api/core';
 
 export interface FeedbackData {
-    rating: 'up' | 'down';
+    // Set by the default form; projects with a feedback_schema send their own fields
+    rating?: 'up' | 'down';
     comment?: string;
     context?: any; // E.g. inputs, outputs
+    [field: string]: any;
 }
 
-export async function submitFeedback(project_name: string, feedback: FeedbackData): Promise<number> {
-    console.log('Submitting feedback for project:', project_name, feedback);
+// Returns the id of the new feedback entry. `runId` links the feedback to a run
+// from the run history, whose inputs and outputs are then stored with it.
+export async function submitFeedback(project_name: string, feedback: FeedbackData, runId?: string): Promise<string> {
+    console.log('Submitting feedback for project:', project_name, feedback, runId);
     try {
-        const timestamp = await invoke<number>('submit_feedback', { projectName: project_name, feedback });
-        console.log('Feedback submitted successfully, timestamp:', timestamp);
-        return timestamp;
+        const id = await invoke<string>('submit_feedback', { projectName: project_name, feedback, runId: runId ?? null });
+        console.log('Feedback submitted successfully, id:', id);
+        return id;
     } catch (error) {
         console.error('Error submitting feedback:', error);
         throw error;
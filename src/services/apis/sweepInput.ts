@@ -0,0 +1,59 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import type { SectionType } from '../../components/insights';
+import type { ChanterelleError } from '../../types/ModelError';
+
+// What-if sweeps over one or two inputs (see src-tauri/src/sweep.rs)
+
+export interface SweepAxis {
+    input: string;
+    // Explicit values; otherwise the grid comes from the input's constraints
+    values?: any[];
+    min?: number;
+    max?: number;
+    step?: number;
+    // Evenly spaced points between min and max, used when there is no step
+    points?: number;
+}
+
+export interface SweepRequest {
+    base_inputs: Record<string, any>;
+    // One input for line/bar charts, two for a heatmap
+    axes: SweepAxis[];
+    // Output names to chart; defaults to every float and int output
+    outputs?: string[];
+}
+
+export interface SweepPoint {
+    values: any[];
+    outputs: Record<string, any>;
+    error: ChanterelleError | null;
+}
+
+export interface SweepReport {
+    project_name: string;
+    model_version: string | null;
+    axes: { input: string; label: string; values: any[] }[];
+    outputs: string[];
+    points: SweepPoint[];
+    errors: number;
+    sections: SectionType[];
+}
+
+export async function sweepInput(
+    projectName: string,
+    request: SweepRequest,
+    onProgress?: (done: number, total: number) => void,
+): Promise<SweepReport> {
+    const unlisten = onProgress
+        ? await listen<{ project_name: string; done: number; total: number }>('sweep:progress', (event) => {
+            if (event.payload.project_name !== projectName) return;
+            onProgress(event.payload.done, event.payload.total);
+        })
+        : null;
+    try {
+        return await invoke<SweepReport>('sweep_input', { projectName, request });
+    } finally {
+        unlisten?.();
+    }
+}
@@ -1,8 +1,9 @@
 import { invoke } from '@tauri-apps/api/core';
 
-export async function updateFeedback(projectName: string, timestamp: number, context: any): Promise<void> {
+// Replaces the context and sets `fields`, which are checked against the project's feedback_schema
+export async function updateFeedback(projectName: string, id: string, context?: any, fields?: Record<string, any>): Promise<void> {
     try {
-        await invoke('update_feedback', { projectName, timestamp, context });
+        await invoke('update_feedback', { projectName, id, context: context ?? null, fields: fields ?? null });
     } catch (error) {
         console.error('Error updating feedback:', error);
         throw error;
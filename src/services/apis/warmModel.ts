@@ -1,10 +1,13 @@
 import { invoke } from '@tauri-apps/api/core';
+import { errorMessage, type ChanterelleError } from '../../types/ModelError';
+import type { FeedbackSchema } from '../../types/ModelMeta';
 
 
 export type WarmResponse = {
     warmup: boolean;
-    error?: string;
+    error?: ChanterelleError;
     allow_feedback?: boolean;
+    feedback_schema?: FeedbackSchema | null;
 };
 
 export async function warmModel(project_name: string): Promise<WarmResponse> {
@@ -18,7 +21,7 @@ export async function warmModel(project_name: string): Promise<WarmResponse> {
         return r;
     } catch (error) {
         console.error('Error fetching model meta:', error);
-        if (typeof error === 'string' && error.includes('No projects directory set')) {
+        if (errorMessage(error).includes('No projects directory set')) {
             throw new Error('Please configure your projects directory in settings first.');
         }
         throw error;
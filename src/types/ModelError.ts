@@ -0,0 +1,48 @@
+// Structured error returned by every backend command (see src-tauri/src/error.rs)
+
+export interface TracebackFrame {
+    file: string;
+    path?: string | null;
+    line: number;
+    function: string;
+    code: string;
+}
+
+export type ChanterelleErrorCode = 'config' | 'env' | 'spawn' | 'protocol' | 'handler' | 'timeout' | 'io' | 'validation' | 'contract';
+
+// One rejected input, named by its `name` in model_meta.json
+export interface FieldError {
+    field: string;
+    message: string;
+}
+
+// A response field that breaks its declared output (see ModelMeta outputs)
+export interface ContractViolation {
+    output: string;
+    path: string;
+    message: string;
+}
+
+export interface ChanterelleError {
+    code: ChanterelleErrorCode;
+    message: string;
+    // Only set for code === 'handler'
+    error_type?: string;
+    stage?: string;
+    context?: string;
+    traceback?: TracebackFrame[];
+    // Only set for code === 'validation'
+    fields?: FieldError[];
+    // Only set for code === 'contract'
+    violations?: ContractViolation[];
+}
+
+export function isChanterelleError(error: unknown): error is ChanterelleError {
+    return typeof error === 'object' && error !== null && 'code' in error && 'message' in error;
+}
+
+export function errorMessage(error: unknown): string {
+    if (typeof error === 'string') return error;
+    if (isChanterelleError(error) || error instanceof Error) return error.message;
+    return String(error);
+}
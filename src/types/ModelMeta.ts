@@ -86,6 +86,24 @@ export interface ModelOutput {
     }> | string[];
 }
 
+// Feedback form fields, declared as `feedback_schema` in model_meta.json or interactive.json
+export type FeedbackField = {
+    name: string;
+    label: string;
+    required?: boolean;
+    description?: string;
+} & (
+    | { type: 'rating'; min?: number; max?: number }
+    | { type: 'label'; options: string[]; multiple?: boolean }
+    // The value an output should have had
+    | { type: 'correction'; output: ModelOutput }
+    | { type: 'text'; max_length?: number }
+);
+
+export interface FeedbackSchema {
+    fields: FeedbackField[];
+}
+
 // Python Environment Configuration
 export interface PythonEnvironment {
     type: 'system' | 'venv' | 'conda' | 'virtualenv';
@@ -116,4 +134,16 @@ export interface ModelMeta {
     // signed_url_params?: string;
     python_environment?: PythonEnvironment;
     allow_feedback?: boolean;
+    // Host calls handlers may make through chanterelle_host; all when omitted
+    host_services?: string[];
+    // Fail predictions that break the declared outputs instead of warning
+    strict_outputs?: boolean;
+    // Presence turns on the prediction cache
+    prediction_cache?: {
+        max_entries?: number;
+        max_bytes?: number;
+        ttl_seconds?: number;
+    };
+    // Replaces the thumbs up/down form
+    feedback_schema?: FeedbackSchema;
 }
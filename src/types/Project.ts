@@ -1,7 +1,10 @@
 import { SectionType } from "../components/insights";
 
 export interface ProjectMeta {
+    // Folder path relative to the projects directory, e.g. "team/domain/project"
     project_name: string;
+    // Folders above the project, e.g. "team/domain"; null for top-level projects
+    category?: string | null;
     project_title: string;
     description: string;
     description_short?: string;